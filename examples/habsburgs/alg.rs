@@ -23,7 +23,7 @@ pub(crate) fn coi_for_data_set(records: Vec<Record>) -> Vec<OutputRecord> {
     let parents: HashMap<u8, (u8, u8)> = get_parents(&people);
 
     // map from identifiers to vec locations
-    let mut ids: Vec<u8> = people.iter().map(|(_, p)| p.id).collect();
+    let mut ids: Vec<u8> = people.values().map(|p| p.id).collect();
     ids.sort();
     let ids = ids;
 
@@ -33,12 +33,9 @@ pub(crate) fn coi_for_data_set(records: Vec<Record>) -> Vec<OutputRecord> {
     let parents: Vec<_> = ids
         .iter()
         .map(|id| {
-            parents.get(id).map(|(a, b)| {
-                (
-                    *idx_from_id.get(a).unwrap() as usize,
-                    *idx_from_id.get(b).unwrap() as usize,
-                )
-            })
+            parents
+                .get(id)
+                .map(|(a, b)| (*idx_from_id.get(a).unwrap(), *idx_from_id.get(b).unwrap()))
         })
         .collect();
 
@@ -101,7 +98,7 @@ fn records_to_genealogy(records: Vec<Record>) -> HashMap<u8, Person> {
             } => {
                 let person = Person {
                     name: cat_name(&name, &regnal_number),
-                    id: id,
+                    id,
                     children: Vec::new(),
                 };
                 people.insert(id, person);
@@ -120,7 +117,7 @@ fn records_to_genealogy(records: Vec<Record>) -> HashMap<u8, Person> {
 fn get_parents(people: &HashMap<u8, Person>) -> HashMap<u8, (u8, u8)> {
     let mut map: HashMap<u8, (u8, Option<u8>)> = HashMap::new();
 
-    for (_, parent) in people {
+    for parent in people.values() {
         for child in &parent.children {
             map.entry(*child)
                 .and_modify(|r| r.1 = Some(parent.id))
@@ -134,15 +131,7 @@ fn get_parents(people: &HashMap<u8, Person>) -> HashMap<u8, (u8, u8)> {
 }
 
 fn zeros(size: usize) -> Vec<Vec<f32>> {
-    let mut matrix = Vec::with_capacity(size);
-    for _ in 0..size {
-        let mut row = Vec::new();
-        for _ in 0..size {
-            row.push(0.0);
-        }
-        matrix.push(row);
-    }
-    matrix
+    vec![vec![0.0; size]; size]
 }
 
 fn diag_minus_one(data: Vec<Vec<f32>>) -> Vec<f32> {
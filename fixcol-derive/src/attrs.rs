@@ -3,14 +3,16 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use proc_macro2::{Literal, Span, TokenStream, TokenTree};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{Attribute, Ident, Meta, Path};
+use syn::{Attribute, Ident, Lit, Meta, Path, Type};
 
 use crate::error::MacroError;
 
 const FIXED_ATTR_KEY: &str = "fixcol";
-const STRICT_DEFAULT: bool = true;
+// Strict mode applies unless the `lax-by-default` feature is enabled. Cargo
+// unifies features, so this is global to the build rather than per crate.
+const STRICT_DEFAULT: bool = !cfg!(feature = "lax-by-default");
 
 // Extracts the ident name from a path
 fn ident_from_path(path: &Path) -> String {
@@ -35,11 +37,12 @@ pub(crate) fn fixcol_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
     attrs.iter().filter(|a| is_fixcol_attr(a)).collect()
 }
 
-/// Wraps either a literal or an identifier
+/// Wraps either a literal, an identifier, or a path
 #[derive(Debug)]
 enum ValueToken {
     Ident(Ident),
     Literal(Literal),
+    Path(Path),
 }
 
 impl ValueToken {
@@ -47,6 +50,7 @@ impl ValueToken {
         match self {
             ValueToken::Ident(ident) => ident.span(),
             ValueToken::Literal(literal) => literal.span(),
+            ValueToken::Path(path) => path.span(),
         }
     }
 }
@@ -56,6 +60,7 @@ impl Display for ValueToken {
         match self {
             ValueToken::Ident(ident) => ident.fmt(f),
             ValueToken::Literal(literal) => literal.fmt(f),
+            ValueToken::Path(path) => quote!(#path).to_string().replace(' ', "").fmt(f),
         }
     }
 }
@@ -85,6 +90,35 @@ impl OuterConfig {
             OuterConfig::Struct(sc) => sc.strict,
         }
     }
+
+    pub fn ascii_only(&self) -> bool {
+        match self {
+            OuterConfig::Variant(_) => false,
+            OuterConfig::Struct(sc) => sc.ascii_only,
+        }
+    }
+
+    pub fn reject_blank(&self) -> bool {
+        match self {
+            OuterConfig::Variant(_) => false,
+            OuterConfig::Struct(sc) => sc.reject_blank,
+        }
+    }
+
+    pub fn decoding(&self) -> Decoding {
+        match self {
+            OuterConfig::Variant(vc) => vc.decoding,
+            OuterConfig::Struct(sc) => sc.decoding,
+        }
+    }
+
+    /// The absolute column at which the first field begins
+    pub fn first_column(&self) -> usize {
+        match self {
+            OuterConfig::Variant(vc) if !vc.discriminated => vc.key_width,
+            _ => 0,
+        }
+    }
 }
 
 impl From<VariantConfig> for OuterConfig {
@@ -146,6 +180,33 @@ impl FieldParam {
     fn value(&self) -> String {
         strip_quotes(self.value.to_string().as_str())
     }
+
+    /// The value as a single character, written as `'c'` or `"c"`
+    fn char_value(&self) -> Option<char> {
+        let value = match &self.value {
+            ValueToken::Literal(literal) => match Lit::new(literal.clone()) {
+                Lit::Char(c) => return Some(c.value()),
+                Lit::Str(s) => s.value(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// The value as a path, if it was written as an identifier or path
+    fn path(&self) -> Option<Path> {
+        match &self.value {
+            ValueToken::Ident(ident) => Some(ident.clone().into()),
+            ValueToken::Path(path) => Some(path.clone()),
+            ValueToken::Literal(_) => None,
+        }
+    }
 }
 
 impl PartialEq for FieldParam {
@@ -157,12 +218,18 @@ impl PartialEq for FieldParam {
 impl Eq for FieldParam {}
 
 // Ident holds the key of the current param we're parsing
+//
+// Identifier values may continue as a path (e.g., `layout::WIDTH`), so they
+// are held with their segments until the path ends.
 #[derive(PartialEq, Eq, Debug)]
 enum ExpectedTokenState {
     Key,
     Equals(Ident),
     Value(Ident),
     Separator,
+    Path(Ident, Vec<Ident>),
+    PathColon(Ident, Vec<Ident>),
+    PathSegment(Ident, Vec<Ident>),
 }
 
 impl Display for ExpectedTokenState {
@@ -171,11 +238,52 @@ impl Display for ExpectedTokenState {
             ExpectedTokenState::Key => f.write_str("identifier"),
             ExpectedTokenState::Equals(_) => f.write_str("assignment"),
             ExpectedTokenState::Value(_) => f.write_str("value"),
-            ExpectedTokenState::Separator => f.write_str("separator"),
+            ExpectedTokenState::Separator | ExpectedTokenState::Path(..) => {
+                f.write_str("separator")
+            }
+            ExpectedTokenState::PathColon(..) => f.write_str("path separator"),
+            ExpectedTokenState::PathSegment(..) => f.write_str("path segment"),
         }
     }
 }
 
+/// Builds the parameter for an identifier or path value
+fn path_param(key: Ident, mut segments: Vec<Ident>) -> FieldParam {
+    if segments.len() == 1 {
+        let ident = segments.pop().unwrap();
+        return FieldParam::new(key, ident.into());
+    }
+
+    let path: Path = syn::parse_quote! { #(#segments)::* };
+    FieldParam::new(key, ValueToken::Path(path))
+}
+
+/// Boolean parameters that may be written as a bare flag meaning `true`
+const BARE_FLAGS: [&str; 12] = [
+    "allow_nonfinite",
+    "ascii_only",
+    "catch_all",
+    "embed",
+    "from_end",
+    "from_fields",
+    "ignore_others",
+    "keep_trailing",
+    "match_display",
+    "overlap",
+    "reject_blank",
+    "strict",
+];
+
+fn is_bare_flag(key: &Ident) -> bool {
+    BARE_FLAGS.contains(&key.to_string().as_str())
+}
+
+/// Builds the parameter for a bare flag such as `#[fixcol(strict)]`
+fn bare_flag_param(key: Ident) -> FieldParam {
+    let value = Ident::new("true", key.span());
+    FieldParam::new(key, value.into())
+}
+
 fn parse_next_token(
     state: ExpectedTokenState,
     tt: TokenTree,
@@ -188,14 +296,18 @@ fn parse_next_token(
         (ExpectedTokenState::Equals(key), TokenTree::Punct(p)) if p.as_char() == '=' => {
             Ok((ExpectedTokenState::Value(key), None))
         }
+        (ExpectedTokenState::Equals(key), TokenTree::Punct(p))
+            if p.as_char() == ',' && is_bare_flag(&key) =>
+        {
+            Ok((ExpectedTokenState::Key, Some(bare_flag_param(key))))
+        }
         (ExpectedTokenState::Equals(_), t) => Err(MacroError::new(
             "Expected assignment ('=' character).",
             t.span(),
         )),
-        (ExpectedTokenState::Value(key), TokenTree::Ident(ident)) => Ok((
-            ExpectedTokenState::Separator,
-            Some(FieldParam::new(key, ident.into())),
-        )),
+        (ExpectedTokenState::Value(key), TokenTree::Ident(ident)) => {
+            Ok((ExpectedTokenState::Path(key, vec![ident]), None))
+        }
         (ExpectedTokenState::Value(key), TokenTree::Literal(literal)) => Ok((
             ExpectedTokenState::Separator,
             Some(FieldParam::new(key, literal.into())),
@@ -206,10 +318,34 @@ fn parse_next_token(
         (ExpectedTokenState::Separator, TokenTree::Punct(p)) if p.as_char() == ',' => {
             Ok((ExpectedTokenState::Key, None))
         }
-        (ExpectedTokenState::Separator, t) => Err(MacroError::new(
-            "Expected separator (',' character) or end of sequence.",
+        (ExpectedTokenState::Path(key, segments), TokenTree::Punct(p)) if p.as_char() == ',' => {
+            Ok((ExpectedTokenState::Key, Some(path_param(key, segments))))
+        }
+        (ExpectedTokenState::Path(key, segments), TokenTree::Punct(p)) if p.as_char() == ':' => {
+            Ok((ExpectedTokenState::PathColon(key, segments), None))
+        }
+        (ExpectedTokenState::PathColon(key, segments), TokenTree::Punct(p))
+            if p.as_char() == ':' =>
+        {
+            Ok((ExpectedTokenState::PathSegment(key, segments), None))
+        }
+        (ExpectedTokenState::PathColon(_, _), t) => Err(MacroError::new(
+            "Expected path separator (\"::\").",
             t.span(),
         )),
+        (ExpectedTokenState::PathSegment(key, mut segments), TokenTree::Ident(ident)) => {
+            segments.push(ident);
+            Ok((ExpectedTokenState::Path(key, segments), None))
+        }
+        (ExpectedTokenState::PathSegment(_, _), t) => {
+            Err(MacroError::new("Expected identifier.", t.span()))
+        }
+        (ExpectedTokenState::Separator | ExpectedTokenState::Path(_, _), t) => {
+            Err(MacroError::new(
+                "Expected separator (',' character) or end of sequence.",
+                t.span(),
+            ))
+        }
     }
 }
 
@@ -255,6 +391,18 @@ fn get_config_params(tokens: TokenStream) -> Result<Vec<FieldParam>, MacroError>
         }
     }
 
+    if let ExpectedTokenState::Equals(key) = &state {
+        if is_bare_flag(key) {
+            field_params.push(bare_flag_param(key.clone()));
+            state = ExpectedTokenState::Separator;
+        }
+    }
+
+    if let ExpectedTokenState::Path(key, segments) = state {
+        field_params.push(path_param(key, segments));
+        state = ExpectedTokenState::Separator;
+    }
+
     if state != ExpectedTokenState::Separator && any_tokens {
         Err(MacroError::new(
             format!("Expected {} found end of input.", state).as_str(),
@@ -265,6 +413,7 @@ fn get_config_params(tokens: TokenStream) -> Result<Vec<FieldParam>, MacroError>
     }
 }
 
+#[derive(Clone, Copy, Debug)]
 pub(crate) enum Align {
     Left,
     Right,
@@ -284,18 +433,273 @@ impl FromStr for Align {
     }
 }
 
+pub(crate) enum Negatives {
+    Sign,
+    Parens,
+}
+
+impl FromStr for Negatives {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sign" => Ok(Negatives::Sign),
+            "parens" => Ok(Negatives::Parens),
+            other => Err(format!("Unknown negatives style {}", other)),
+        }
+    }
+}
+
+pub(crate) enum Ipv4 {
+    Dotted,
+    Octets,
+}
+
+impl FromStr for Ipv4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dotted" => Ok(Ipv4::Dotted),
+            "octets" => Ok(Ipv4::Octets),
+            other => Err(format!("Unknown ipv4 style {}", other)),
+        }
+    }
+}
+
+/// The width of a field, either an integer literal or a constant expression
+///
+/// Constant widths are a path to a `const usize` or, when the width is
+/// omitted, the `FixedWidth::WIDTH` of the field's type.
+#[derive(Clone)]
+pub(crate) enum Width {
+    Literal(usize),
+    Const(TokenStream),
+}
+
+impl Width {
+    /// The width of a field whose `width` parameter is omitted
+    fn inferred(ty: &Type) -> Self {
+        Width::Const(quote_spanned! { ty.span()=> <#ty as fixcol::FixedWidth>::WIDTH })
+    }
+}
+
+impl quote::ToTokens for Width {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Width::Literal(width) => width.to_tokens(tokens),
+            Width::Const(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+/// A count of columns that may include the values of named constants
+///
+/// The count is only known to the macro when no constants are included.
+/// Otherwise, it is emitted as a sum to be evaluated by the compiler.
+#[derive(Clone, Default)]
+pub(crate) struct Columns {
+    fixed: usize,
+    consts: Vec<TokenStream>,
+}
+
+impl Columns {
+    /// The number of columns, if known to the macro
+    pub(crate) fn known(&self) -> Option<usize> {
+        match self.consts.is_empty() {
+            true => Some(self.fixed),
+            false => None,
+        }
+    }
+}
+
+impl From<usize> for Columns {
+    fn from(value: usize) -> Self {
+        Self { fixed: value, consts: Vec::new() }
+    }
+}
+
+impl From<&Width> for Columns {
+    fn from(value: &Width) -> Self {
+        match value {
+            Width::Literal(width) => Columns::from(*width),
+            Width::Const(expr) => Self { fixed: 0, consts: vec![expr.clone()] },
+        }
+    }
+}
+
+impl std::ops::AddAssign for Columns {
+    fn add_assign(&mut self, rhs: Self) {
+        self.fixed += rhs.fixed;
+        self.consts.extend(rhs.consts);
+    }
+}
+
+impl std::iter::Sum for Columns {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let mut total = Columns::default();
+        for columns in iter {
+            total += columns;
+        }
+        total
+    }
+}
+
+impl quote::ToTokens for Columns {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Columns { fixed, consts } = self;
+
+        match consts.is_empty() {
+            true => fixed.to_tokens(tokens),
+            false => tokens.extend(quote! { (#fixed #(+ #consts)*) }),
+        }
+    }
+}
+
+pub(crate) enum Overflow {
+    Truncate,
+    Error,
+    KeepEnd,
+}
+
+impl FromStr for Overflow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "truncate" => Ok(Overflow::Truncate),
+            "error" => Ok(Overflow::Error),
+            "keep_end" => Ok(Overflow::KeepEnd),
+            other => Err(format!("Unknown overflow policy {}", other)),
+        }
+    }
+}
+
 pub(crate) struct FieldConfig {
     pub(crate) skip: usize,
-    pub(crate) width: usize,
+    pub(crate) width: Width,
+    pub(crate) count: usize,
     pub(crate) align: Align,
     pub(crate) strict: bool,
+    pub(crate) keep_trailing: bool,
+    pub(crate) skip_literal: Option<String>,
+    pub(crate) from_end: bool,
+    pub(crate) pad: char,
+    pub(crate) negatives: Negatives,
+    pub(crate) repeat_rest: bool,
+    pub(crate) blank_as: Option<String>,
+    pub(crate) pad_side: Option<Align>,
+    pub(crate) at: Option<usize>,
+    /// Whether the field re-reads columns already covered by prior fields
+    pub(crate) overlap: bool,
+    pub(crate) bool_tokens: Option<String>,
+    pub(crate) on_overflow: Option<Overflow>,
+    pub(crate) min_digits: Option<usize>,
+    pub(crate) sep: Option<char>,
+    pub(crate) scale: Option<u32>,
+    pub(crate) prefix: Option<String>,
+    pub(crate) allow_nonfinite: bool,
+    pub(crate) flags: Option<String>,
+    pub(crate) ipv4: Ipv4,
+    /// A function applied to the field's value after it is read
+    pub(crate) transform: Option<Path>,
+    /// Whether a field holding only its pad character is an error
+    pub(crate) reject_blank: bool,
+    pub(crate) decoding: Decoding,
+    pub(crate) name: Option<String>,
+    pub(crate) description: Option<String>,
+}
+
+impl FieldConfig {
+    /// The columns holding the field's value, `count` items of its width
+    pub(crate) fn value_columns(&self) -> Columns {
+        (0..self.count).map(|_| Columns::from(&self.width)).sum()
+    }
+
+    /// The columns occupied by the field, including its skipped columns
+    pub(crate) fn columns(&self) -> Columns {
+        let mut columns = Columns::from(self.skip);
+        columns += self.value_columns();
+        columns
+    }
+
+    /// Code to write the field's name in place of its value in a header row
+    ///
+    /// The name is left aligned in the field's columns and truncated to fit.
+    /// Unnamed fields are written as blanks.
+    pub(crate) fn write_header(&self) -> TokenStream {
+        let skip = if self.from_end { 0 } else { self.skip };
+        let len = self.value_columns();
+        let label = self.name.as_deref().unwrap_or("");
+        let trailing = self.write_trailing();
+
+        quote! {
+            let _ = #label.write_fixed_field(
+                buf,
+                &{
+                    let mut __fixcol_desc = fixcol::FieldDescription::default();
+                    __fixcol_desc.skip = #skip;
+                    __fixcol_desc.len = #len;
+                    __fixcol_desc.on_overflow = Some(fixcol::Overflow::Truncate);
+                    __fixcol_desc
+                },
+            )?;
+            #trailing
+        }
+    }
+
+    /// Code to write any columns that follow the field
+    ///
+    /// Fields read from the end of the line place their skipped columns after
+    /// the field rather than before it.
+    pub(crate) fn write_trailing(&self) -> TokenStream {
+        let skip = self.skip;
+
+        if self.from_end && skip > 0 {
+            quote! {
+                let _ = ().write_fixed_field(
+                    buf,
+                    &fixcol::FieldDescription::new(0, #skip, fixcol::Alignment::Left, true),
+                )?;
+            }
+        } else {
+            quote! {}
+        }
+    }
 }
 
 // This allows us to directly convert a FieldConfig (from the macro code)
-// into a FieldDescription literal in the generated code
+// into a FieldDescription in the generated code
 impl quote::ToTokens for FieldConfig {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let FieldConfig { skip, width, align, strict } = &self;
+        let FieldConfig {
+            skip,
+            width,
+            align,
+            strict,
+            keep_trailing,
+            skip_literal,
+            from_end,
+            pad,
+            negatives,
+            blank_as,
+            pad_side,
+            bool_tokens,
+            on_overflow,
+            min_digits,
+            sep,
+            scale,
+            prefix,
+            allow_nonfinite,
+            flags,
+            ipv4,
+            name,
+            description,
+            ..
+        } = &self;
+
+        // Fields read from the end are sliced to exactly their own width
+        let skip = if *from_end { 0 } else { *skip };
 
         let alignment = match &align {
             Align::Left => quote! { fixcol::Alignment::Left },
@@ -303,31 +707,164 @@ impl quote::ToTokens for FieldConfig {
             Align::Full => quote! { fixcol::Alignment::Full },
         };
 
+        let negatives = match &negatives {
+            Negatives::Sign => quote! { fixcol::NegativeStyle::Sign },
+            Negatives::Parens => quote! { fixcol::NegativeStyle::Parens },
+        };
+
+        let ipv4 = match &ipv4 {
+            Ipv4::Dotted => quote! { fixcol::Ipv4Style::Dotted },
+            Ipv4::Octets => quote! { fixcol::Ipv4Style::Octets },
+        };
+
+        let skip_literal = match skip_literal {
+            Some(literal) => quote! { Some(#literal) },
+            None => quote! { None },
+        };
+
+        let blank_as = match blank_as {
+            Some(sentinel) => quote! { Some(#sentinel) },
+            None => quote! { None },
+        };
+
+        let pad_side = match pad_side {
+            Some(Align::Left) => quote! { Some(fixcol::PadSide::Left) },
+            Some(_) => quote! { Some(fixcol::PadSide::Right) },
+            None => quote! { None },
+        };
+
+        let bool_tokens = match bool_tokens {
+            Some(tokens) => quote! { Some(#tokens) },
+            None => quote! { None },
+        };
+
+        let on_overflow = match on_overflow {
+            Some(Overflow::Truncate) => quote! { Some(fixcol::Overflow::Truncate) },
+            Some(Overflow::Error) => quote! { Some(fixcol::Overflow::Error) },
+            Some(Overflow::KeepEnd) => quote! { Some(fixcol::Overflow::KeepEnd) },
+            None => quote! { None },
+        };
+
+        let min_digits = match min_digits {
+            Some(digits) => quote! { Some(#digits) },
+            None => quote! { None },
+        };
+
+        let sep = match sep {
+            Some(sep) => quote! { Some(#sep) },
+            None => quote! { None },
+        };
+
+        let scale = match scale {
+            Some(scale) => quote! { Some(#scale) },
+            None => quote! { None },
+        };
+
+        let prefix = match prefix {
+            Some(prefix) => quote! { Some(#prefix) },
+            None => quote! { None },
+        };
+
+        let flags = match flags {
+            Some(tokens) => quote! { Some(#tokens) },
+            None => quote! { None },
+        };
+
+        let name = match name {
+            Some(name) => quote! { Some(#name) },
+            None => quote! { None },
+        };
+
+        let description = match description {
+            Some(description) => quote! { Some(#description) },
+            None => quote! { None },
+        };
+
+        // FieldDescription is non_exhaustive, so its options are assigned to a
+        // value from its constructor rather than written as a struct literal
         tokens.extend(quote! {
-            &fixcol::FieldDescription {
-                skip: #skip,
-                len: #width,
-                alignment: #alignment,
-                strict: #strict,
+            &{
+                let mut __fixcol_desc =
+                    fixcol::FieldDescription::new(#skip, #width, #alignment, #strict);
+                __fixcol_desc.keep_trailing = #keep_trailing;
+                __fixcol_desc.skip_literal = #skip_literal;
+                __fixcol_desc.pad = #pad;
+                __fixcol_desc.negatives = #negatives;
+                __fixcol_desc.blank_as = #blank_as;
+                __fixcol_desc.pad_side = #pad_side;
+                __fixcol_desc.bool_tokens = #bool_tokens;
+                __fixcol_desc.on_overflow = #on_overflow;
+                __fixcol_desc.min_digits = #min_digits;
+                __fixcol_desc.sep = #sep;
+                __fixcol_desc.scale = #scale;
+                __fixcol_desc.prefix = #prefix;
+                __fixcol_desc.allow_nonfinite = #allow_nonfinite;
+                __fixcol_desc.flags = #flags;
+                __fixcol_desc.ipv4 = #ipv4;
+                __fixcol_desc.name = #name;
+                __fixcol_desc.description = #description;
+                __fixcol_desc
             }
         });
     }
 }
 
 struct FieldConfigBuilder {
-    width: Option<usize>,
+    width: Option<Width>,
+    count: Option<usize>,
     skip: Option<usize>,
     align: Option<Align>,
     strict: Option<bool>,
+    keep_trailing: Option<bool>,
+    skip_literal: Option<String>,
+    from_end: Option<bool>,
+    pad: Option<char>,
+    negatives: Option<Negatives>,
+    repeat: Option<String>,
+    blank_as: Option<String>,
+    pad_side: Option<Align>,
+    at: Option<usize>,
+    overlap: Option<bool>,
+    bool_tokens: Option<String>,
+    on_overflow: Option<Overflow>,
+    min_digits: Option<usize>,
+    sep: Option<char>,
+    scale: Option<u32>,
+    prefix: Option<String>,
+    allow_nonfinite: Option<bool>,
+    flags: Option<String>,
+    ipv4: Option<Ipv4>,
+    transform: Option<Path>,
 }
 
 impl FieldConfigBuilder {
     fn new() -> Self {
         Self {
             width: None,
+            count: None,
             skip: None,
             align: None,
             strict: None,
+            keep_trailing: None,
+            skip_literal: None,
+            from_end: None,
+            pad: None,
+            negatives: None,
+            repeat: None,
+            blank_as: None,
+            pad_side: None,
+            at: None,
+            overlap: None,
+            bool_tokens: None,
+            on_overflow: None,
+            min_digits: None,
+            sep: None,
+            scale: None,
+            prefix: None,
+            allow_nonfinite: None,
+            flags: None,
+            ipv4: None,
+            transform: None,
         }
     }
 }
@@ -342,9 +879,14 @@ fn check_none<T>(key: &str, span: Span, opt: Option<T>) -> Result<(), MacroError
     }
 }
 
+/// Parses the config of a field of type `ty`
+///
+/// A field without a `width` takes the width of its type, which must then
+/// implement `FixedWidth`.
 pub(crate) fn parse_field_attributes(
     span: &Span,
     attrs: &[Attribute],
+    ty: &Type,
     parent: &OuterConfig,
 ) -> Result<FieldConfig, MacroError> {
     let params = parse_attributes(attrs)?;
@@ -363,15 +905,24 @@ pub(crate) fn parse_field_attributes(
                 check_none("skip", param.key_span(), old)?;
             }
             "width" => {
-                let err = "Expected numeric value for width.";
-                let val: usize = param
-                    .value()
-                    .to_string()
-                    .parse()
-                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let err = "Expected numeric value or constant for width.";
+                let val = match (param.value().parse(), param.path()) {
+                    (Ok(width), _) => Width::Literal(width),
+                    (Err(_), Some(path)) => Width::Const(quote! { #path }),
+                    (Err(_), None) => return Err(MacroError::new(err, param.value_span())),
+                };
                 let old = conf.width.replace(val);
                 check_none("width", param.key_span(), old)?;
             }
+            "count" => {
+                let err = "Expected a positive integer for count.";
+                let val: usize = match param.value().parse() {
+                    Ok(0) | Err(_) => return Err(MacroError::new(err, param.value_span())),
+                    Ok(count) => count,
+                };
+                let old = conf.count.replace(val);
+                check_none("count", param.key_span(), old)?;
+            }
             "align" => {
                 let err = "Expected values for align are \"left\", \"right\", or \"full\".";
                 let val: Align = param
@@ -392,6 +943,184 @@ pub(crate) fn parse_field_attributes(
                 let old = conf.strict.replace(val);
                 check_none("strict", param.key_span(), old)?;
             }
+            "keep_trailing" => {
+                let err = "Expected boolean value for parameter keep_trailing.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.keep_trailing.replace(val);
+                check_none("keep_trailing", param.key_span(), old)?;
+            }
+            "skip_literal" => {
+                let old = conf.skip_literal.replace(param.value());
+                check_none("skip_literal", param.key_span(), old)?;
+            }
+            "from_end" => {
+                let err = "Expected boolean value for parameter from_end.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.from_end.replace(val);
+                check_none("from_end", param.key_span(), old)?;
+            }
+            "pad" => {
+                let err = "Expected a single character for pad.";
+                let val = param
+                    .char_value()
+                    .ok_or_else(|| MacroError::new(err, param.value_span()))?;
+                let old = conf.pad.replace(val);
+                check_none("pad", param.key_span(), old)?;
+            }
+            "negatives" => {
+                let err = "Expected values for negatives are \"sign\" or \"parens\".";
+                let val: Negatives = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.negatives.replace(val);
+                check_none("negatives", param.key_span(), old)?;
+            }
+            "ipv4" => {
+                let err = "Expected values for ipv4 are \"dotted\" or \"octets\".";
+                let val: Ipv4 = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.ipv4.replace(val);
+                check_none("ipv4", param.key_span(), old)?;
+            }
+            "repeat" => {
+                let err = "Expected value for repeat is \"rest\".";
+                let val = param.value();
+                if val != "rest" {
+                    return Err(MacroError::new(err, param.value_span()));
+                }
+                let old = conf.repeat.replace(val);
+                check_none("repeat", param.key_span(), old)?;
+            }
+            "blank_as" => {
+                let old = conf.blank_as.replace(param.value());
+                check_none("blank_as", param.key_span(), old)?;
+            }
+            "at" => {
+                let err = "Expected numeric value for at.";
+                let val: usize = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.at.replace(val);
+                check_none("at", param.key_span(), old)?;
+            }
+            "overlap" => {
+                let err = "Expected boolean value for parameter overlap.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.overlap.replace(val);
+                check_none("overlap", param.key_span(), old)?;
+            }
+            "pad_side" => {
+                let err = "Expected values for pad_side are \"left\" or \"right\".";
+                let val: Align = match param.value().to_string().parse() {
+                    Ok(Align::Full) | Err(_) => {
+                        return Err(MacroError::new(err, param.value_span()));
+                    }
+                    Ok(side) => side,
+                };
+                let old = conf.pad_side.replace(val);
+                check_none("pad_side", param.key_span(), old)?;
+            }
+            "bool_tokens" => {
+                let err = "Expected two distinct characters for bool_tokens, \
+                    the true token followed by the false token.";
+                let val = param.value();
+                let chars: Vec<char> = val.chars().collect();
+                if chars.len() != 2 || chars[0] == chars[1] {
+                    return Err(MacroError::new(err, param.value_span()));
+                }
+                let old = conf.bool_tokens.replace(val);
+                check_none("bool_tokens", param.key_span(), old)?;
+            }
+            "on_overflow" => {
+                let err =
+                    "Expected values for on_overflow are \"truncate\", \"keep_end\" or \"error\".";
+                let val: Overflow = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.on_overflow.replace(val);
+                check_none("on_overflow", param.key_span(), old)?;
+            }
+            "min_digits" => {
+                let err = "Expected a positive integer for min_digits.";
+                let val: usize = match param.value().parse() {
+                    Ok(0) | Err(_) => return Err(MacroError::new(err, param.value_span())),
+                    Ok(digits) => digits,
+                };
+                let old = conf.min_digits.replace(val);
+                check_none("min_digits", param.key_span(), old)?;
+            }
+            "sep" => {
+                let err = "Expected a single character for sep.";
+                let val = param
+                    .char_value()
+                    .ok_or_else(|| MacroError::new(err, param.value_span()))?;
+                let old = conf.sep.replace(val);
+                check_none("sep", param.key_span(), old)?;
+            }
+            "scale" => {
+                let err = "Expected numeric value for scale.";
+                let val: u32 = param
+                    .value()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.scale.replace(val);
+                check_none("scale", param.key_span(), old)?;
+            }
+            "prefix" => {
+                let old = conf.prefix.replace(param.value());
+                check_none("prefix", param.key_span(), old)?;
+            }
+            "allow_nonfinite" => {
+                let err = "Expected boolean value for parameter allow_nonfinite.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.allow_nonfinite.replace(val);
+                check_none("allow_nonfinite", param.key_span(), old)?;
+            }
+            "flags" => {
+                let err = "Expected two distinct characters for flags, \
+                    the on token followed by the off token.";
+                let val = param.value();
+                let chars: Vec<char> = val.chars().collect();
+                if chars.len() != 2 || chars[0] == chars[1] {
+                    return Err(MacroError::new(err, param.value_span()));
+                }
+                let old = conf.flags.replace(val);
+                check_none("flags", param.key_span(), old)?;
+            }
+            "transform" => {
+                let err = "Expected a function path for transform.";
+                let val = param
+                    .path()
+                    .or_else(|| syn::parse_str(&param.value()).ok())
+                    .ok_or_else(|| MacroError::new(err, param.value_span()))?;
+                let old = conf.transform.replace(val);
+                check_none("transform", param.key_span(), old)?;
+            }
             key => {
                 return Err(MacroError::new(
                     format!("Unrecognized parameter \"{}\".", key).as_str(),
@@ -401,37 +1130,148 @@ pub(crate) fn parse_field_attributes(
         }
     }
 
-    match conf.width {
-        Some(width) => {
-            let fc = FieldConfig {
-                skip: conf.skip.unwrap_or(0),
-                align: conf.align.unwrap_or(Align::Left),
-                width,
-                strict: conf.strict.unwrap_or(parent.strict()),
-            };
-
-            Ok(fc)
+    // A literal implies the skip width unless one was given explicitly
+    let skip = match (conf.skip, &conf.skip_literal) {
+        (Some(skip), Some(literal)) if skip != literal.len() => {
+            return Err(MacroError::new(
+                "Length of skip_literal must match skip.",
+                *span,
+            ));
         }
-        None => Err(MacroError::new(
-            "Width must be specified for all fields.",
+        (Some(skip), _) => skip,
+        (None, Some(literal)) => literal.len(),
+        (None, None) => 0,
+    };
+
+    if conf.at.is_some() && (conf.skip.is_some() || conf.skip_literal.is_some()) {
+        return Err(MacroError::new(
+            "at cannot be combined with skip or skip_literal.",
             *span,
-        )),
+        ));
+    }
+
+    let overlap = conf.overlap.unwrap_or(false);
+    if overlap && conf.at.is_none() {
+        return Err(MacroError::new("overlap requires at.", *span));
     }
+
+    let from_end = conf.from_end.unwrap_or(false);
+    if from_end && conf.at.is_some() {
+        return Err(MacroError::new(
+            "at is not supported on fields read from the end.",
+            *span,
+        ));
+    }
+
+    if from_end && conf.skip_literal.is_some() {
+        return Err(MacroError::new(
+            "skip_literal is not supported on fields read from the end.",
+            *span,
+        ));
+    }
+
+    let repeat_rest = conf.repeat.is_some();
+    if from_end && repeat_rest {
+        return Err(MacroError::new(
+            "repeat is not supported on fields read from the end.",
+            *span,
+        ));
+    }
+
+    if repeat_rest && overlap {
+        return Err(MacroError::new(
+            "overlap cannot be combined with repeat.",
+            *span,
+        ));
+    }
+
+    if repeat_rest && conf.count.is_some() {
+        return Err(MacroError::new(
+            "count cannot be combined with repeat.",
+            *span,
+        ));
+    }
+
+    if repeat_rest && conf.sep.is_some() {
+        return Err(MacroError::new(
+            "sep cannot be combined with repeat.",
+            *span,
+        ));
+    }
+
+    let width = conf.width.unwrap_or_else(|| Width::inferred(ty));
+
+    Ok(FieldConfig {
+        skip,
+        align: conf.align.unwrap_or(Align::Left),
+        width,
+        count: conf.count.unwrap_or(1),
+        strict: conf.strict.unwrap_or(parent.strict()),
+        keep_trailing: conf.keep_trailing.unwrap_or(false),
+        skip_literal: conf.skip_literal,
+        from_end,
+        pad: conf.pad.unwrap_or(' '),
+        negatives: conf.negatives.unwrap_or(Negatives::Sign),
+        repeat_rest,
+        blank_as: conf.blank_as,
+        pad_side: conf.pad_side,
+        at: conf.at,
+        overlap,
+        bool_tokens: conf.bool_tokens,
+        on_overflow: conf.on_overflow,
+        min_digits: conf.min_digits,
+        sep: conf.sep,
+        scale: conf.scale,
+        prefix: conf.prefix,
+        allow_nonfinite: conf.allow_nonfinite.unwrap_or(false),
+        flags: conf.flags,
+        ipv4: conf.ipv4.unwrap_or(Ipv4::Dotted),
+        transform: conf.transform,
+        reject_blank: false,
+        decoding: parent.decoding(),
+        name: None,
+        description: None,
+    })
 }
 
 // TODO: confirm these need to be public
 struct StructConfigBuilder {
     strict: Option<bool>,
+    max_line_length: Option<usize>,
+    ascii_only: Option<bool>,
+    reject_blank: Option<bool>,
+    decoding: Option<Decoding>,
+    encoding: Option<Decoding>,
+    lines: Option<usize>,
+    from_fields: Option<bool>,
+    delimiter: Option<char>,
 }
 
 impl StructConfigBuilder {
     pub fn new() -> Self {
-        Self { strict: None }
+        Self {
+            strict: None,
+            max_line_length: None,
+            ascii_only: None,
+            reject_blank: None,
+            decoding: None,
+            encoding: None,
+            lines: None,
+            from_fields: None,
+            delimiter: None,
+        }
     }
 }
 
 pub(crate) struct StructConfig {
     strict: bool,
+    pub(crate) max_line_length: Option<usize>,
+    ascii_only: bool,
+    reject_blank: bool,
+    pub(crate) decoding: Decoding,
+    pub(crate) lines: Option<usize>,
+    pub(crate) from_fields: bool,
+    pub(crate) delimiter: Option<char>,
 }
 
 pub(crate) fn parse_struct_attributes(attrs: &[Attribute]) -> Result<StructConfig, MacroError> {
@@ -450,6 +1290,76 @@ pub(crate) fn parse_struct_attributes(attrs: &[Attribute]) -> Result<StructConfi
                 let old = conf.strict.replace(val);
                 check_none("strict", param.key_span(), old)?;
             }
+            "max_line_length" => {
+                let err = "Expected numeric value for max_line_length.";
+                let val: usize = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.max_line_length.replace(val);
+                check_none("max_line_length", param.key_span(), old)?;
+            }
+            "lines" => {
+                let err = "Expected a positive integer for lines.";
+                let val: usize = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .ok()
+                    .filter(|lines| *lines > 0)
+                    .ok_or_else(|| MacroError::new(err, param.value_span()))?;
+                let old = conf.lines.replace(val);
+                check_none("lines", param.key_span(), old)?;
+            }
+            "ascii_only" => {
+                let err = "Expected boolean value for parameter ascii_only.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.ascii_only.replace(val);
+                check_none("ascii_only", param.key_span(), old)?;
+            }
+            "reject_blank" => {
+                let err = "Expected boolean value for parameter reject_blank.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.reject_blank.replace(val);
+                check_none("reject_blank", param.key_span(), old)?;
+            }
+            "utf8" => {
+                let old = conf.decoding.replace(parse_utf8_mode(&param)?);
+                check_none("utf8", param.key_span(), old)?;
+                check_single_decoding(&param, conf.encoding)?;
+            }
+            "encoding" => {
+                let old = conf.encoding.replace(parse_encoding(&param)?);
+                check_none("encoding", param.key_span(), old)?;
+                check_single_decoding(&param, conf.decoding)?;
+            }
+            "from_fields" => {
+                let err = "Expected boolean value for parameter from_fields.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.from_fields.replace(val);
+                check_none("from_fields", param.key_span(), old)?;
+            }
+            "delimiter" => {
+                let err = "Expected a single character for delimiter.";
+                let val = param
+                    .char_value()
+                    .ok_or_else(|| MacroError::new(err, param.value_span()))?;
+                let old = conf.delimiter.replace(val);
+                check_none("delimiter", param.key_span(), old)?;
+            }
             key => {
                 return Err(MacroError::new(
                     format!("Unrecognized parameter \"{}\".", key).as_str(),
@@ -461,31 +1371,49 @@ pub(crate) fn parse_struct_attributes(attrs: &[Attribute]) -> Result<StructConfi
 
     let sc = StructConfig {
         strict: conf.strict.unwrap_or(STRICT_DEFAULT),
+        max_line_length: conf.max_line_length,
+        ascii_only: conf.ascii_only.unwrap_or(false),
+        reject_blank: conf.reject_blank.unwrap_or(false),
+        decoding: conf.decoding.or(conf.encoding).unwrap_or(Decoding::Utf8),
+        lines: conf.lines,
+        from_fields: conf.from_fields.unwrap_or(false),
+        delimiter: conf.delimiter,
     };
 
     Ok(sc)
 }
 
 struct EnumConfigBuilder {
+    discriminant: Option<usize>,
     ignore_others: Option<bool>,
     key_width: Option<usize>,
+    key_align: Option<Align>,
     strict: Option<bool>,
+    decoding: Option<Decoding>,
+    encoding: Option<Decoding>,
 }
 
 impl EnumConfigBuilder {
     pub fn new() -> Self {
         Self {
+            discriminant: None,
             ignore_others: None,
             key_width: None,
+            key_align: None,
             strict: None,
+            decoding: None,
+            encoding: None,
         }
     }
 }
 
 pub(crate) struct EnumConfig {
+    pub discriminant: Option<usize>,
     pub _ignore_others: bool, // TODO: implement
     pub key_width: usize,
+    pub key_align: Align,
     pub strict: bool,
+    pub decoding: Decoding,
 }
 
 pub(crate) fn parse_enum_attributes(
@@ -497,6 +1425,16 @@ pub(crate) fn parse_enum_attributes(
 
     for param in params {
         match param.key().as_str() {
+            "discriminant" => {
+                let err = "Expected numeric value for discriminant.";
+                let val: usize = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.discriminant.replace(val);
+                check_none("discriminant", param.key_span(), old)?;
+            }
             "ignore_others" => {
                 let err = "Expected true or false for ignore_others.";
                 let val: bool = param
@@ -517,6 +1455,27 @@ pub(crate) fn parse_enum_attributes(
                 let old = conf.key_width.replace(val);
                 check_none("key_width", param.key_span(), old)?;
             }
+            "key_align" => {
+                let err = "Expected values for key_align are \"left\" or \"right\".";
+                let val: Align = match param.value().to_string().parse() {
+                    Ok(Align::Full) | Err(_) => {
+                        return Err(MacroError::new(err, param.value_span()));
+                    }
+                    Ok(align) => align,
+                };
+                let old = conf.key_align.replace(val);
+                check_none("key_align", param.key_span(), old)?;
+            }
+            "utf8" => {
+                let old = conf.decoding.replace(parse_utf8_mode(&param)?);
+                check_none("utf8", param.key_span(), old)?;
+                check_single_decoding(&param, conf.encoding)?;
+            }
+            "encoding" => {
+                let old = conf.encoding.replace(parse_encoding(&param)?);
+                check_none("encoding", param.key_span(), old)?;
+                check_single_decoding(&param, conf.decoding)?;
+            }
             "strict" => {
                 let err = "Expected numeric value for key_width.";
                 let val: bool = param
@@ -544,30 +1503,124 @@ pub(crate) fn parse_enum_attributes(
     ))?;
 
     let ec = EnumConfig {
+        discriminant: conf.discriminant,
         _ignore_others: conf.ignore_others.unwrap_or(false),
         strict: conf.strict.unwrap_or(STRICT_DEFAULT),
         key_width,
+        key_align: conf.key_align.unwrap_or(Align::Left),
+        decoding: conf.decoding.or(conf.encoding).unwrap_or(Decoding::Utf8),
     };
 
     Ok(ec)
 }
 
+/// How the bytes of a record are decoded to text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Decoding {
+    /// UTF-8, where invalid sequences are an error
+    Utf8,
+    /// UTF-8, where invalid sequences are replaced with `U+FFFD`
+    Utf8Lossy,
+    /// ISO-8859-1, where each byte is the code point of the same value
+    Latin1,
+    /// Windows-1252, which differs from Latin-1 in the range `0x80..=0x9F`
+    Windows1252,
+}
+
+impl Decoding {
+    /// Whether the decoded text may be longer than the bytes it came from
+    ///
+    /// Only strict UTF-8 decodes to exactly the bytes that were read.
+    pub(crate) fn widens(&self) -> bool {
+        *self != Decoding::Utf8
+    }
+}
+
+/// Parses the `utf8` parameter
+fn parse_utf8_mode(param: &FieldParam) -> Result<Decoding, MacroError> {
+    match param.value().as_str() {
+        "strict" => Ok(Decoding::Utf8),
+        "lossy" => Ok(Decoding::Utf8Lossy),
+        _ => Err(MacroError::new(
+            "Expected values for utf8 are \"strict\" or \"lossy\".",
+            param.value_span(),
+        )),
+    }
+}
+
+/// Parses the `encoding` parameter
+///
+/// Encodings other than UTF-8 are decoded by `fixcol::Encoding`, which is only
+/// available with the `encoding` feature.
+fn parse_encoding(param: &FieldParam) -> Result<Decoding, MacroError> {
+    let decoding = match param.value().to_ascii_lowercase().as_str() {
+        "latin1" | "iso-8859-1" => Decoding::Latin1,
+        "windows-1252" | "cp1252" => Decoding::Windows1252,
+        _ => {
+            return Err(MacroError::new(
+                "Expected values for encoding are \"latin1\" or \"windows-1252\".",
+                param.value_span(),
+            ))
+        }
+    };
+
+    if !cfg!(feature = "encoding") {
+        return Err(MacroError::new(
+            "encoding requires the \"encoding\" feature of fixcol.",
+            param.key_span(),
+        ));
+    }
+
+    Ok(decoding)
+}
+
+/// Ensures only one of `utf8` and `encoding` is set
+fn check_single_decoding(param: &FieldParam, other: Option<Decoding>) -> Result<(), MacroError> {
+    match other {
+        Some(_) => Err(MacroError::new(
+            "encoding cannot be combined with utf8.",
+            param.key_span(),
+        )),
+        None => Ok(()),
+    }
+}
+
 pub(crate) struct VariantConfigBuilder {
     key: Option<String>,
+    label: Option<String>,
+    catch_all: Option<bool>,
+    key_width: Option<usize>,
     embed: Option<bool>,
+    embed_span: Option<Span>,
     strict: Option<bool>,
 }
 
 impl VariantConfigBuilder {
     pub fn new() -> Self {
-        Self { key: None, embed: None, strict: None }
+        Self {
+            key: None,
+            label: None,
+            catch_all: None,
+            key_width: None,
+            embed: None,
+            embed_span: None,
+            strict: None,
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct VariantConfig {
     pub key: String,
+    pub label: String,
+    pub key_width: usize,
+    pub key_align: Align,
+    pub discriminated: bool,
+    pub decoding: Decoding,
+    pub catch_all: bool,
     pub embed: bool,
+    /// Location of the `embed` parameter, for errors about embedded variants
+    pub embed_span: Span,
     pub strict: bool,
 }
 
@@ -585,6 +1638,30 @@ pub(crate) fn parse_variant_attributes(
                 let old = conf.key.replace(param.value());
                 check_none("key", param.key_span(), old)?;
             }
+            "label" => {
+                let old = conf.label.replace(param.value());
+                check_none("label", param.key_span(), old)?;
+            }
+            "key_width" => {
+                let err = "Expected numeric value for key_width.";
+                let val: usize = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.key_width.replace(val);
+                check_none("key_width", param.key_span(), old)?;
+            }
+            "catch_all" => {
+                let err = "Expected true or false for catch_all.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = conf.catch_all.replace(val);
+                check_none("catch_all", param.key_span(), old)?;
+            }
             "embed" => {
                 let err = "Expected true or false for embed.";
                 let val: bool = param
@@ -594,6 +1671,7 @@ pub(crate) fn parse_variant_attributes(
                     .map_err(|_| MacroError::new(err, param.value_span()))?;
                 let old = conf.embed.replace(val);
                 check_none("embed", param.key_span(), old)?;
+                conf.embed_span = Some(param.key_span());
             }
             "strict" => {
                 let err = "Expected numeric value for key_width.";
@@ -614,21 +1692,137 @@ pub(crate) fn parse_variant_attributes(
         }
     }
 
-    let key = conf.key.ok_or(MacroError::new(
-        "The parameter key must be provided for all enum variants.\n\n \
-        Try adding #[fixcol(key = \"<my key>\")] to this variant.",
-        name.span(),
-    ))?;
+    let catch_all = conf.catch_all.unwrap_or(false);
+    if catch_all && (conf.key.is_some() || conf.key_width.is_some()) {
+        return Err(MacroError::new(
+            "A catch_all variant matches any unrecognized key and cannot have a key.",
+            name.span(),
+        ));
+    }
+    if catch_all && conf.embed.unwrap_or(false) {
+        return Err(MacroError::new(
+            "catch_all cannot be combined with embed.",
+            name.span(),
+        ));
+    }
+
+    // The catch-all variant holds the whole line, so it has no key of its own
+    let key = match conf.key {
+        Some(key) => key,
+        None if catch_all => String::new(),
+        None => {
+            return Err(MacroError::new(
+                "The parameter key must be provided for all enum variants.\n\n \
+                Try adding #[fixcol(key = \"<my key>\")] to this variant.",
+                name.span(),
+            ))
+        }
+    };
+
+    if conf.key_width.is_some() && parent.discriminant.is_some() {
+        return Err(MacroError::new(
+            "key_width cannot be overridden on variants of an enum with a discriminant.",
+            name.span(),
+        ));
+    }
+
+    // Shorter keys are space padded to the full key width
+    let key_width = match catch_all {
+        true => 0,
+        false => conf.key_width.unwrap_or(parent.key_width),
+    };
+    let key = match parent.key_align {
+        Align::Right => format!("{:>width$}", key, width = key_width),
+        _ => format!("{:<width$}", key, width = key_width),
+    };
 
     let vc = VariantConfig {
         key,
+        label: conf.label.unwrap_or_else(|| name.to_string()),
+        key_width,
+        key_align: parent.key_align,
+        discriminated: parent.discriminant.is_some(),
+        decoding: parent.decoding,
+        catch_all,
         embed: conf.embed.unwrap_or(false),
+        embed_span: conf.embed_span.unwrap_or_else(|| name.span()),
         strict: conf.strict.unwrap_or(parent.strict),
     };
 
     Ok(vc)
 }
 
+/// Reads the enum level `match_display` flag of a `FixcolEnum` code table
+pub(crate) fn parse_code_enum_attributes(attrs: &[Attribute]) -> Result<bool, MacroError> {
+    let params = parse_attributes(attrs)?;
+    let mut match_display: Option<bool> = None;
+
+    for param in params {
+        match param.key().as_str() {
+            "match_display" => {
+                let err = "Expected boolean value for match_display.";
+                let val: bool = param
+                    .value()
+                    .to_string()
+                    .parse()
+                    .map_err(|_| MacroError::new(err, param.value_span()))?;
+                let old = match_display.replace(val);
+                check_none("match_display", param.key_span(), old)?;
+            }
+            key => {
+                return Err(MacroError::new(
+                    format!("Unrecognized parameter \"{}\".", key).as_str(),
+                    param.key_span(),
+                ));
+            }
+        }
+    }
+
+    Ok(match_display.unwrap_or(false))
+}
+
+/// Parses the `value` token of a variant of a `FixcolEnum` code table
+///
+/// When `match_display` is set the variant is identified by its `Display`
+/// text instead, and `value` is not allowed.
+pub(crate) fn parse_code_attributes(
+    name: &Ident,
+    attrs: &[Attribute],
+    match_display: bool,
+) -> Result<Option<String>, MacroError> {
+    let params = parse_attributes(attrs)?;
+    let mut value: Option<String> = None;
+
+    for param in params {
+        match param.key().as_str() {
+            "value" => {
+                let old = value.replace(param.value());
+                check_none("value", param.key_span(), old)?;
+            }
+            key => {
+                return Err(MacroError::new(
+                    format!("Unrecognized parameter \"{}\".", key).as_str(),
+                    param.key_span(),
+                ));
+            }
+        }
+    }
+
+    match (value, match_display) {
+        (Some(_), true) => Err(MacroError::new(
+            "value cannot be combined with match_display.",
+            name.span(),
+        )),
+        (None, true) => Ok(None),
+        (Some(value), false) => Ok(Some(value)),
+        (None, false) => Err(MacroError::new(
+            "The parameter value must be provided for all variants.\n\n \
+            Try adding #[fixcol(value = \"<my value>\")] to this variant.",
+            name.span(),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // TODO: needs tests not just of parsing but all the way to the field config
@@ -668,7 +1862,7 @@ mod tests {
         let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
 
         assert_eq!(params.len(), 1);
-        assert_eq!(*(params.get(0)).unwrap(), expected);
+        assert_eq!(*(params.first()).unwrap(), expected);
     }
 
     #[test]
@@ -703,7 +1897,63 @@ mod tests {
         let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
 
         assert_eq!(params.len(), 1);
-        assert_eq!(*(params.get(0)).unwrap(), expected);
+        assert_eq!(*(params.first()).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_bare_flag() {
+        let expected = FieldParam::test("strict", "\"true\"");
+        let code: MetaList = syn::parse_str("fixcol(strict)").unwrap();
+        let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
+
+        assert_eq!(params, vec![expected]);
+    }
+
+    #[test]
+    fn parse_bare_flags_mixed() {
+        let expected = vec![
+            FieldParam::test("embed", "\"true\""),
+            FieldParam::test("strict", "\"false\""),
+            FieldParam::test("ignore_others", "\"true\""),
+            FieldParam::test("width", "3"),
+        ];
+        let code: MetaList =
+            syn::parse_str("fixcol(embed, strict = false, ignore_others, width = 3)").unwrap();
+        let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
+
+        assert_eq!(params, expected);
+    }
+
+    #[test]
+    fn parse_path_value() {
+        let code: MetaList =
+            syn::parse_str("fixcol(width = layout::NAME, skip = 1, align = right)").unwrap();
+        let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
+
+        assert_eq!(params.len(), 3);
+        assert_eq!(params[0].value(), "layout::NAME");
+        let expected: Path = syn::parse_str("layout::NAME").unwrap();
+        assert_eq!(params[0].path(), Some(expected));
+        assert_eq!(params[2].value(), "right");
+    }
+
+    #[test]
+    fn parse_char_value() {
+        let code: MetaList =
+            syn::parse_str("fixcol(sep = ',', pad = \"_\", tab = '\\t', wide = \"ab\")").unwrap();
+        let params: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
+
+        assert_eq!(params[0].char_value(), Some(','));
+        assert_eq!(params[1].char_value(), Some('_'));
+        assert_eq!(params[2].char_value(), Some('\t'));
+        assert_eq!(params[3].char_value(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected assignment ('=' character).")]
+    fn parse_params_ident_only_followed() {
+        let code: MetaList = syn::parse_str("fixcol(width, strict)").unwrap();
+        let _: Vec<FieldParam> = get_config_params(code.tokens).unwrap();
     }
 
     #[test]
@@ -0,0 +1,121 @@
+use quote::quote;
+use syn::{Ident, Variant};
+
+use crate::attrs::parse_code_attributes;
+use crate::error::{MacroError, MacroResult};
+
+/// Reads the `value` token of each variant of a code table enum
+///
+/// When `match_display` is set no variant has a `value` token and the list of
+/// values is empty.
+fn code_values<'a>(
+    variants: &[&'a Variant],
+    match_display: bool,
+) -> Result<(Vec<&'a Ident>, Vec<String>), MacroError> {
+    let mut idents: Vec<&Ident> = Vec::new();
+    let mut values: Vec<String> = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.ident;
+
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(MacroError::new(
+                "FixcolEnum is only valid on enums with unit variants",
+                ident.span(),
+            ));
+        }
+
+        let value = parse_code_attributes(ident, &variant.attrs, match_display)?;
+        if let Some(value) = value {
+            if values.contains(&value) {
+                return Err(MacroError::new(
+                    format!("Duplicate value \"{}\".", value).as_str(),
+                    ident.span(),
+                ));
+            }
+
+            values.push(value);
+        }
+
+        idents.push(ident);
+    }
+
+    Ok((idents, values))
+}
+
+/// Generates a `FixedDeserializer` mapping each variant's `value` token to
+/// the variant
+///
+/// With `match_display` the column is compared against each variant's
+/// `Display` text instead.
+pub(crate) fn code_read(variants: &[&Variant], match_display: bool) -> MacroResult {
+    let (idents, values) = code_values(variants, match_display)?;
+
+    if match_display {
+        return Ok(quote! {
+            fn parse_fixed(
+                s: &str,
+                desc: &fixcol::FieldDescription,
+            ) -> Result<Self, fixcol::error::DataError> {
+                let text = desc.extract(s)?;
+
+                #(
+                    if fixcol::display_matches(&Self::#idents, text) {
+                        return Ok(Self::#idents);
+                    }
+                )*
+
+                Err(fixcol::display_mismatch::<Self>(text, &[#(Self::#idents),*]))
+            }
+        });
+    }
+
+    let expected: Vec<String> = values.iter().map(|v| format!("{:?}", v)).collect();
+    let message = format!(
+        "Unrecognized value. Expected one of {}",
+        expected.join(", ")
+    );
+
+    Ok(quote! {
+        fn parse_fixed(
+            s: &str,
+            desc: &fixcol::FieldDescription,
+        ) -> Result<Self, fixcol::error::DataError> {
+            let text = desc.extract(s)?;
+
+            match text {
+                #(#values => Ok(Self::#idents),)*
+                _ => Err(fixcol::error::DataError::custom(text, #message)),
+            }
+        }
+    })
+}
+
+/// Generates a `FixedSerializer` writing each variant as its `value` token
+///
+/// With `match_display` each variant is written as its `Display` text.
+pub(crate) fn code_write(variants: &[&Variant], match_display: bool) -> MacroResult {
+    let (idents, values) = code_values(variants, match_display)?;
+
+    let value = if match_display {
+        quote! { &self.to_string() }
+    } else {
+        quote! {
+            match *self {
+                #(Self::#idents => #values,)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        fn write_fixed_field<W: std::io::Write>(
+            &self,
+            buf: &mut W,
+            desc: &fixcol::FieldDescription,
+        ) -> Result<(), fixcol::error::Error> {
+            let value: &str = #value;
+
+            fixcol::FixedSerializer::write_fixed_field(value, buf, desc)
+        }
+    })
+}
@@ -4,11 +4,12 @@ use syn::spanned::Spanned;
 use syn::{Attribute, FieldsNamed, FieldsUnnamed, Ident, Variant};
 
 use crate::attrs::{
-    fixcol_attrs, parse_enum_attributes, parse_variant_attributes, OuterConfig, VariantConfig,
+    fixcol_attrs, parse_enum_attributes, parse_variant_attributes, Align, Columns, Decoding,
+    FieldConfig, OuterConfig, VariantConfig,
 };
 use crate::error::{MacroError, MacroResult};
 use crate::fields::{
-    read_named_fields, read_unnamed_fields, write_named_fields, write_unnamed_fields,
+    decode_text, read_named_fields, read_unnamed_fields, write_named_fields, write_unnamed_fields,
 };
 
 //
@@ -18,46 +19,131 @@ use crate::fields::{
 pub(crate) fn enum_read(name: &Ident, attrs: &[Attribute], variants: Vec<&Variant>) -> MacroResult {
     let enum_config = parse_enum_attributes(name, attrs)?;
 
-    let items: Result<Vec<(String, TokenStream)>, MacroError> = variants
-        .iter()
-        .map(|variant| -> Result<(String, TokenStream), MacroError> {
-            let var_name = &variant.ident;
+    let mut items: Vec<(String, usize, TokenStream)> = Vec::new();
+    let mut catch_all: Option<TokenStream> = None;
 
-            let config: VariantConfig =
-                parse_variant_attributes(var_name, &variant.attrs, &enum_config)?;
-            let key = config.key.clone();
+    // TODO: Gather all the errors instead of just the first
+    for variant in variants {
+        let var_name = &variant.ident;
 
-            let read = match &variant.fields {
-                syn::Fields::Named(fields) => read_struct_variant(var_name, fields, config.into())?,
-                syn::Fields::Unnamed(fields) if config.embed => {
-                    read_embedded_variant(var_name, fields)?
-                }
-                syn::Fields::Unnamed(fields) => {
-                    read_tuple_variant(var_name, fields, &config.into())?
-                }
-                syn::Fields::Unit => read_unit_variant(var_name),
-            };
+        let config: VariantConfig =
+            parse_variant_attributes(var_name, &variant.attrs, &enum_config)?;
 
-            Ok((key, read))
-        })
-        .collect(); // TODO: Gather all the errors instead of just the first
+        if config.catch_all {
+            let read = read_catch_all_variant(variant)?;
+            if catch_all.replace(read).is_some() {
+                return Err(MacroError::new(
+                    "Only one variant may be marked catch_all.",
+                    var_name.span(),
+                ));
+            }
+            continue;
+        }
+
+        let key = config.key.clone();
+        let key_width = config.key_width;
 
-    let (var_name, var_read): (Vec<String>, Vec<TokenStream>) = items?.into_iter().unzip();
+        // A second variant with the same key could never be read
+        if items.iter().any(|(other, _, _)| *other == key) {
+            return Err(MacroError::new(
+                format!("Duplicate key \"{}\".", key.trim()).as_str(),
+                var_name.span(),
+            ));
+        }
+
+        let read = match &variant.fields {
+            syn::Fields::Named(fields) => read_struct_variant(var_name, fields, config.into())?,
+            syn::Fields::Unnamed(fields) if config.embed => {
+                read_embedded_variant(var_name, &config, fields)?
+            }
+            syn::Fields::Unnamed(fields) => read_tuple_variant(var_name, fields, &config.into())?,
+            syn::Fields::Unit => read_unit_variant(var_name),
+        };
+
+        items.push((key, key_width, read));
+    }
 
     let key_width = enum_config.key_width;
+    if items.iter().any(|(_, width, _)| *width != key_width) {
+        return Ok(read_mixed_width_keys(
+            items,
+            catch_all,
+            enum_config.decoding,
+        ));
+    }
+
+    let decoding = enum_config.decoding;
+    let decode_line = decode_text(quote! { line }, decoding);
+    let decode_key = decode_text(quote! { s.to_vec() }, decoding);
+    let decode_column = decode_text(quote! { key.to_vec() }, decoding);
+
+    let (var_name, var_read): (Vec<String>, Vec<TokenStream>) =
+        items.into_iter().map(|(key, _, read)| (key, read)).unzip();
+
+    // A discriminant column is read from within the record, which is then read
+    // again from its start by the selected variant. Otherwise the key is a
+    // prefix consumed before the variant's fields. Either way only the key is
+    // decoded here, so the variant's fields are decoded from the original bytes.
+    let read_key = match enum_config.discriminant {
+        Some(column) => quote! {
+            use std::io::Read;
+
+            let mut line: Vec<u8> = Vec::new();
+            buf.read_to_end(&mut line).map_err(|e| fixcol::error::Error::from(e))?;
+            let key = line
+                .get(#column..#column + #key_width)
+                .unwrap_or_else(|| line.get(#column..).unwrap_or(&[]));
+            let key: String = #decode_column;
+            let buf = &mut line.as_slice();
+        },
+        None => quote! {
+            use std::io::Read;
+
+            let mut s: Vec<u8> = Vec::new();
+            Read::by_ref(buf)
+                .take(#key_width as u64)
+                .read_to_end(&mut s)
+                .map_err(|e| fixcol::error::Error::from(e))?;
+            if s.len() < #key_width {
+                let text = String::from_utf8_lossy(&s).into_owned();
+                let message = format!("Line too short to contain a key of width {}", #key_width);
+                return Err(fixcol::error::DataError::custom(&text, &message).into());
+            }
+            let key: String = #decode_key;
+        },
+    };
+
+    // The catch-all variant is given the whole line. With a discriminant the
+    // line has already been read, otherwise the key is rejoined with the rest.
+    let unknown_key = match (catch_all, enum_config.discriminant) {
+        (Some(read), Some(_)) => quote! {
+            _ => {
+                let line: String = #decode_line;
+                #read
+            }
+        },
+        (Some(read), None) => quote! {
+            _ => {
+                let mut line = key.clone().into_bytes();
+                buf.read_to_end(&mut line).map_err(|e| fixcol::error::Error::from(e))?;
+                let line: String = #decode_line;
+                #read
+            }
+        },
+        (None, _) => quote! {
+            k => Err(fixcol::error::Error::unknown_key_error(k.to_owned())),
+        },
+    };
 
     let fun = quote! {
         fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
             use fixcol::FixedDeserializer;
 
-            let mut s: [u8; #key_width] = [0; #key_width];
-            buf.read_exact(&mut s).map_err(|e| fixcol::error::Error::from(e))?;
-            let key: String = String::from_utf8(s.to_vec())
-                .map_err(|e| fixcol::error::Error::from(e))?;
+            #read_key
 
             match key.as_str() {
                 #(#var_name => { #var_read },)*
-                k => Err(fixcol::error::Error::unknown_key_error(k.to_owned())),
+                #unknown_key
             }
         }
     };
@@ -65,6 +151,205 @@ pub(crate) fn enum_read(name: &Ident, attrs: &[Attribute], variants: Vec<&Varian
     Ok(fun)
 }
 
+/// Reads an enum where some variants override the key width
+///
+/// Enough bytes for the widest key are read and compared against each key,
+/// longest first. Any bytes beyond the matched key are chained ahead of the
+/// buffer so the variant's fields read them first.
+fn read_mixed_width_keys(
+    mut items: Vec<(String, usize, TokenStream)>,
+    catch_all: Option<TokenStream>,
+    decoding: Decoding,
+) -> TokenStream {
+    items.sort_by_key(|(_, width, _)| std::cmp::Reverse(*width));
+    let max_width = items.first().map(|(_, width, _)| *width).unwrap_or(0) as u64;
+
+    let branches = items.into_iter().map(|(key, width, read)| {
+        quote! {
+            if s.starts_with(#key.as_bytes()) {
+                let mut __fixcol_chain = std::io::Read::chain(&s[#width..], &mut *buf);
+                let buf = &mut __fixcol_chain;
+                #read
+            } else
+        }
+    });
+
+    let decode_line = decode_text(quote! { line }, decoding);
+    let unknown_key = match catch_all {
+        Some(read) => quote! {
+            let mut line = s;
+            buf.read_to_end(&mut line).map_err(|e| fixcol::error::Error::from(e))?;
+            let line: String = #decode_line;
+            #read
+        },
+        None => quote! {
+            let key = String::from_utf8_lossy(&s).into_owned();
+            Err(fixcol::error::Error::unknown_key_error(key))
+        },
+    };
+
+    quote! {
+        fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
+            use fixcol::FixedDeserializer;
+            use std::io::Read;
+
+            let mut s: Vec<u8> = Vec::new();
+            Read::by_ref(buf)
+                .take(#max_width)
+                .read_to_end(&mut s)
+                .map_err(|e| fixcol::error::Error::from(e))?;
+
+            #(#branches)* {
+                #unknown_key
+            }
+        }
+    }
+}
+
+/// Generates `total_width`, the width of the widest variant including the key
+///
+/// Embedded variants only count their key since the width of the inner type
+/// is not known to the macro. A discriminant column lies within the variant's
+/// fields so only extends the width if it falls past the widest variant.
+pub(crate) fn enum_total_width(
+    name: &Ident,
+    attrs: &[Attribute],
+    variants: &[&Variant],
+) -> MacroResult {
+    let enum_config = parse_enum_attributes(name, attrs)?;
+
+    let widths: Result<Vec<Columns>, MacroError> = variants
+        .iter()
+        .map(|variant| -> Result<Columns, MacroError> {
+            let config = parse_variant_attributes(&variant.ident, &variant.attrs, &enum_config)?;
+            if config.catch_all {
+                return Ok(Columns::default());
+            }
+
+            let key_width = match enum_config.discriminant {
+                Some(_) => 0,
+                None => config.key_width,
+            };
+
+            let configs = match &variant.fields {
+                syn::Fields::Named(fields) => write_named_fields(fields, &config.into())?.1,
+                syn::Fields::Unnamed(_) if config.embed => Vec::new(),
+                syn::Fields::Unnamed(fields) => write_unnamed_fields(fields, &config.into())?.1,
+                syn::Fields::Unit => Vec::new(),
+            };
+
+            let mut width = Columns::from(key_width);
+            width += configs.iter().map(FieldConfig::columns).sum();
+
+            Ok(width)
+        })
+        .collect();
+    let widths = widths?;
+
+    let discriminant = enum_config
+        .discriminant
+        .map(|column| column + enum_config.key_width);
+
+    // Widths set by constants are compared when the method is called
+    let known: Option<Vec<usize>> = widths.iter().map(Columns::known).collect();
+    let total_width = match known {
+        Some(known) => {
+            let widest = known.into_iter().max().unwrap_or(0);
+            let total_width = widest.max(discriminant.unwrap_or(0));
+            quote! { #total_width }
+        }
+        None => {
+            let discriminant = discriminant.unwrap_or(0);
+            quote! { [#discriminant, #(#widths),*].into_iter().max().unwrap_or(0) }
+        }
+    };
+
+    Ok(quote! {
+        /// Returns the width of the widest variant, including the key
+        ///
+        /// Embedded variants only count the width of their key.
+        pub fn total_width() -> usize {
+            #total_width
+        }
+    })
+}
+
+/// Generates the `WIDTH` of a `FixedWidth` implementation for enums of keyed
+/// unit variants
+///
+/// Such an enum is read from exactly its key, so a field of the enum's type
+/// may take the enum's `key_width` as its width. Other enums have no fixed
+/// width and return `None`.
+pub(crate) fn enum_fixed_width(
+    name: &Ident,
+    attrs: &[Attribute],
+    variants: &[&Variant],
+) -> Result<Option<TokenStream>, MacroError> {
+    let enum_config = parse_enum_attributes(name, attrs)?;
+    if enum_config.discriminant.is_some() {
+        return Ok(None);
+    }
+
+    for variant in variants {
+        let config = parse_variant_attributes(&variant.ident, &variant.attrs, &enum_config)?;
+        let unit = matches!(variant.fields, syn::Fields::Unit);
+
+        if !unit || config.catch_all || config.key_width != enum_config.key_width {
+            return Ok(None);
+        }
+    }
+
+    let key_width = enum_config.key_width;
+    Ok(Some(quote! { const WIDTH: usize = #key_width; }))
+}
+
+/// Generates `variant_labels` and `variant_label`, which report the label of
+/// each variant alongside its key
+///
+/// A variant's label defaults to its name. The catch-all variant has no key
+/// so is omitted from `variant_labels`.
+pub(crate) fn enum_labels(name: &Ident, attrs: &[Attribute], variants: &[&Variant]) -> MacroResult {
+    let enum_config = parse_enum_attributes(name, attrs)?;
+
+    let mut keys: Vec<String> = Vec::new();
+    let mut keyed_labels: Vec<String> = Vec::new();
+    let mut patterns: Vec<TokenStream> = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.ident;
+        let config = parse_variant_attributes(ident, &variant.attrs, &enum_config)?;
+
+        if !config.catch_all {
+            keys.push(config.key.clone());
+            keyed_labels.push(config.label.clone());
+        }
+
+        patterns.push(match &variant.fields {
+            syn::Fields::Named(_) => quote! { Self::#ident { .. } },
+            syn::Fields::Unnamed(_) => quote! { Self::#ident(..) },
+            syn::Fields::Unit => quote! { Self::#ident },
+        });
+        labels.push(config.label);
+    }
+
+    Ok(quote! {
+        /// Returns the key and label of each variant
+        ///
+        /// Keys are padded to their key width as they appear in a record.
+        pub fn variant_labels() -> &'static [(&'static str, &'static str)] {
+            &[#((#keys, #keyed_labels)),*]
+        }
+
+        /// Returns the label of this variant
+        pub fn variant_label(&self) -> &'static str {
+            match *self {
+                #(#patterns => #labels,)*
+            }
+        }
+    })
+}
+
 fn read_struct_variant(name: &Ident, fields: &FieldsNamed, outer: OuterConfig) -> MacroResult {
     let (field_names, field_reads) = read_named_fields(fields, outer)?;
 
@@ -76,15 +361,30 @@ fn read_struct_variant(name: &Ident, fields: &FieldsNamed, outer: OuterConfig) -
     Ok(read_code)
 }
 
+/// Checks that an `embed` variant wraps exactly one field
+fn check_embedded_fields(config: &VariantConfig, fields: &FieldsUnnamed) -> Result<(), MacroError> {
+    match fields.unnamed.len() {
+        1 => Ok(()),
+        n => Err(MacroError::new(
+            format!(
+                "embed is only valid on variants with exactly one field, found {}.\n\n \
+                Try removing embed to read the fields as an ordinary tuple variant.",
+                n
+            )
+            .as_str(),
+            config.embed_span,
+        )),
+    }
+}
+
 // TODO: figure out how to do strict cascade into embedded variants
 // deleted: "outer: OuterConfig" from the function arguments
-fn read_embedded_variant(name: &Ident, fields: &FieldsUnnamed) -> MacroResult {
-    if fields.unnamed.len() != 1 {
-        return Err(MacroError::new(
-            "Embed param is only valid on variants with exactly one field",
-            fields.span(),
-        ));
-    }
+fn read_embedded_variant(
+    name: &Ident,
+    config: &VariantConfig,
+    fields: &FieldsUnnamed,
+) -> MacroResult {
+    check_embedded_fields(config, fields)?;
     if let Some(field) = fields.unnamed.first() {
         if let Some(fa) = fixcol_attrs(&field.attrs).first() {
             return Err(MacroError::new(
@@ -95,8 +395,10 @@ fn read_embedded_variant(name: &Ident, fields: &FieldsUnnamed) -> MacroResult {
 
         let inner_type = field.ty.clone();
 
+        // The inner type reads from the columns following the outer key. If it
+        // is itself a keyed enum it will read its own key from that position.
         let code = quote! {
-            let elem = #inner_type::read_fixed(buf)?;
+            let elem = <#inner_type>::read_fixed(buf)?;
             Ok(Self::#name(elem))
         };
 
@@ -120,6 +422,37 @@ fn read_tuple_variant(
     })
 }
 
+/// Returns the type held by a catch-all variant
+fn catch_all_type(variant: &Variant) -> Result<&syn::Type, MacroError> {
+    let err = "catch_all is only valid on variants with exactly one unnamed field";
+
+    let field = match &variant.fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+        _ => None,
+    }
+    .ok_or_else(|| MacroError::new(err, variant.ident.span()))?;
+
+    if let Some(fa) = fixcol_attrs(&field.attrs).first() {
+        return Err(MacroError::new(
+            "Did not expect fixcol attribute on catch_all enum variant",
+            fa.meta.path().span(),
+        ));
+    }
+
+    Ok(&field.ty)
+}
+
+/// Reads the catch-all variant from the full text of the line in `line`
+fn read_catch_all_variant(variant: &Variant) -> MacroResult {
+    let name = &variant.ident;
+    let inner_type = catch_all_type(variant)?;
+
+    Ok(quote! {
+        let elem = <#inner_type>::read_fixed_str(&line)?;
+        Ok(Self::#name(elem))
+    })
+}
+
 fn read_unit_variant(
     // key: String,
     name: &Ident,
@@ -143,6 +476,7 @@ pub(crate) fn enum_write(name: &Ident, attrs: &[Attribute], variants: &[&Variant
                 parse_variant_attributes(&variant.ident, &variant.attrs, &enum_config).unwrap(); // TODO: need to do this for write macros also
 
             let out = match &variant.fields {
+                _ if config.catch_all => write_catch_all_variant(variant, &config)?,
                 syn::Fields::Named(fields) => {
                     write_struct_variant(&variant.ident, &config, fields)?
                 }
@@ -161,43 +495,95 @@ pub(crate) fn enum_write(name: &Ident, attrs: &[Attribute], variants: &[&Variant
 
     let write_variants = write_variants?;
 
-    let code = quote! {
-        fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
-            use fixcol::FixedSerializer;
-
-            match self {
-                #(#write_variants)*
+    let code = match enum_config.discriminant {
+        // The record is written to a local buffer so the key can be placed
+        // over the discriminant column once the variant's fields are written
+        Some(column) => {
+            let end = column + enum_config.key_width;
+
+            quote! {
+                fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
+                    use fixcol::FixedSerializer;
+
+                    let __fixcol_key: Option<&str>;
+                    let mut __fixcol_record: Vec<u8> = Vec::new();
+                    {
+                        let buf = &mut __fixcol_record;
+                        match self {
+                            #(#write_variants)*
+                        }
+                    }
+
+                    if let Some(key) = __fixcol_key {
+                        if __fixcol_record.len() < #end {
+                            __fixcol_record.resize(#end, b' ');
+                        }
+                        __fixcol_record[#column..#end].copy_from_slice(key.as_bytes());
+                    }
+                    buf.write_all(&__fixcol_record)?;
+
+                    Ok(())
+                }
             }
-
-            Ok(())
         }
+        None => quote! {
+            fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
+                use fixcol::FixedSerializer;
+
+                match self {
+                    #(#write_variants)*
+                }
+
+                Ok(())
+            }
+        },
     };
 
     Ok(code)
 }
 
+/// Code to write the key identifying a variant
+///
+/// With a discriminant column the key is only recorded here and written over
+/// that column after the variant's fields.
+// TODO: we may want to inherit strict for the key from the enum or variant
+fn write_key(config: &VariantConfig, strict: bool) -> TokenStream {
+    let key = config.key.as_str();
+    let key_len = key.len();
+
+    if config.discriminated {
+        return quote! {
+            __fixcol_key = Some(#key);
+        };
+    }
+
+    // The key is already padded to the key width, so this only describes it
+    let alignment = match config.key_align {
+        Align::Right => quote! { fixcol::Alignment::Right },
+        _ => quote! { fixcol::Alignment::Left },
+    };
+
+    quote! {
+        let key_config = fixcol::FieldDescription::new(0, #key_len, #alignment, #strict);
+        let key = String::from(#key);
+        let _ = key.write_fixed_field(buf, &key_config)?;
+    }
+}
+
 fn write_struct_variant(
     ident: &Ident,
     config: &VariantConfig,
     fields: &FieldsNamed,
 ) -> MacroResult {
-    let key = config.key.to_owned();
-    let key_len = key.len();
     let (names, configs) = write_named_fields(fields, &(*config).clone().into())?;
+    let trailing: Vec<_> = configs.iter().map(FieldConfig::write_trailing).collect();
+    let write_key = write_key(config, false);
 
-    // TODO: we may want to inherit strict for the key from the enum or variant
     let code = quote! {
-        Self::#ident { #(#names),* } => {
-            let key_config = fixcol::FieldDescription {
-                skip: 0,
-                len: #key_len,
-                alignment: fixcol::Alignment::Left,
-                strict: false,
-            };
-            let key = String::from(#key);
-            let _ = key.write_fixed_field(buf, &key_config)?;
+        Self::#ident { #(#names,)* .. } => {
+            #write_key
 
-            #( let _ = #names.write_fixed_field(buf, #configs)?;  )*
+            #( let _ = #names.write_fixed_field(buf, #configs)?; #trailing )*
         },
     };
 
@@ -209,30 +595,32 @@ fn write_tuple_variant(
     config: &VariantConfig,
     fields: &FieldsUnnamed,
 ) -> MacroResult {
-    let (_, configs) = write_unnamed_fields(fields, &config.clone().into())?;
-    let VariantConfig { key, strict, .. } = config;
+    let (indices, configs) = write_unnamed_fields(fields, &config.clone().into())?;
+    let trailing: Vec<_> = configs.iter().map(FieldConfig::write_trailing).collect();
+    let VariantConfig { strict, .. } = config;
 
-    let named_fields: Vec<Ident> = configs
+    let named_fields: Vec<Ident> = indices
         .iter()
-        .enumerate()
-        .map(|f| format_ident!("f_{}", f.0))
+        .map(|index| format_ident!("f_{}", index.index))
         .collect();
 
-    let key_len = key.len();
+    // Fields that are not written, such as overlapping fields, are ignored
+    let patterns = (0..fields.unnamed.len()).map(|i| {
+        if indices.iter().any(|index| index.index as usize == i) {
+            let field = format_ident!("f_{}", i);
+            quote! { #field }
+        } else {
+            quote! { _ }
+        }
+    });
+
+    let write_key = write_key(config, *strict);
 
-    // TODO: we may want to inherit strict for the key from the enum or variant
     let code = quote! {
-        Self::#ident(#(#named_fields),*) => {
-            let key_config = fixcol::FieldDescription {
-                skip: 0,
-                len: #key_len,
-                alignment: fixcol::Alignment::Left,
-                strict: #strict,
-            };
-            let key = String::from(#key);
-            let _ = key.write_fixed_field(buf, &key_config)?;
+        Self::#ident(#(#patterns),*) => {
+            #write_key
 
-            #( let _ = #named_fields.write_fixed_field(buf, #configs)?;  )*
+            #( let _ = #named_fields.write_fixed_field(buf, #configs)?; #trailing )*
         },
     };
 
@@ -244,12 +632,7 @@ fn write_embedded_variant(
     config: &VariantConfig,
     fields: &FieldsUnnamed,
 ) -> MacroResult {
-    if fields.unnamed.len() != 1 {
-        return Err(MacroError::new(
-            "Embed param is only valid on variants with exactly one field",
-            fields.span(),
-        ));
-    }
+    check_embedded_fields(config, fields)?;
 
     if let Some(field) = fields.unnamed.first() {
         if let Some(fa) = fixcol_attrs(&field.attrs).first() {
@@ -259,20 +642,11 @@ fn write_embedded_variant(
             ));
         }
 
-        let key_len = config.key.len();
-        let key = config.key.clone();
+        let write_key = write_key(config, false);
 
-        // TODO: we may want to inherit strict for the key from the enum or variant
         let gen = quote! {
             Self::#ident(inner) => {
-                let key_config = fixcol::FieldDescription {
-                    skip: 0,
-                    len: #key_len,
-                    alignment: fixcol::Alignment::Left,
-                    strict: false,
-                };
-                let key = String::from(#key);
-                let _ = key.write_fixed_field(buf, &key_config)?;
+                #write_key
 
                 inner.write_fixed(buf)?;
             }
@@ -284,21 +658,31 @@ fn write_embedded_variant(
     }
 }
 
+/// Writes the line held by a catch-all variant verbatim, without a key
+fn write_catch_all_variant(variant: &Variant, config: &VariantConfig) -> MacroResult {
+    let ident = &variant.ident;
+    catch_all_type(variant)?;
+
+    let no_key = match config.discriminated {
+        true => quote! { __fixcol_key = None; },
+        false => quote! {},
+    };
+
+    Ok(quote! {
+        Self::#ident(inner) => {
+            #no_key
+
+            inner.write_fixed(buf)?;
+        }
+    })
+}
+
 fn write_unit_variant(ident: &Ident, config: &VariantConfig) -> TokenStream {
-    let VariantConfig { key, .. } = config;
-    let key_len = key.len();
+    let write_key = write_key(config, false);
 
-    // TODO: we may want to inherit strict for the key from the enum or variant
     quote! {
         Self::#ident => {
-            let key_config = fixcol::FieldDescription {
-                skip: 0,
-                len: #key_len,
-                alignment: fixcol::Alignment::Left,
-                strict: false,
-            };
-            let key = String::from(#key);
-            let _ = key.write_fixed_field(buf, &key_config)?;
+            #write_key
         },
     }
 }
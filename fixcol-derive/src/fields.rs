@@ -1,9 +1,13 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{FieldsNamed, FieldsUnnamed, Index, Token, Type};
+use syn::{
+    Attribute, Expr, ExprLit, Field, FieldsNamed, FieldsUnnamed, Index, Lit, Meta, MetaNameValue,
+    Token, Type,
+};
 
-use crate::attrs::{self, parse_field_attributes, FieldConfig, OuterConfig};
+use crate::attrs::{parse_field_attributes, Columns, Decoding, FieldConfig, OuterConfig};
 use crate::error::MacroError;
 
 fn add_turbo_to_type(path: &syn::TypePath) -> syn::TypePath {
@@ -24,57 +28,683 @@ fn add_turbo_to_type(path: &syn::TypePath) -> syn::TypePath {
     new_path
 }
 
-pub(crate) fn read_unnamed_fields(
-    fields: &FieldsUnnamed,
+/// Parses the config of each field, resolving absolute `at` columns
+///
+/// A field positioned with `at` is given the `skip` needed to reach that
+/// column from the end of the prior field. The fields of an enum variant
+/// begin after its key. An `overlap` field positioned before the end of the
+/// prior field re-reads columns of earlier fields and does not advance the
+/// column; one positioned at or after it is laid out like any other field.
+pub(crate) fn parse_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
     outer_config: &OuterConfig,
-) -> Result<(Vec<Ident>, Vec<TokenStream>), MacroError> {
-    let last_field = fields.unnamed.len().saturating_sub(1);
+) -> Result<Vec<FieldConfig>, MacroError> {
+    let mut column = Columns::from(outer_config.first_column());
+
+    fields
+        .map(|field| -> Result<FieldConfig, MacroError> {
+            let span = match &field.ident {
+                Some(ident) => ident.span(),
+                None => field.span(),
+            };
 
-    let field_reads: Result<Vec<(Ident, TokenStream)>, MacroError> = fields
-        .unnamed
-        .iter()
-        .enumerate()
-        .map(|item| -> Result<(Ident, TokenStream), MacroError> {
-            let (field_num, field) = item;
+            let mut config = parse_field_attributes(&span, &field.attrs, &field.ty, outer_config)
+                .map_err(|e| match field.ident {
+                Some(_) => e,
+                None => e.replace_span(field.span()),
+            })?;
+
+            config.name = field
+                .ident
+                .as_ref()
+                .map(|ident| ident.to_string().trim_start_matches("r#").to_owned());
+            config.description = doc_comment(&field.attrs);
+
+            // Option fields and fields with a blank_as sentinel may be blank
+            config.reject_blank = outer_config.reject_blank()
+                && config.strict
+                && config.blank_as.is_none()
+                && !is_option(&field.ty);
+
+            if let Some(at) = config.at {
+                let column = match column.known() {
+                    Some(column) => column,
+                    None => {
+                        let message =
+                            "at cannot follow a field whose width is not an integer literal.";
+                        return Err(MacroError::new(message, span));
+                    }
+                };
+                if at < column && config.overlap {
+                    check_overlap(&config, at, column, outer_config, span)?;
+                } else if at < column {
+                    let message = format!(
+                        "Column {} is before the end of the prior field at column {}.",
+                        at, column
+                    );
+                    return Err(MacroError::new(message.as_str(), span));
+                } else {
+                    config.overlap = false;
+                    config.skip = at - column;
+                }
+            }
 
-            let type_token = field.ty.clone();
-            let ident = format_ident!("_{}", field_num);
+            if !config.from_end && !config.overlap {
+                column += config.columns();
+            }
 
-            let config = attrs::parse_field_attributes(&item.1.span(), &field.attrs, outer_config)
-                .map_err(|e| e.replace_span(field.span()))?;
-            let FieldConfig { skip, width, strict, .. } = config;
+            Ok(config)
+        })
+        .collect()
+}
 
-            let buf_size = skip + width;
+/// Ensures an overlapping field lies within the columns of the prior fields
+///
+/// An overlapping field is read from the columns already read for the
+/// record's fields, so it may neither extend into columns that have not been
+/// read nor reach back into an enum variant's key.
+fn check_overlap(
+    config: &FieldConfig,
+    at: usize,
+    column: usize,
+    outer_config: &OuterConfig,
+    span: proc_macro2::Span,
+) -> Result<(), MacroError> {
+    let first_column = outer_config.first_column();
+    if at < first_column {
+        let message = format!(
+            "Overlapping field begins at column {}, within the key ending at column {}.",
+            at, first_column
+        );
+        return Err(MacroError::new(message.as_str(), span));
+    }
 
-            let read_field = if field_num == last_field && !strict {
-                quote! {
-                    let n = buf.read(&mut s)
-                        .map_err(|e| fixcol::error::Error::from(e))?;
-                    let raw = String::from_utf8(s[..n].to_vec())
-                        .map_err(|e| fixcol::error::Error::from(e))?;
-                }
+    let width = match config.value_columns().known() {
+        Some(width) => width,
+        None => {
+            let message = "overlap requires a width that is an integer literal.";
+            return Err(MacroError::new(message, span));
+        }
+    };
+
+    if at + width > column {
+        let message = format!(
+            "Overlapping field ends at column {}, after the end of the prior field at column {}.",
+            at + width,
+            column
+        );
+        return Err(MacroError::new(message.as_str(), span));
+    }
+
+    Ok(())
+}
+
+/// Fields wider than this are read into a heap allocated buffer
+const MAX_STACK_BUFFER: usize = 4096;
+
+/// Code to declare the buffer `s` a field of `buf_size` bytes is read into
+///
+/// Normal widths use a stack array, but very wide fields use a `Vec` to avoid
+/// overflowing the stack. Widths set by a constant are not known to the macro
+/// so also use a `Vec`.
+fn field_buffer(buf_size: Columns) -> TokenStream {
+    match buf_size.known() {
+        Some(size) if size <= MAX_STACK_BUFFER => {
+            quote! { let mut s: [u8; #size] = [0; #size]; }
+        }
+        _ => quote! { let mut s: Vec<u8> = vec![0; #buf_size]; },
+    }
+}
+
+/// Code to reject non-ASCII text in the raw field when `ascii_only` is set
+pub(crate) fn ascii_check(
+    label: &str,
+    config: &FieldConfig,
+    outer_config: &OuterConfig,
+) -> TokenStream {
+    if outer_config.ascii_only() && config.strict {
+        let message = format!("Non-ASCII character in field {} (strict)", label);
+
+        quote! {
+            if !raw.is_ascii() {
+                return Err(fixcol::error::DataError::custom(&raw, #message).into());
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Code to convert a field's parse error, attaching the field's position
+///
+/// When the field's columns are known, `span` holds expressions for the start
+/// and length of its value within the line.
+pub(crate) fn field_error(
+    index: usize,
+    config: &FieldConfig,
+    span: Option<(TokenStream, TokenStream)>,
+) -> TokenStream {
+    let name = match &config.name {
+        Some(name) => quote! { Some(#name) },
+        None => quote! { None },
+    };
+    let span = match span {
+        Some((start, len)) => quote! { .with_span(#start, #len) },
+        None => quote! {},
+    };
+
+    quote! { |e| fixcol::error::Error::from(e.with_field(#index, #name)#span) }
+}
+
+/// The start and length of a field's value within the line
+///
+/// `column` is the column following the prior field read in sequence, and is
+/// advanced past this field. Fields read from the end of the line or repeated
+/// to its end have no fixed position.
+fn field_span(config: &FieldConfig, column: &mut Columns) -> Option<(TokenStream, TokenStream)> {
+    let len = config.value_columns();
+    if config.overlap {
+        let start = config.at.unwrap_or_default();
+        Some((quote! { #start }, quote! { #len }))
+    } else if config.from_end || config.repeat_rest {
+        None
+    } else {
+        let mut start = column.clone();
+        start += Columns::from(config.skip);
+        *column += config.columns();
+        Some((quote! { #start }, quote! { #len }))
+    }
+}
+
+/// Code to decode the bytes of `bytes` into a `String`
+///
+/// In lossy mode invalid sequences are replaced with `U+FFFD` rather than
+/// failing the read. Single byte encodings cannot fail.
+pub(crate) fn decode_text(bytes: TokenStream, decoding: Decoding) -> TokenStream {
+    match decoding {
+        Decoding::Utf8 => quote! {
+            String::from_utf8(#bytes).map_err(|e| fixcol::error::Error::from(e))?
+        },
+        Decoding::Utf8Lossy => quote! { String::from_utf8_lossy(&#bytes).into_owned() },
+        Decoding::Latin1 => quote! { fixcol::Encoding::Latin1.decode(&#bytes) },
+        Decoding::Windows1252 => quote! { fixcol::Encoding::Windows1252.decode(&#bytes) },
+    }
+}
+
+/// The description passed to a field's parser once its text is decoded
+///
+/// Lossy decoding may lengthen the text, as each `U+FFFD` takes three bytes,
+/// as may single byte encodings, whose characters past `U+007F` take two or
+/// three. The field is then widened to cover the decoded text. A field
+/// holding several items shares the decoded text evenly between them.
+fn decoded_desc(config: &FieldConfig) -> TokenStream {
+    if !config.decoding.widens() {
+        return quote! { #config };
+    }
+
+    let width = &config.width;
+    let skip = if config.from_end { 0 } else { config.skip };
+    let decoded = match config.count {
+        1 => quote! { raw.len().saturating_sub(#skip) },
+        count => quote! { raw.len().saturating_sub(#skip) / #count },
+    };
+
+    quote! {
+        &{
+            let mut __fixcol_desc = *#config;
+            __fixcol_desc.len = std::cmp::max(#width, #decoded);
+            __fixcol_desc
+        }
+    }
+}
+
+/// The description of a lax last field, which the line may end before
+///
+/// A line that ends at or before the start of the field's value, having read
+/// `len` bytes of the field, leaves the field empty. A numeric field then
+/// reads as zero, unless it sets its own `blank_as`, rather than failing to
+/// parse.
+pub(crate) fn empty_field_desc(desc: TokenStream, skip: usize, len: TokenStream) -> TokenStream {
+    quote! {
+        &if #len <= #skip {
+            let mut __fixcol_desc = *#desc;
+            __fixcol_desc.blank_as = __fixcol_desc.blank_as.or(Some("0"));
+            __fixcol_desc
+        } else {
+            *#desc
+        }
+    }
+}
+
+/// Code to keep the bytes read for a field when the record has overlapping
+/// fields
+///
+/// The bytes are appended to `__fixcol_line`, which is declared by the first
+/// field, so that overlapping fields can be sliced from it.
+fn record_line(record: bool, field_num: usize, bytes: TokenStream) -> TokenStream {
+    match (record, field_num) {
+        (false, _) => quote! {},
+        (true, 0) => quote! { let mut __fixcol_line: Vec<u8> = (#bytes).to_vec(); },
+        (true, _) => quote! { __fixcol_line.extend_from_slice(#bytes); },
+    }
+}
+
+/// Whether the type is an `Option`
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Index of the first field of the run of `Option` fields ending the record
+///
+/// These fields may be missing entirely from a short line. Fields read from
+/// the end, repeated fields and overlapping fields end the run, as they are
+/// read differently.
+fn first_trailing_option(fields: &Punctuated<Field, Token![,]>, configs: &[FieldConfig]) -> usize {
+    let trailing = fields
+        .iter()
+        .zip(configs)
+        .rev()
+        .take_while(|(field, config)| {
+            is_option(&field.ty) && !config.from_end && !config.repeat_rest && !config.overlap
+        })
+        .count();
+
+    fields.len() - trailing
+}
+
+/// Code to read a field's value from `text` using `parse`, such as
+/// `<T>::parse_fixed`
+///
+/// A field that rejects blanks is an error when it holds only its pad
+/// character. Any `transform` is applied to the value that was read.
+pub(crate) fn parse_value(
+    parse: TokenStream,
+    text: TokenStream,
+    desc: TokenStream,
+    config: &FieldConfig,
+) -> TokenStream {
+    let mut value = quote! { #parse(#text, __fixcol_desc) };
+
+    if config.reject_blank {
+        value = quote! {
+            if __fixcol_desc.is_blank(#text) {
+                Err(fixcol::error::DataError::custom(#text, "required field is blank"))
             } else {
-                quote! {
-                    buf.read_exact(&mut s)
-                        .map_err(|e| fixcol::error::Error::from(e))?;
-                    let raw = String::from_utf8(s.to_vec())
-                        .map_err(|e| fixcol::error::Error::from(e))?;
-                }
-            };
+                #value
+            }
+        };
+    }
 
-            // TODO: we shouldn't need a String here at all
-            let read = quote! {
-                let mut s: [u8; #buf_size] = [0; #buf_size];
-                #read_field
-                let #ident = #type_token::parse_fixed(raw.as_str(), #config)
-                    .map_err(|e| fixcol::error::Error::from(e))?;
-            };
+    if let Some(path) = &config.transform {
+        value = quote! {
+            (#value).and_then(|value| fixcol::Transform::transform(&#path, value))
+        };
+    }
 
-            Ok((ident, read))
+    quote! {
+        {
+            let __fixcol_desc = #desc;
+            #value
+        }
+    }
+}
+
+/// The text of a field's doc comment, with its lines joined by spaces
+///
+/// Returns `None` if the field has no doc comment.
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value: Expr::Lit(ExprLit { lit: Lit::Str(doc), .. }),
+                ..
+            }) => Some(doc.value().trim().to_owned()),
+            _ => None,
         })
+        .filter(|line| !line.is_empty())
         .collect();
 
-    Ok(field_reads?.into_iter().unzip())
+    (!lines.is_empty()).then(|| lines.join(" "))
+}
+
+/// Code to read an `Option` field that may be missing from a short line
+///
+/// The field is read as `None` when the line ends before the field. A field
+/// that is only partly present is an error unless `partial` reads are allowed,
+/// as for the last field outside of strict mode.
+fn read_optional_field(
+    ident: &Ident,
+    type_token: &Type,
+    config: &FieldConfig,
+    partial: bool,
+    recording: TokenStream,
+    check: TokenStream,
+    on_error: TokenStream,
+) -> TokenStream {
+    let buffer = field_buffer(config.columns());
+    let decode = decode_text(quote! { s[..n].to_vec() }, config.decoding);
+    let desc = decoded_desc(config);
+    let parse = parse_value(
+        quote! { <#type_token>::parse_fixed },
+        quote! { raw.as_str() },
+        quote! { #desc },
+        config,
+    );
+
+    let check_partial = if partial {
+        quote! {}
+    } else {
+        quote! {
+            if n < s.len() {
+                let eof = std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                );
+                return Err(fixcol::error::Error::from(eof));
+            }
+        }
+    };
+
+    quote! {
+        #buffer
+        let mut n = 0;
+        while n < s.len() {
+            let read = buf.read(&mut s[n..])
+                .map_err(|e| fixcol::error::Error::from(e))?;
+            if read == 0 {
+                break;
+            }
+            n += read;
+        }
+
+        let #ident = if n == 0 {
+            None
+        } else {
+            #check_partial
+            #recording
+            let raw = #decode;
+            #check
+            #parse.map_err(#on_error)?
+        };
+    }
+}
+
+/// Code to read a field that overlaps the columns of prior fields
+///
+/// The field is sliced from the bytes already read for the record rather
+/// than read from the buffer. The first recorded byte is at `first_column`.
+fn read_overlap_field(
+    ident: &Ident,
+    type_token: &Type,
+    config: &FieldConfig,
+    first_column: usize,
+    check: TokenStream,
+    on_error: TokenStream,
+) -> TokenStream {
+    // The column checks in parse_fields guarantee at and the width are known
+    let start = config.at.unwrap_or_default() - first_column;
+    let end = start + config.value_columns().known().unwrap_or_default();
+
+    let decode = decode_text(
+        quote! { __fixcol_line[#start..#end].to_vec() },
+        config.decoding,
+    );
+    let desc = decoded_desc(config);
+    let parse = parse_value(
+        quote! { <#type_token>::parse_fixed },
+        quote! { raw.as_str() },
+        quote! { #desc },
+        config,
+    );
+
+    quote! {
+        let raw = #decode;
+        #check
+        let #ident = #parse.map_err(#on_error)?;
+    }
+}
+
+/// Code to read a field positioned relative to the end of the line
+///
+/// Fields read from the end are laid out in declaration order and occupy the
+/// final `total` columns of the line. Each field begins `offset` columns from
+/// the end and is followed by its skipped columns. The first such field is
+/// given the `total` and reads the remainder of the buffer so that the fields
+/// may be sliced from it.
+fn read_from_end_field(
+    ident: &Ident,
+    type_token: &Type,
+    config: &FieldConfig,
+    offset: &Columns,
+    total: Option<&Columns>,
+    check: TokenStream,
+    on_error: TokenStream,
+) -> TokenStream {
+    let width = config.value_columns();
+    let decode = decode_text(
+        quote! { __fixcol_tail[start..start + #width].to_vec() },
+        config.decoding,
+    );
+    let desc = decoded_desc(config);
+    let parse = parse_value(
+        quote! { <#type_token>::parse_fixed },
+        quote! { raw.as_str() },
+        quote! { #desc },
+        config,
+    );
+
+    let read_tail = if let Some(total) = total {
+        quote! {
+            let mut __fixcol_tail: Vec<u8> = Vec::new();
+            buf.read_to_end(&mut __fixcol_tail)
+                .map_err(|e| fixcol::error::Error::from(e))?;
+            if __fixcol_tail.len() < #total {
+                return Err(fixcol::error::DataError::custom(
+                    &String::from_utf8_lossy(&__fixcol_tail),
+                    "Line is too short to contain fields read from the end",
+                ).into());
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let gap_check = from_end_gap_check(config, &width, &on_error);
+
+    quote! {
+        #read_tail
+        let start = __fixcol_tail.len() - #offset;
+        let raw: String = #decode;
+        #check
+        #gap_check
+        let #ident = #parse.map_err(#on_error)?;
+    }
+}
+
+/// Code to check the skipped columns that follow a field read from the end
+///
+/// In strict mode these columns must be whitespace, just like the skipped
+/// columns preceding other fields.
+fn from_end_gap_check(
+    config: &FieldConfig,
+    width: &Columns,
+    on_error: &TokenStream,
+) -> TokenStream {
+    let skip = config.skip;
+    if !config.strict || skip == 0 {
+        return quote! {};
+    }
+
+    quote! {
+        let gap = &__fixcol_tail[start + #width..start + #width + #skip];
+        if !gap.iter().all(u8::is_ascii_whitespace) {
+            let text = String::from_utf8_lossy(&__fixcol_tail[start..start + #width + #skip]);
+            return Err(fixcol::error::DataError::whitespace(&text)).map_err(#on_error);
+        }
+    }
+}
+
+/// Code to read a field repeated until the end of the line
+///
+/// The remainder of the buffer is handed to the field's type, which is
+/// expected to split it into items of the declared width.
+fn read_rest_field(
+    ident: &Ident,
+    type_token: &Type,
+    config: &FieldConfig,
+    check: TokenStream,
+    on_error: TokenStream,
+) -> TokenStream {
+    let decode = decode_text(quote! { s }, config.decoding);
+    let parse = parse_value(
+        quote! { <#type_token>::parse_fixed },
+        quote! { raw.as_str() },
+        quote! { #config },
+        config,
+    );
+
+    quote! {
+        let mut s: Vec<u8> = Vec::new();
+        buf.read_to_end(&mut s)
+            .map_err(|e| fixcol::error::Error::from(e))?;
+        let __fixcol_rest_read = s.len();
+        let raw = #decode;
+        #check
+        let #ident = #parse.map_err(#on_error)?;
+    }
+}
+
+/// Ensures a field repeated until the end of the line is the last field
+fn check_repeat_last(
+    config: &FieldConfig,
+    is_last: bool,
+    span: proc_macro2::Span,
+) -> Result<(), MacroError> {
+    if config.repeat_rest && !is_last {
+        Err(MacroError::new(
+            "Only the last field may repeat until the end of the line.",
+            span,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// The offset from the end of the line at which each field read from the end
+/// begins
+///
+/// Other fields are given the total columns occupied by fields read from the
+/// end, since those all follow them.
+fn from_end_offsets(configs: &[FieldConfig]) -> Vec<Columns> {
+    let mut offset = Columns::default();
+    let mut offsets: Vec<Columns> = configs
+        .iter()
+        .rev()
+        .map(|config| {
+            if config.from_end {
+                offset += config.columns();
+            }
+            offset.clone()
+        })
+        .collect();
+
+    offsets.reverse();
+    offsets
+}
+
+/// Code to check the length of the line after all fields have been read
+///
+/// Any bytes remaining in the buffer are counted toward the line length along
+/// with the declared widths of the fields.
+pub(crate) fn check_line_length<'a>(
+    fields: impl Iterator<Item = &'a syn::Field> + Clone,
+    outer_config: &OuterConfig,
+    max_line_length: usize,
+) -> TokenStream {
+    let configs = parse_fields(fields, outer_config).unwrap_or_default();
+    let declared: Columns = configs
+        .iter()
+        .filter(|config| !config.from_end && !config.overlap && !config.repeat_rest)
+        .map(FieldConfig::columns)
+        .sum();
+
+    // Fields read from the end or repeated until the end consume the rest of
+    // the line
+    let consumed = if configs.iter().any(|config| config.from_end) {
+        quote! { #declared + __fixcol_tail.len() }
+    } else if configs.iter().any(|config| config.repeat_rest) {
+        quote! { #declared + __fixcol_rest_read }
+    } else {
+        quote! { #declared }
+    };
+
+    quote! {
+        let mut __fixcol_rest: Vec<u8> = Vec::new();
+        buf.read_to_end(&mut __fixcol_rest)
+            .map_err(|e| fixcol::error::Error::from(e))?;
+        let line_length = #consumed + __fixcol_rest.len();
+        if line_length > #max_line_length {
+            let overflow = String::from_utf8_lossy(&__fixcol_rest);
+            let message = format!(
+                "Line length {} exceeds max_line_length {} by {} (strict)",
+                line_length,
+                #max_line_length,
+                line_length - #max_line_length,
+            );
+            return Err(fixcol::error::DataError::custom(&overflow, &message).into());
+        }
+    }
+}
+
+/// Ensures fields read from the end of the line follow all other fields
+fn check_from_end_order(
+    config: &FieldConfig,
+    seen_from_end: &mut bool,
+    span: proc_macro2::Span,
+) -> Result<(), MacroError> {
+    if config.from_end {
+        *seen_from_end = true;
+        Ok(())
+    } else if *seen_from_end {
+        Err(MacroError::new(
+            "Fields read from the end must follow all other fields.",
+            span,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// How generated code refers to a field being read
+struct FieldAccess {
+    /// The variable the field's value is read into
+    ident: Ident,
+    /// How the field is named in error messages
+    label: String,
+    /// Where errors in the field's configuration are reported
+    span: Span,
+}
+
+pub(crate) fn read_unnamed_fields(
+    fields: &FieldsUnnamed,
+    outer_config: &OuterConfig,
+) -> Result<(Vec<Ident>, Vec<TokenStream>), MacroError> {
+    read_fields(&fields.unnamed, outer_config, |field_num, field| {
+        FieldAccess {
+            ident: format_ident!("_{}", field_num),
+            label: field_num.to_string(),
+            span: field.span(),
+        }
+    })
 }
 
 /// Retuns field names and code to read those fields
@@ -82,93 +712,173 @@ pub(crate) fn read_named_fields(
     fields: &FieldsNamed,
     outer_config: OuterConfig,
 ) -> Result<(Vec<Ident>, Vec<TokenStream>), MacroError> {
-    let last_field = fields.named.len().saturating_sub(1);
+    read_fields(&fields.named, &outer_config, |_, field| {
+        let name = field.ident.as_ref().unwrap().clone();
+        FieldAccess {
+            label: name.to_string(),
+            span: name.span(),
+            ident: name,
+        }
+    })
+}
+
+/// Returns the identifiers given by `access` and code to read those fields
+fn read_fields<F>(
+    fields: &Punctuated<Field, Token![,]>,
+    outer_config: &OuterConfig,
+    access: F,
+) -> Result<(Vec<Ident>, Vec<TokenStream>), MacroError>
+where
+    F: Fn(usize, &Field) -> FieldAccess,
+{
+    let last_field = fields.len().saturating_sub(1);
+    let mut seen_from_end = false;
+    let configs = parse_fields(fields.iter(), outer_config)?;
+    let offsets = from_end_offsets(&configs);
+    let record = configs.iter().any(|config| config.overlap);
+    let first_column = outer_config.first_column();
+    let first_option = first_trailing_option(fields, &configs);
+    let mut column = Columns::from(first_column);
 
     let field_reads: Result<Vec<(Ident, TokenStream)>, MacroError> = fields
-        .named
         .iter()
+        .zip(configs)
         .enumerate()
         .map(|item| -> Result<(Ident, TokenStream), MacroError> {
-            let (field_num, field) = item;
+            let (field_num, (field, config)) = item;
 
             let type_token = match &field.ty {
                 Type::Path(path) => Type::Path(add_turbo_to_type(path)),
                 other => other.clone(),
             };
-            let name = field.ident.as_ref().unwrap().clone();
+            let FieldAccess { ident, label, span: config_span } = access(field_num, field);
+
+            let total = (!seen_from_end).then(|| &offsets[0]);
+            check_from_end_order(&config, &mut seen_from_end, config_span)?;
+            check_repeat_last(&config, field_num == last_field, config_span)?;
+            let check = ascii_check(&label, &config, outer_config);
+            let span = field_span(&config, &mut column);
+            let on_error = field_error(field_num, &config, span);
+            if config.repeat_rest {
+                let read = read_rest_field(&ident, &type_token, &config, check, on_error);
+                return Ok((ident, read));
+            }
+            if config.overlap {
+                let read =
+                    read_overlap_field(&ident, &type_token, &config, first_column, check, on_error);
+                return Ok((ident, read));
+            }
+            if config.from_end {
+                let offset = &offsets[field_num];
+                let read = read_from_end_field(
+                    &ident,
+                    &type_token,
+                    &config,
+                    offset,
+                    total,
+                    check,
+                    on_error,
+                );
+                return Ok((ident, read));
+            }
+
+            if field_num >= first_option {
+                let partial = field_num == last_field && !config.strict;
+                let recording = record_line(record, field_num, quote! { &s[..n] });
+                let read = read_optional_field(
+                    &ident,
+                    &type_token,
+                    &config,
+                    partial,
+                    recording,
+                    check,
+                    on_error,
+                );
+                return Ok((ident, read));
+            }
 
-            let config = parse_field_attributes(&name.span(), &field.attrs, &outer_config)?;
-            let FieldConfig { skip, width, strict, .. } = config;
+            let FieldConfig { strict, decoding, .. } = config;
 
-            let buf_size = skip + width;
+            let buffer = field_buffer(config.columns());
+            let mut desc = decoded_desc(&config);
 
             let read_field = if field_num == last_field && !strict {
+                let decode = decode_text(quote! { s[..n].to_vec() }, decoding);
+                let recording = record_line(record, field_num, quote! { &s[..n] });
+                desc = empty_field_desc(desc, config.skip, quote! { n });
                 quote! {
                     let n = buf.read(&mut s)
                         .map_err(|e| fixcol::error::Error::from(e))?;
-                    let raw = String::from_utf8(s[..n].to_vec())
-                        .map_err(|e| fixcol::error::Error::from(e))?;
+                    #recording
+                    let raw = #decode;
                 }
             } else {
+                let decode = decode_text(quote! { s.to_vec() }, decoding);
+                let recording = record_line(record, field_num, quote! { &s });
                 quote! {
                     buf.read_exact(&mut s)
                         .map_err(|e| fixcol::error::Error::from(e))?;
-                    let raw = String::from_utf8(s.to_vec())
-                        .map_err(|e| fixcol::error::Error::from(e))?;
+                    #recording
+                    let raw = #decode;
                 }
             };
 
+            let parse = parse_value(
+                quote! { <#type_token>::parse_fixed },
+                quote! { raw.as_str() },
+                quote! { #desc },
+                &config,
+            );
+
             // TODO: we shouldn't need a String here at all
             let read = quote! {
-                let mut s: [u8; #buf_size] = [0; #buf_size];
+                #buffer
                 #read_field
-                let #name = #type_token::parse_fixed(raw.as_str(), #config)
-                    .map_err(|e| fixcol::error::Error::from(e))?;
+                #check
+                let #ident = #parse.map_err(#on_error)?;
             };
 
-            Ok((name, read))
+            Ok((ident, read))
         })
         .collect();
 
     Ok(field_reads?.into_iter().unzip())
 }
 
+/// Returns the names and configs of the fields to write
+///
+/// Overlapping fields are left out, as their columns are written by the
+/// fields they overlap.
 pub(crate) fn write_named_fields(
     fields: &FieldsNamed,
     outer_config: &OuterConfig,
 ) -> Result<(Vec<Ident>, Vec<FieldConfig>), MacroError> {
-    let field_configs: Result<Vec<(Ident, FieldConfig)>, MacroError> = fields
+    let names = fields
         .named
         .iter()
-        .map(|field| -> Result<(Ident, FieldConfig), MacroError> {
-            let name = field.ident.as_ref().unwrap().clone();
-            let config = attrs::parse_field_attributes(&name.span(), &field.attrs, outer_config)?;
-
-            Ok((name, config))
-        })
-        .collect();
+        .map(|field| field.ident.as_ref().unwrap().clone());
+    let configs = parse_fields(fields.named.iter(), outer_config)?;
 
-    Ok(field_configs?.into_iter().unzip())
+    Ok(names
+        .zip(configs)
+        .filter(|(_, config)| !config.overlap)
+        .unzip())
 }
 
+/// Returns the indices and configs of the fields to write
+///
+/// Overlapping fields are left out, as with [`write_named_fields`].
 pub(crate) fn write_unnamed_fields(
     fields: &FieldsUnnamed,
     outer_config: &OuterConfig,
 ) -> Result<(Vec<Index>, Vec<FieldConfig>), MacroError> {
-    let field_configs: Result<Vec<(Index, FieldConfig)>, MacroError> = fields
-        .unnamed
-        .iter()
-        .enumerate()
-        .map(|field| -> Result<(Index, FieldConfig), MacroError> {
-            let name = syn::Index::from(field.0);
-            let config =
-                attrs::parse_field_attributes(&field.1.span(), &field.1.attrs, outer_config)?;
-
-            Ok((name, config))
-        })
-        .collect();
+    let names = (0..fields.unnamed.len()).map(syn::Index::from);
+    let configs = parse_fields(fields.unnamed.iter(), outer_config)?;
 
-    Ok(field_configs?.into_iter().unzip())
+    Ok(names
+        .zip(configs)
+        .filter(|(_, config)| !config.overlap)
+        .unzip())
 }
 
 #[cfg(test)]
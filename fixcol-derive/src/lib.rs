@@ -11,6 +11,7 @@
 //! [`ReadFixed`]: https://docs.rs/fixcol/latest/fixcol/trait.ReadFixed.html
 //! [`WriteFixed`]: https://docs.rs/fixcol/latest/fixcol/trait.WriteFixed.html
 mod attrs;
+mod codes;
 mod enums;
 mod error;
 mod fields;
@@ -21,15 +22,17 @@ extern crate proc_macro2;
 extern crate quote;
 extern crate syn;
 
+use attrs::parse_code_enum_attributes;
+use codes::{code_read, code_write};
 use enums::enum_write;
 use error::MacroError;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{Data, DataEnum, DataStruct, DeriveInput};
+use syn::{Data, DataEnum, DataStruct, DeriveInput, GenericParam, Lifetime, LifetimeParam};
 
-use crate::enums::enum_read;
-use crate::structs::{struct_read, struct_write};
+use crate::enums::{enum_fixed_width, enum_labels, enum_read, enum_total_width};
+use crate::structs::{struct_from_fields, struct_read, struct_read_ref, struct_write};
 
 /// Derive proc-macro for ReadFixed
 ///
@@ -44,8 +47,8 @@ pub fn read_fixed_impl(input: TokenStream) -> TokenStream {
     let attrs = &ast.attrs;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let function_impl_result = match ast.data {
-        Data::Struct(DataStruct { fields, .. }) => struct_read(name, attrs, fields),
+    let function_impl_result = match &ast.data {
+        Data::Struct(DataStruct { fields, .. }) => struct_read(name, attrs, fields.clone()),
         Data::Enum(DataEnum { variants, .. }) => enum_read(name, attrs, variants.iter().collect()),
         Data::Union(u) => Err(MacroError::new(
             "Deriving ReadFixed on unions is not supported",
@@ -53,15 +56,59 @@ pub fn read_fixed_impl(input: TokenStream) -> TokenStream {
         )),
     };
 
-    let gen = match function_impl_result {
-        Ok(function_impl) => {
+    // Inherent methods generated alongside the trait implementation
+    let inherent_impl_result = match &ast.data {
+        Data::Enum(DataEnum { variants, .. }) => {
+            let variants = variants.iter().collect::<Vec<_>>();
+            enum_total_width(name, attrs, &variants).and_then(|total_width| {
+                let labels = enum_labels(name, attrs, &variants)?;
+                Ok(Some(quote! { #total_width #labels }))
+            })
+        }
+        Data::Struct(DataStruct { fields, .. }) => struct_from_fields(name, attrs, fields),
+        _ => Ok(None),
+    };
+
+    // Enums of keyed unit variants have a known width for use as a field
+    let fixed_width_result = match &ast.data {
+        Data::Enum(DataEnum { variants, .. }) => {
+            enum_fixed_width(name, attrs, &variants.iter().collect::<Vec<_>>())
+        }
+        _ => Ok(None),
+    };
+
+    let gen = match (
+        function_impl_result,
+        inherent_impl_result,
+        fixed_width_result,
+    ) {
+        (Ok(function_impl), Ok(inherent_impl), Ok(fixed_width)) => {
+            let inherent_impl = inherent_impl.map(|methods| {
+                quote! {
+                    impl #impl_generics #name #ty_generics #where_clause {
+                        #methods
+                    }
+                }
+            });
+
+            let fixed_width = fixed_width.map(|width| {
+                quote! {
+                    impl #impl_generics fixcol::FixedWidth for #name #ty_generics #where_clause {
+                        #width
+                    }
+                }
+            });
+
             quote! {
                 impl #impl_generics fixcol::ReadFixed for #name #ty_generics #where_clause {
                     #function_impl
                 }
+
+                #inherent_impl
+                #fixed_width
             }
         }
-        Err(err) => quote! { #err },
+        (Err(err), _, _) | (_, Err(err), _) | (_, _, Err(err)) => quote! { #err },
     };
 
     // println!("{}", gen);
@@ -69,6 +116,53 @@ pub fn read_fixed_impl(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
+/// Derive proc-macro for ReadFixedRef
+///
+/// See documentation on [`ReadFixedRef`] for a full description.
+///
+/// [`ReadFixedRef`]: https://docs.rs/fixcol/latest/fixcol/trait.ReadFixedRef.html
+#[proc_macro_derive(ReadFixedRef, attributes(fixcol))]
+pub fn read_fixed_ref_impl(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    let name = &ast.ident;
+    let attrs = &ast.attrs;
+
+    // Records borrow from the line for the struct's own lifetime, if it has
+    // one. Otherwise the implementation is for any lifetime.
+    let mut generics = ast.generics.clone();
+    let lifetime = match generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime = Lifetime::new("'__fixcol", name.span());
+            let param = GenericParam::Lifetime(LifetimeParam::new(lifetime.clone()));
+            generics.params.insert(0, param);
+            lifetime
+        }
+    };
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let function_impl_result = match &ast.data {
+        Data::Struct(DataStruct { fields, .. }) => struct_read_ref(name, attrs, fields, &lifetime),
+        _ => Err(MacroError::new(
+            "Deriving ReadFixedRef is only supported on structs",
+            name.span(),
+        )),
+    };
+
+    let gen = match function_impl_result {
+        Ok(function_impl) => quote! {
+            impl #impl_generics fixcol::ReadFixedRef<#lifetime> for #name #ty_generics #where_clause {
+                #function_impl
+            }
+        },
+        Err(err) => quote! { #err },
+    };
+
+    gen.into()
+}
+
 /// Derive proc-macro for WriteFixed
 ///
 /// See [`WriteFixed`] for a complete discuassion.
@@ -109,11 +203,70 @@ pub fn write_fixed_impl(input: TokenStream) -> TokenStream {
     gen.into()
 }
 
+/// Derive proc-macro for FixcolEnum
+///
+/// Implements `FixedDeserializer`, and `FixedSerializer` when writing is
+/// enabled, for an enum of unit variants each identified by a `value` token
+/// or, with `match_display`, by its `Display` text.
+/// See documentation on [`FixcolEnum`] for a full description.
+///
+/// [`FixcolEnum`]: https://docs.rs/fixcol/latest/fixcol/derive.FixcolEnum.html
+#[proc_macro_derive(FixcolEnum, attributes(fixcol))]
+pub fn fixcol_enum_impl(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(DataEnum { variants, .. }) => variants.iter().collect::<Vec<_>>(),
+        _ => {
+            let err = MacroError::new(
+                "Deriving FixcolEnum is only supported on enums",
+                name.span(),
+            );
+            return quote! { #err }.into();
+        }
+    };
+
+    let match_display = match parse_code_enum_attributes(&ast.attrs) {
+        Ok(match_display) => match_display,
+        Err(err) => return quote! { #err }.into(),
+    };
+
+    let read = match code_read(&variants, match_display) {
+        Ok(read) => quote! {
+            impl #impl_generics fixcol::FixedDeserializer for #name #ty_generics #where_clause {
+                #read
+            }
+        },
+        Err(err) => return quote! { #err }.into(),
+    };
+
+    let write = match code_write(&variants, match_display) {
+        Ok(write) if cfg!(feature = "experimental-write") => quote! {
+            impl #impl_generics fixcol::FixedSerializer for #name #ty_generics #where_clause {
+                #write
+            }
+        },
+        Ok(_) => quote! {},
+        Err(err) => return quote! { #err }.into(),
+    };
+
+    let gen = quote! {
+        #read
+        #write
+    };
+
+    gen.into()
+}
+
 #[cfg(test)]
 mod tests {
     // use super::*;
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn macro_test() {
         assert!(true);
     }
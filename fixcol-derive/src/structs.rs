@@ -1,11 +1,12 @@
-use proc_macro2::Ident;
-use quote::quote;
-use syn::{Attribute, Fields, FieldsNamed, FieldsUnnamed};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Field, Fields, FieldsNamed, FieldsUnnamed, Lifetime};
 
-use crate::attrs::{parse_struct_attributes, OuterConfig, StructConfig};
+use crate::attrs::{fixcol_attrs, parse_struct_attributes, FieldConfig, OuterConfig, StructConfig};
 use crate::error::{MacroError, MacroResult};
 use crate::fields::{
-    read_named_fields, read_unnamed_fields, write_named_fields, write_unnamed_fields,
+    ascii_check, check_line_length, decode_text, empty_field_desc, field_error, parse_fields,
+    parse_value, read_named_fields, read_unnamed_fields, write_named_fields, write_unnamed_fields,
 };
 
 //
@@ -14,25 +15,77 @@ use crate::fields::{
 
 pub(crate) fn struct_read(ident: &Ident, attrs: &[Attribute], fields: Fields) -> MacroResult {
     let config = parse_struct_attributes(attrs)?;
+    if let Some(delimiter) = config.delimiter {
+        return struct_read_delimited(ident, &fields, config, delimiter);
+    }
+
+    let lines = match config.lines {
+        Some(lines) => quote! { const LINES: usize = #lines; },
+        None => quote! {},
+    };
 
-    match fields {
+    let read = match fields {
         Fields::Named(named_fields) => struct_read_fixed(named_fields, config),
         Fields::Unnamed(unnamed_fields) => tuple_struct_read_fixed(unnamed_fields, config),
         Fields::Unit => Err(MacroError::new(
             "Cannot derive ReadFixed for unit type",
             ident.span(),
         )),
+    }?;
+
+    Ok(quote! {
+        #lines
+        #read
+    })
+}
+
+/// Code to enforce `max_line_length` in strict mode, if set
+fn line_length_check<'a>(
+    fields: impl Iterator<Item = &'a Field> + Clone,
+    max_line_length: Option<usize>,
+    outer: &OuterConfig,
+) -> TokenStream {
+    match max_line_length {
+        Some(max) if outer.strict() => check_line_length(fields, outer, max),
+        _ => quote! {},
+    }
+}
+
+/// Returns the wrapped field of a newtype struct that should delegate to the
+/// inner type's own `ReadFixed` and `WriteFixed` implementations
+///
+/// A tuple struct with exactly one field and no `fixcol` attribute on that
+/// field is treated as a newtype, the same way as an embedded enum variant.
+fn newtype_field(fields: &FieldsUnnamed) -> Option<&Field> {
+    match fields.unnamed.first() {
+        Some(field) if fields.unnamed.len() == 1 && fixcol_attrs(&field.attrs).is_empty() => {
+            Some(field)
+        }
+        _ => None,
     }
 }
 
 fn tuple_struct_read_fixed(fields: FieldsUnnamed, outer: StructConfig) -> MacroResult {
+    if let Some(field) = newtype_field(&fields) {
+        let inner_type = &field.ty;
+
+        return Ok(quote! {
+            fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
+                Ok(Self(<#inner_type>::read_fixed(buf)?))
+            }
+        });
+    }
+
+    let max_line_length = outer.max_line_length;
     let outer: OuterConfig = outer.into();
     let (names, reads) = read_unnamed_fields(&fields, &outer)?;
+    let check = line_length_check(fields.unnamed.iter(), max_line_length, &outer);
 
     let fun = quote! {
         fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
             use fixcol::FixedDeserializer;
             #( #reads )*
+            #check
 
             Ok(Self(#(#names),*))
         }
@@ -42,13 +95,16 @@ fn tuple_struct_read_fixed(fields: FieldsUnnamed, outer: StructConfig) -> MacroR
 }
 
 fn struct_read_fixed(fields: FieldsNamed, outer: StructConfig) -> MacroResult {
+    let max_line_length = outer.max_line_length;
     let outer: OuterConfig = outer.into();
+    let check = line_length_check(fields.named.iter(), max_line_length, &outer);
     let (field_names, field_reads) = read_named_fields(&fields, outer)?;
 
     let function = quote! {
         fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
             use fixcol::FixedDeserializer;
             #(#field_reads)*
+            #check
 
             Ok(Self {
                 #(#field_names),*
@@ -59,6 +115,313 @@ fn struct_read_fixed(fields: FieldsNamed, outer: StructConfig) -> MacroResult {
     Ok(function)
 }
 
+/// Generates `from_fields` if the struct sets the `from_fields` parameter
+///
+/// Each element of the supplied slice is the text of one field, in
+/// declaration order.
+pub(crate) fn struct_from_fields(
+    ident: &Ident,
+    attrs: &[Attribute],
+    fields: &Fields,
+) -> Result<Option<TokenStream>, MacroError> {
+    let config = parse_struct_attributes(attrs)?;
+    if !config.from_fields || matches!(fields, Fields::Unit) {
+        return Ok(None);
+    }
+
+    let read = read_split_fields(ident, fields, config, "from_fields")?;
+
+    Ok(Some(quote! {
+        /// Builds the record from the text of each field, in declaration order
+        pub fn from_fields(fields: &[&str]) -> Result<Self, fixcol::error::Error> {
+            #read
+        }
+    }))
+}
+
+/// Code to build the record from `fields`, a slice holding the text of each
+/// field in declaration order
+///
+/// Fields are parsed with their own description, except that skipped columns
+/// are ignored and a single valued field takes the width of its element.
+/// `param` names the struct parameter that split the fields, for errors.
+fn read_split_fields(
+    ident: &Ident,
+    fields: &Fields,
+    config: StructConfig,
+    param: &str,
+) -> MacroResult {
+    let named = matches!(fields, Fields::Named(_));
+    let fields: Vec<&Field> = match fields {
+        Fields::Named(named) => named.named.iter().collect(),
+        Fields::Unnamed(unnamed) if newtype_field(unnamed).is_some() => {
+            let message = format!("{} is not supported on newtype structs.", param);
+            return Err(MacroError::new(&message, ident.span()));
+        }
+        Fields::Unnamed(unnamed) => unnamed.unnamed.iter().collect(),
+        Fields::Unit => {
+            let message = format!("{} is not supported on unit structs.", param);
+            return Err(MacroError::new(&message, ident.span()));
+        }
+    };
+
+    let outer: OuterConfig = config.into();
+    let configs = parse_fields(fields.iter().copied(), &outer)?;
+    let count = configs.len();
+
+    let (names, reads): (Vec<Ident>, Vec<TokenStream>) = fields
+        .iter()
+        .zip(configs.iter())
+        .enumerate()
+        .map(|(index, (field, config))| {
+            let name = format_ident!("_{}", index);
+            let ty = &field.ty;
+            let on_error = field_error(index, config, None);
+
+            // Fields holding several items keep their item width
+            let len = if config.count == 1 && !config.repeat_rest {
+                quote! { __fixcol_desc.len = fields[#index].len(); }
+            } else {
+                quote! {}
+            };
+
+            let parse = parse_value(
+                quote! { <#ty>::parse_fixed },
+                quote! { fields[#index] },
+                quote! {
+                    &{
+                        let mut __fixcol_desc = *#config;
+                        __fixcol_desc.skip = 0;
+                        __fixcol_desc.skip_literal = None;
+                        #len
+                        __fixcol_desc
+                    }
+                },
+                config,
+            );
+            let read = quote! {
+                let #name = #parse.map_err(#on_error)?;
+            };
+
+            (name, read)
+        })
+        .unzip();
+
+    let construct = if named {
+        let idents = fields.iter().map(|field| &field.ident);
+        quote! { Self { #(#idents: #names),* } }
+    } else {
+        quote! { Self(#(#names),*) }
+    };
+
+    Ok(quote! {
+        use fixcol::FixedDeserializer;
+
+        if fields.len() != #count {
+            let message = format!("Expected {} fields but found {}", #count, fields.len());
+            return Err(fixcol::error::DataError::custom(&fields.join(" "), &message).into());
+        }
+
+        #(#reads)*
+
+        Ok(#construct)
+    })
+}
+
+/// Generates `read_fixed` for a struct whose fields are separated by its
+/// `delimiter` rather than found at fixed columns
+///
+/// The line is split on the delimiter and each piece is read as the field in
+/// the same position, as by `from_fields`.
+fn struct_read_delimited(
+    ident: &Ident,
+    fields: &Fields,
+    config: StructConfig,
+    delimiter: char,
+) -> MacroResult {
+    if config.lines.is_some() {
+        return Err(MacroError::new(
+            "delimiter cannot be combined with lines.",
+            ident.span(),
+        ));
+    }
+
+    let decode = decode_text(quote! { line }, config.decoding);
+    let read = read_split_fields(ident, fields, config, "delimiter")?;
+
+    Ok(quote! {
+        fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, fixcol::error::Error> {
+            let mut line: Vec<u8> = Vec::new();
+            buf.read_to_end(&mut line)
+                .map_err(|e| fixcol::error::Error::from(e))?;
+            let line: String = #decode;
+            let fields: Vec<&str> = line.split(#delimiter).collect();
+
+            #read
+        }
+    })
+}
+
+/// Generates `read_fixed_ref`, reading each field from a slice of the line
+///
+/// Fields are sliced from the line in order, so a text field can borrow its
+/// slice rather than copy it. As when reading from a buffer, a line too short
+/// for a field is an error unless it is the last field of a lax record.
+pub(crate) fn struct_read_ref(
+    ident: &Ident,
+    attrs: &[Attribute],
+    fields: &Fields,
+    lifetime: &Lifetime,
+) -> MacroResult {
+    let config = parse_struct_attributes(attrs)?;
+    if config.lines.is_some() {
+        return Err(MacroError::new(
+            "ReadFixedRef does not support records spanning several lines.",
+            ident.span(),
+        ));
+    }
+    if config.delimiter.is_some() {
+        return Err(MacroError::new(
+            "ReadFixedRef does not support delimited records.",
+            ident.span(),
+        ));
+    }
+
+    let named = matches!(fields, Fields::Named(_));
+    let fields: Vec<&Field> = match fields {
+        Fields::Named(named) => named.named.iter().collect(),
+        Fields::Unnamed(unnamed) => unnamed.unnamed.iter().collect(),
+        Fields::Unit => {
+            return Err(MacroError::new(
+                "Cannot derive ReadFixedRef for unit type",
+                ident.span(),
+            ))
+        }
+    };
+
+    let max_line_length = config.max_line_length;
+    let outer: OuterConfig = config.into();
+    let configs = parse_fields(fields.iter().copied(), &outer)?;
+    let last_field = configs.len().saturating_sub(1);
+
+    let mut names: Vec<Ident> = Vec::new();
+    let mut reads: Vec<TokenStream> = Vec::new();
+    for (index, (field, config)) in fields.iter().zip(configs.iter()).enumerate() {
+        if config.from_end {
+            return Err(MacroError::new(
+                "ReadFixedRef does not support fields read from the end of the line.",
+                field.ident.as_ref().map_or(ident.span(), Ident::span),
+            ));
+        }
+
+        let name = format_ident!("_{}", index);
+        let ty = &field.ty;
+        let skip = config.skip;
+        let span = (
+            quote! { __fixcol_start + #skip },
+            quote! { __fixcol_end.saturating_sub(__fixcol_start + #skip) },
+        );
+        let on_error = field_error(index, config, Some(span));
+        let check = ascii_check(&index.to_string(), config, &outer);
+
+        // The column checks in parse_fields guarantee at and the width are known
+        let (start, end, advance) = if config.overlap {
+            let start = config.at.unwrap_or_default();
+            let end = start + config.value_columns().known().unwrap_or_default();
+            (quote! { #start }, quote! { #end }, quote! {})
+        } else if config.repeat_rest {
+            let end = quote! { line.len() };
+            (
+                quote! { __fixcol_pos },
+                end,
+                quote! { __fixcol_pos = line.len(); },
+            )
+        } else {
+            let columns = config.columns();
+            let end = quote! { __fixcol_pos + #columns };
+            (
+                quote! { __fixcol_pos },
+                end,
+                quote! { __fixcol_pos = __fixcol_end; },
+            )
+        };
+
+        let partial = index == last_field && !config.strict;
+        let desc = match partial {
+            true => empty_field_desc(quote! { #config }, config.skip, quote! { raw.len() }),
+            false => quote! { #config },
+        };
+        let parse = parse_value(
+            quote! { <#ty as fixcol::FixedDeserializerRef<#lifetime>>::parse_fixed_ref },
+            quote! { raw },
+            desc,
+            config,
+        );
+        let short_line = if partial {
+            quote! {}
+        } else {
+            quote! {
+                if __fixcol_end > line.len() {
+                    let text = line.get(__fixcol_start..).unwrap_or_default();
+                    let expected = __fixcol_end - __fixcol_start;
+                    return Err(fixcol::error::DataError::width(text, expected, text.len()))
+                        .map_err(#on_error);
+                }
+            }
+        };
+
+        names.push(name.clone());
+        reads.push(quote! {
+            let __fixcol_start: usize = #start;
+            let __fixcol_end: usize = #end;
+            #short_line
+            let raw: &#lifetime str = line
+                .get(__fixcol_start.min(line.len())..__fixcol_end.min(line.len()))
+                .ok_or_else(|| {
+                    fixcol::error::DataError::custom(line, "Field boundary within a character")
+                })
+                .map_err(#on_error)?;
+            #check
+            let #name = #parse.map_err(#on_error)?;
+            #advance
+        });
+    }
+
+    let check = match max_line_length {
+        Some(max) if outer.strict() => quote! {
+            if line.len() > #max {
+                let overflow = line.get(#max..).unwrap_or_default();
+                let message = format!(
+                    "Line length {} exceeds max_line_length {} by {} (strict)",
+                    line.len(),
+                    #max,
+                    line.len() - #max,
+                );
+                return Err(fixcol::error::DataError::custom(overflow, &message).into());
+            }
+        },
+        _ => quote! {},
+    };
+
+    let construct = if named {
+        let idents = fields.iter().map(|field| &field.ident);
+        quote! { Self { #(#idents: #names),* } }
+    } else {
+        quote! { Self(#(#names),*) }
+    };
+
+    Ok(quote! {
+        #[allow(unused_assignments)]
+        fn read_fixed_ref(line: &#lifetime str) -> Result<Self, fixcol::error::Error> {
+            let mut __fixcol_pos: usize = 0;
+            #(#reads)*
+            #check
+
+            Ok(#construct)
+        }
+    })
+}
+
 //
 // Writes
 ///////////////////////////////////
@@ -81,11 +444,22 @@ pub(crate) fn struct_write(ident: &Ident, attrs: &[Attribute], fields: Fields) -
 fn struct_write_fixed(fields: FieldsNamed, config: StructConfig) -> MacroResult {
     let (names, configs) = write_named_fields(&fields, &OuterConfig::Struct(config))?;
 
+    let trailing: Vec<_> = configs.iter().map(FieldConfig::write_trailing).collect();
+    let headers: Vec<_> = configs.iter().map(FieldConfig::write_header).collect();
+
     let gen = quote! {
         fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
             use fixcol::FixedSerializer;
 
-            #( let _ = self.#names.write_fixed_field(buf, #configs)?; )*
+            #( let _ = self.#names.write_fixed_field(buf, #configs)?; #trailing )*
+
+            Ok(())
+        }
+
+        fn write_fixed_header<W: std::io::Write>(buf: &mut W) -> Result<(), fixcol::error::Error> {
+            use fixcol::FixedSerializer;
+
+            #( #headers )*
 
             Ok(())
         }
@@ -95,13 +469,37 @@ fn struct_write_fixed(fields: FieldsNamed, config: StructConfig) -> MacroResult
 }
 
 fn tuple_struct_write_fixed(fields: FieldsUnnamed, config: StructConfig) -> MacroResult {
+    if let Some(field) = newtype_field(&fields) {
+        let ty = &field.ty;
+        return Ok(quote! {
+            fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
+                fixcol::WriteFixed::write_fixed(&self.0, buf)
+            }
+
+            fn write_fixed_header<W: std::io::Write>(buf: &mut W) -> Result<(), fixcol::error::Error> {
+                <#ty as fixcol::WriteFixed>::write_fixed_header(buf)
+            }
+        });
+    }
+
     let (names, configs) = write_unnamed_fields(&fields, &OuterConfig::Struct(config))?;
 
+    let trailing: Vec<_> = configs.iter().map(FieldConfig::write_trailing).collect();
+    let headers: Vec<_> = configs.iter().map(FieldConfig::write_header).collect();
+
     let gen = quote! {
         fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), fixcol::error::Error> {
             use fixcol::FixedSerializer;
 
-            #( let _ = self.#names.write_fixed_field(buf, #configs)?; )*
+            #( let _ = self.#names.write_fixed_field(buf, #configs)?; #trailing )*
+
+            Ok(())
+        }
+
+        fn write_fixed_header<W: std::io::Write>(buf: &mut W) -> Result<(), fixcol::error::Error> {
+            use fixcol::FixedSerializer;
+
+            #( #headers )*
 
             Ok(())
         }
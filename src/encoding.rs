@@ -0,0 +1,87 @@
+//! Decoding of text in single byte encodings
+//!
+//! Records in these encodings are read by setting the `encoding` parameter on
+//! a struct or enum, which requires the `encoding` feature.
+
+use alloc::string::String;
+
+/// Characters for the bytes `0x80..=0x9F` in Windows-1252
+///
+/// The bytes `0x81`, `0x8D`, `0x8F`, `0x90` and `0x9D` are unassigned and
+/// decode to the control characters of the same value, as in Latin-1.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// A single byte text encoding that records can be read from
+///
+/// Every byte decodes to exactly one character, so decoding never fails and
+/// columns are counted in bytes, as they are for UTF-8 records. Decoded text
+/// may be longer than the bytes it came from, since characters past `U+007F`
+/// take more than one byte in a Rust [`String`].
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{Encoding, ReadFixed};
+///
+/// #[derive(ReadFixed)]
+/// #[fixcol(encoding = "latin1")]
+/// struct City {
+///     #[fixcol(width = 6)]
+///     name: String,
+///     #[fixcol(width = 3, align = "right")]
+///     code: u16,
+/// }
+///
+/// let city = City::read_fixed(&mut &b"M\xfcnchn 89"[..]).unwrap();
+/// assert_eq!(city.name, "Münchn");
+/// assert_eq!(city.code, 89);
+///
+/// assert_eq!(Encoding::Windows1252.decode(b"\x80 5"), "€ 5");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    /// ISO-8859-1, where each byte is the code point of the same value
+    Latin1,
+    /// Windows-1252, which replaces the control characters `0x80..=0x9F` of
+    /// Latin-1 with typographic characters such as `€` and `“`
+    Windows1252,
+}
+
+impl Encoding {
+    /// Decodes the bytes into a [`String`]
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        bytes.iter().map(|&b| self.decode_byte(b)).collect()
+    }
+
+    /// The character a single byte represents
+    fn decode_byte(&self, byte: u8) -> char {
+        match (self, byte) {
+            (Encoding::Windows1252, 0x80..=0x9F) => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+            _ => char::from(byte),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_latin1() {
+        assert_eq!(Encoding::Latin1.decode(b"abc"), "abc");
+        assert_eq!(Encoding::Latin1.decode(b"\xe9t\xe9"), "été");
+        assert_eq!(Encoding::Latin1.decode(b"\x80\xff"), "\u{80}ÿ");
+    }
+
+    #[test]
+    fn decode_windows_1252() {
+        assert_eq!(Encoding::Windows1252.decode(b"\xe9t\xe9"), "été");
+        assert_eq!(Encoding::Windows1252.decode(b"\x93hi\x94"), "“hi”");
+        assert_eq!(Encoding::Windows1252.decode(b"\x80\x81\x9f"), "€\u{81}Ÿ");
+    }
+}
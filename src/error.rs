@@ -40,11 +40,13 @@
 //! # Ok(())
 //! # }
 //! ```
-use std::fmt::{Display, Formatter};
+use alloc::borrow::ToOwned;
+use alloc::string::{FromUtf8Error, String};
+use core::fmt::{Display, Formatter};
+use core::num::{ParseFloatError, ParseIntError};
+use core::str::Utf8Error;
+#[cfg(feature = "std")]
 use std::io;
-use std::num::{ParseFloatError, ParseIntError};
-use std::str::Utf8Error;
-use std::string::FromUtf8Error;
 
 /// The standard error for the `fixcol` library.
 ///
@@ -93,6 +95,7 @@ use std::string::FromUtf8Error;
 ///
 /// ```text
 /// Error handling data from "123x6": invalid digit found in string
+/// Error occured in field 2 (`count`)
 /// Error occured on line 56
 /// ```
 /// [`ReadFixed`]: crate::ReadFixed
@@ -105,6 +108,7 @@ pub enum Error {
     /// An error that occured while reading or writing the data.
     ///
     /// This variant is a thin wrapper around [`std::io::Error`].
+    #[cfg(feature = "std")]
     IoError(io::Error),
 }
 
@@ -115,14 +119,16 @@ impl Display for Error {
     /// corrupted input or incorrectly annotated type with `#[derive(ReadFixed)]`.
     ///
     /// See [`Display::fmt`] docs for more information.
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::DataError(data_error) => data_error.fmt(f),
+            #[cfg(feature = "std")]
             Error::IoError(io_error) => io_error.fmt(f),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for Error {
     /// Wraps an `std::io::Error` in a `fixcol::error::Error`
     ///
@@ -165,6 +171,8 @@ impl Error {
         Self::DataError(DataError {
             text,
             line: None,
+            field: None,
+            span: None,
             inner_error: err.into(),
         })
     }
@@ -173,6 +181,8 @@ impl Error {
         Self::DataError(DataError {
             text: key.to_owned(),
             line: None,
+            field: None,
+            span: None,
             inner_error: InnerError::UnknownKey,
         })
     }
@@ -183,6 +193,9 @@ impl Error {
 pub struct DataError {
     text: String,
     line: Option<usize>,
+    field: Option<(usize, Option<String>)>,
+    // Kept narrow so that results holding a `DataError` stay small
+    span: Option<(u32, u32)>,
     inner_error: InnerError,
 }
 
@@ -194,10 +207,17 @@ impl DataError {
         DataError {
             text,
             line: None,
+            field: None,
+            span: None,
             inner_error: err.into(),
         }
     }
 
+    /// Creates a width mismatch error
+    ///
+    /// * `text` - The value whose width did not match
+    /// * `expected` - The declared width of the field
+    /// * `actual` - The width of the supplied value
     pub(crate) fn new_data_width_error(text: String, expected: usize, actual: usize) -> Self {
         Self::new_err(text, InnerError::InvalidWidth(expected, actual))
     }
@@ -206,6 +226,79 @@ impl DataError {
         Self::new_err(text, InnerError::WhitespaceError)
     }
 
+    pub(crate) fn skip_literal_error(text: String, literal: &str) -> Self {
+        Self::new_err(text, InnerError::SkipLiteralError(literal.to_owned()))
+    }
+
+    /// Creates a `DataError` from any of the structured [`InnerError`] kinds
+    ///
+    /// This allows custom [`FixedDeserializer`] implementations to report the
+    /// same matchable errors as the built in field types. Parse errors from
+    /// the standard library convert directly.
+    ///
+    /// * `value` - The data that we failed to parse
+    /// * `err` - The reason it could not be parsed
+    ///
+    /// [`FixedDeserializer`]: crate::FixedDeserializer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::error::{DataError, InnerError};
+    ///
+    /// let text = "12x";
+    /// let err = DataError::new(text, text.parse::<u32>().unwrap_err());
+    /// assert!(matches!(err.inner_error(), InnerError::ParseIntError(_)));
+    /// ```
+    pub fn new<Err>(value: &str, err: Err) -> Self
+    where
+        Err: Into<InnerError>,
+    {
+        Self::new_err(value.to_owned(), err)
+    }
+
+    /// Creates an error for a value whose width does not match its field
+    ///
+    /// * `value` - The value whose width did not match
+    /// * `expected` - The declared width of the field
+    /// * `actual` - The width of the supplied value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::error::DataError;
+    ///
+    /// let err = DataError::width("ABCDE", 3, 5);
+    /// assert_eq!(err.expected_width(), Some(3));
+    /// assert_eq!(err.actual_width(), Some(5));
+    /// ```
+    pub fn width(value: &str, expected: usize, actual: usize) -> Self {
+        Self::new_data_width_error(value.to_owned(), expected, actual)
+    }
+
+    /// Creates an error for unexpected or missing whitespace in `strict` mode
+    pub fn whitespace(value: &str) -> Self {
+        Self::whitespace_error(value.to_owned())
+    }
+
+    /// Creates an error for skipped columns that do not match `literal`
+    pub fn skip_literal(value: &str, literal: &str) -> Self {
+        Self::skip_literal_error(value.to_owned(), literal)
+    }
+
+    /// Creates an error for a key that does not match any known variant
+    pub fn unknown_key(key: &str) -> Self {
+        Self::new_err(key.to_owned(), InnerError::UnknownKey)
+    }
+
+    /// Creates an error for a well formed number that does not fit its type
+    ///
+    /// * `value` - The number that could not be represented
+    /// * `type_name` - The name of the type it did not fit (e.g., `"u8"`)
+    pub fn out_of_range(value: &str, type_name: &'static str) -> Self {
+        Self::new_err(value.to_owned(), InnerError::OutOfRange(type_name))
+    }
+
     /// Creates a new custom `DataError`
     ///
     /// This method will typically be used when implementing custom deserialization
@@ -224,7 +317,9 @@ impl DataError {
     /// false, or an empty column `' '` to mean null.
     ///
     /// We could create a new type with a custom parser to read that column and
-    /// use `DataError::custom` to provide error context.
+    /// use `DataError::custom` to provide error context. (In practice, an
+    /// `Option<bool>` field with `bool_tokens = "YN"` reads this column
+    /// directly.)
     ///
     /// ```
     /// use fixcol::error::DataError;
@@ -255,24 +350,158 @@ impl DataError {
             text: parsed_value.to_owned(),
             inner_error: InnerError::Custom(message.to_owned()),
             line: None,
+            field: None,
+            span: None,
         }
     }
 
+    #[cfg(feature = "std")]
     pub(crate) fn with_line(&self, line: usize) -> Self {
         let mut new_error = self.clone();
         new_error.line = Some(line);
         new_error
     }
 
+    /// Attaches the position of the field that could not be read
+    ///
+    /// This is called by the code generated by `#[derive(ReadFixed)]` so that
+    /// errors from [`ReadFixed::read_fixed`] identify the failing field.
+    ///
+    /// * `index` - The zero-based index of the field within its record
+    /// * `name` - The name of the field, if it has one
+    ///
+    /// [`ReadFixed::read_fixed`]: crate::ReadFixed::read_fixed
+    pub fn with_field(&self, index: usize, name: Option<&str>) -> Self {
+        let mut new_error = self.clone();
+        new_error.field = Some((index, name.map(str::to_owned)));
+        new_error
+    }
+
+    /// Attaches the columns of the line holding the field that could not be read
+    ///
+    /// This is called by the code generated by `#[derive(ReadFixed)]` for
+    /// fields whose position within the line is known.
+    ///
+    /// * `start` - The zero-based byte offset of the field's value in the line
+    /// * `len` - The width of the field's value
+    pub fn with_span(&self, start: usize, len: usize) -> Self {
+        let mut new_error = self.clone();
+        new_error.span = u32::try_from(start).ok().zip(u32::try_from(len).ok());
+        new_error
+    }
+
+    /// Returns the `(start, len)` of the columns holding the failing field.
+    ///
+    /// The start is the zero-based byte offset of the field's value within the
+    /// line, excluding any skipped columns, and the length is its width. A
+    /// tool displaying the raw line can use this to highlight the field.
+    ///
+    /// Returns `None` if the field's position in the line is not known, as
+    /// for fields read from the end of the line or split on a separator, or
+    /// if it lies beyond `u32::MAX` bytes into the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::error::Error;
+    /// use fixcol::ReadFixed;
+    ///
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3)]
+    ///     x: u8,
+    ///     #[fixcol(skip = 1, width = 3)]
+    ///     y: u8,
+    /// }
+    ///
+    /// match Point::read_fixed_str("1   abc") {
+    ///     Err(Error::DataError(err)) => assert_eq!(err.span(), Some((4, 3))),
+    ///     _ => panic!("Expected a data error"),
+    /// }
+    /// ```
+    pub fn span(&self) -> Option<(usize, usize)> {
+        self.span.map(|(start, len)| (start as usize, len as usize))
+    }
+
+    /// Returns the zero-based index of the field that could not be read.
+    ///
+    /// Returns `None` if the error was not raised while reading a field of a
+    /// derived [`ReadFixed`](crate::ReadFixed) type.
+    pub fn field_index(&self) -> Option<usize> {
+        self.field.as_ref().map(|(index, _)| *index)
+    }
+
+    /// Returns the name of the field that could not be read.
+    ///
+    /// Returns `None` if the field is unnamed, as in a tuple struct, or the
+    /// error was not raised while reading a field.
+    pub fn field_name(&self) -> Option<&str> {
+        self.field.as_ref().and_then(|(_, name)| name.as_deref())
+    }
+
     /// Returns the internal error that was the source of this error.
     pub fn inner_error(&self) -> &InnerError {
         &self.inner_error
     }
+
+    /// Returns the text of the data that could not be handled.
+    pub fn value(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the declared width of the field for width mismatch errors.
+    ///
+    /// Returns `None` if this error was not caused by a width mismatch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "experimental-write")]
+    /// # {
+    /// use fixcol::error::Error;
+    /// use fixcol::WriteFixed;
+    ///
+    /// #[derive(WriteFixed)]
+    /// #[fixcol(strict = true)]
+    /// struct Code {
+    ///     #[fixcol(width = 3)]
+    ///     code: String,
+    /// }
+    ///
+    /// let code = Code { code: String::from("ABCDE") };
+    /// let mut buf: Vec<u8> = Vec::new();
+    ///
+    /// match code.write_fixed(&mut buf) {
+    ///     Err(Error::DataError(err)) => {
+    ///         assert_eq!(err.value(), "ABCDE");
+    ///         assert_eq!(err.expected_width(), Some(3));
+    ///         assert_eq!(err.actual_width(), Some(5));
+    ///     }
+    ///     _ => panic!("Expected a width error"),
+    /// }
+    /// # }
+    /// ```
+    pub fn expected_width(&self) -> Option<usize> {
+        match self.inner_error {
+            InnerError::InvalidWidth(expected, _) => Some(expected),
+            _ => None,
+        }
+    }
+
+    /// Returns the width of the supplied value for width mismatch errors.
+    ///
+    /// Returns `None` if this error was not caused by a width mismatch.
+    pub fn actual_width(&self) -> Option<usize> {
+        match self.inner_error {
+            InnerError::InvalidWidth(_, actual) => Some(actual),
+            _ => None,
+        }
+    }
 }
 
 impl Display for DataError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        fn fmt_err(text: &String, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fn fmt_err(text: &String, f: &mut Formatter<'_>) -> core::fmt::Result {
             write!(f, "Error handling data from \"{}\": ", text)
         }
 
@@ -286,6 +515,10 @@ impl Display for DataError {
                 fmt_err(&self.text, f)?;
                 e.fmt(f)?;
             }
+            InnerError::OutOfRange(ty) => {
+                fmt_err(&self.text, f)?;
+                write!(f, "value {} does not fit in {}", self.text, ty)?;
+            }
             InnerError::ParseFloatError(e) => {
                 fmt_err(&self.text, f)?;
                 e.fmt(f)?;
@@ -313,6 +546,18 @@ impl Display for DataError {
                     "Found non-whitespace character between data fields (strict)"
                 )?;
             }
+            InnerError::SkipLiteralError(lit) => {
+                fmt_err(&self.text, f)?;
+                write!(f, "Expected \"{}\" between data fields (strict)", lit)?;
+            }
+        }
+
+        match &self.field {
+            Some((index, Some(name))) => {
+                write!(f, "\nError occured in field {} (`{}`)", index, name)?
+            }
+            Some((index, None)) => write!(f, "\nError occured in field {}", index)?,
+            None => {}
         }
 
         if let Some(line) = self.line {
@@ -324,12 +569,20 @@ impl Display for DataError {
 }
 
 /// Wrapper type for the known errors that can cause a [`DataError`].
+///
+/// New kinds of error may be added in minor releases, so matches on this
+/// type need a wildcard arm.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum InnerError {
     /// Other error sources for `DataErrors` that don't fit one of the other archtypes.
     Custom(String),
     /// Inner error capturing failure to parse an integer.
     ParseIntError(ParseIntError),
+    /// A well formed integer that does not fit in the field's type.
+    ///
+    /// Param is the name of the integer type.
+    OutOfRange(&'static str),
     /// Inner error capturing a failure to parse a float.
     ParseFloatError(ParseFloatError),
     /// Error decoding utf8 string from a string data field
@@ -345,6 +598,10 @@ pub enum InnerError {
     /// While parsing serialized data in `strict` mode, found missing whitespace
     /// at end of line or a non-whitespace character where whitespace was expected.
     WhitespaceError,
+    /// Skipped columns did not match the expected literal in `strict` mode.
+    ///
+    /// Param is the expected literal.
+    SkipLiteralError(String),
 }
 
 impl From<ParseFloatError> for InnerError {
@@ -381,6 +638,25 @@ mod tests {
         assert!(b);
     }
 
+    #[test]
+    fn structured_constructors() {
+        let err = DataError::whitespace("ab c");
+        assert_eq!(err.value(), "ab c");
+        assert!(matches!(err.inner_error(), InnerError::WhitespaceError));
+
+        let err = DataError::skip_literal("::", "|");
+        assert!(matches!(err.inner_error(), InnerError::SkipLiteralError(lit) if lit == "|"));
+
+        let err = DataError::unknown_key("Q");
+        assert!(matches!(err.inner_error(), InnerError::UnknownKey));
+
+        let err = DataError::out_of_range("300", "u8");
+        assert!(matches!(err.inner_error(), InnerError::OutOfRange("u8")));
+
+        let err = DataError::new("1.2.3", "1.2.3".parse::<f32>().unwrap_err());
+        assert!(matches!(err.inner_error(), InnerError::ParseFloatError(_)));
+    }
+
     #[test]
     fn wrap_data_error() {
         fn need_error(_e: Error) -> bool {
@@ -423,13 +699,31 @@ mod tests {
                     InnerError::Utf8Error(inner) => {
                         assert_eq!(inner.valid_up_to(), 6);
                     }
-                    _ => assert!(false),
+                    _ => panic!("Expected Utf8Error"),
                 }
             }
-            Error::IoError(_) => assert!(false),
+            Error::IoError(_) => panic!("Expected DataError"),
         }
     }
 
+    #[test]
+    fn width_error_accessors() {
+        let err = DataError::new_data_width_error("abcde".to_string(), 3, 5);
+
+        assert_eq!(err.value(), "abcde");
+        assert_eq!(err.expected_width(), Some(3));
+        assert_eq!(err.actual_width(), Some(5));
+    }
+
+    #[test]
+    fn non_width_error_accessors() {
+        let err = DataError::custom("abc", "oh no");
+
+        assert_eq!(err.value(), "abc");
+        assert_eq!(err.expected_width(), None);
+        assert_eq!(err.actual_width(), None);
+    }
+
     #[test]
     fn map_io_error() {
         use std::io::Write;
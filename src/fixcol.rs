@@ -1,9 +1,9 @@
 #[cfg(any(feature = "experimental-write", doc))]
 use std::io::Write;
-use std::io::{BufRead, BufReader, Lines, Read};
+use std::io::{self, BufRead, BufReader, Read};
 use std::marker::PhantomData;
 
-use crate::error::Error;
+use crate::error::{DataError, Error, InnerError};
 
 /// Trait for writing to fixed width (column based) serialization
 ///
@@ -40,6 +40,128 @@ pub trait WriteFixed {
     /// assert_eq!(std::str::from_utf8(&buffer).unwrap(), "12 7  ");
     /// ```
     fn write_fixed<W: Write>(&self, buf: &mut W) -> Result<(), Error>;
+
+    /// Writes the object, first checking it occupies exactly `width` bytes
+    ///
+    /// The record is written to a temporary buffer and only copied to `buf`
+    /// if its length is `width`. Otherwise, nothing is written and an error
+    /// is returned. This is useful for catching layout mistakes in hand
+    /// written `WriteFixed` implementations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fixcol::WriteFixed;
+    /// #[derive(WriteFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3)]
+    ///     x: u8,
+    ///     #[fixcol(width = 3)]
+    ///     y: u8,
+    /// }
+    ///
+    /// let mut buffer = Vec::new();
+    /// let point = Point { x: 12, y: 7 };
+    ///
+    /// assert!(point.write_fixed_checked(&mut buffer, 5).is_err());
+    /// assert!(buffer.is_empty());
+    ///
+    /// assert!(point.write_fixed_checked(&mut buffer, 6).is_ok());
+    /// assert_eq!(std::str::from_utf8(&buffer).unwrap(), "12 7  ");
+    /// ```
+    fn write_fixed_checked<W: Write>(&self, buf: &mut W, width: usize) -> Result<(), Error> {
+        let mut record: Vec<u8> = Vec::new();
+        self.write_fixed(&mut record)?;
+
+        if record.len() != width {
+            let text = String::from_utf8_lossy(&record).into_owned();
+            return Err(DataError::new_data_width_error(text, width, record.len()).into());
+        }
+
+        buf.write_all(&record)?;
+        Ok(())
+    }
+
+    /// The number of bytes [`write_fixed`] would write for this record
+    ///
+    /// The record is serialized without keeping its output, so the length
+    /// reflects the actual data, including the key of an enum variant and
+    /// fields whose length varies. Any error `write_fixed` would return is
+    /// returned here as well. This is useful for sizing output buffers or
+    /// checking records against a required width before writing them.
+    ///
+    /// [`write_fixed`]: WriteFixed::write_fixed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fixcol::WriteFixed;
+    /// #[derive(WriteFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3)]
+    ///     x: u8,
+    ///     #[fixcol(skip = 1, width = 3)]
+    ///     y: u8,
+    /// }
+    ///
+    /// let point = Point { x: 12, y: 7 };
+    /// assert_eq!(point.serialized_len().unwrap(), 7);
+    /// ```
+    fn serialized_len(&self) -> Result<usize, Error> {
+        let mut counter = ByteCounter(0);
+        self.write_fixed(&mut counter)?;
+        Ok(counter.0)
+    }
+
+    /// Writes a header row naming each field of the record
+    ///
+    /// Each field's name is written left aligned in the columns the field
+    /// occupies and truncated if it is wider than the field. Tuple struct
+    /// fields are unnamed and left blank. Derived implementations write the
+    /// header for structs. Enums, whose variants may have different layouts,
+    /// and manual implementations write nothing unless they override this
+    /// method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fixcol::WriteFixed;
+    /// #[derive(WriteFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3)]
+    ///     x: u8,
+    ///     #[fixcol(skip = 1, width = 3)]
+    ///     y: u8,
+    /// }
+    ///
+    /// let mut buffer = Vec::new();
+    /// Point::write_fixed_header(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(std::str::from_utf8(&buffer).unwrap(), "x   y  ");
+    /// ```
+    fn write_fixed_header<W: Write>(buf: &mut W) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        let _ = buf;
+        Ok(())
+    }
+}
+
+/// A writer that discards its input, counting the bytes written
+#[cfg(feature = "experimental-write")]
+struct ByteCounter(usize);
+
+#[cfg(feature = "experimental-write")]
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 /// Implements writing a data set in a fixed width column format
@@ -101,6 +223,74 @@ pub trait WriteFixedAll {
     /// # assert_eq!(s, "0  3  \n12342 \n42 123\n");
     /// ```
     fn write_fixed_all<W: Write>(self, buf: &mut W) -> Result<(), Error>;
+
+    /// Writes a header row followed by a set of objects (newline delimited)
+    ///
+    /// The header names each field in its columns, as written by
+    /// [`WriteFixed::write_fixed_header`], producing a self describing file.
+    /// Such a file can be read back by skipping its first line.
+    ///
+    /// # Example
+    /// ```
+    /// # use fixcol::WriteFixed;
+    /// #[derive(WriteFixed)]
+    /// struct Point {
+    ///     #[fixcol(width=3)] x: u8,
+    ///     #[fixcol(width=3)] y: u8,
+    /// }
+    ///
+    /// let v = vec![Point { x: 0, y: 3 }, Point { x: 123, y: 42 }];
+    /// let mut buffer: Vec<u8> = Vec::new();
+    ///
+    /// use fixcol::WriteFixedAll;
+    /// v.write_fixed_all_with_header(&mut buffer).unwrap();
+    ///
+    /// let s = std::str::from_utf8(&buffer).unwrap();
+    /// assert_eq!(s, "x  y  \n0  3  \n12342 \n");
+    /// ```
+    fn write_fixed_all_with_header<W: Write>(self, buf: &mut W) -> Result<(), Error>;
+
+    /// Writes a set of objects grouped by a key (newline delimited)
+    ///
+    /// The records are buffered and written in order of the key returned by
+    /// `key_fn`. Records with equal keys keep their original order, so each
+    /// group is written in the order its records were supplied. This is
+    /// useful for writing the records of an enum grouped by record type.
+    ///
+    /// # Example
+    /// ```
+    /// # use fixcol::WriteFixed;
+    /// #[derive(WriteFixed)]
+    /// #[fixcol(key_width = 1)]
+    /// enum Record {
+    ///     #[fixcol(key = "H")]
+    ///     Header(#[fixcol(width = 3)] String),
+    ///     #[fixcol(key = "D")]
+    ///     Detail(#[fixcol(width = 3)] u16),
+    /// }
+    ///
+    /// let records = vec![
+    ///     Record::Detail(1),
+    ///     Record::Header("ABC".to_string()),
+    ///     Record::Detail(2),
+    /// ];
+    /// let mut buffer: Vec<u8> = Vec::new();
+    ///
+    /// use fixcol::WriteFixedAll;
+    /// records
+    ///     .write_fixed_all_grouped(&mut buffer, |r| matches!(r, Record::Detail(_)))
+    ///     .unwrap();
+    ///
+    /// let s = std::str::from_utf8(&buffer).unwrap();
+    /// assert_eq!(s, "HABC\nD1  \nD2  \n");
+    /// ```
+    fn write_fixed_all_grouped<W, K, F>(self, buf: &mut W, key_fn: F) -> Result<(), Error>
+    where
+        Self: IntoIterator + Sized,
+        <Self as IntoIterator>::Item: WriteFixed,
+        W: Write,
+        K: Ord,
+        F: FnMut(&<Self as IntoIterator>::Item) -> K;
 }
 
 /// Blanket implementation of WriteFixedAll for collections of `impl WriteFixed`
@@ -110,18 +300,312 @@ pub trait WriteFixedAll {
 impl<T: WriteFixed, Iter: IntoIterator<Item = T>> WriteFixedAll for Iter {
     fn write_fixed_all<W: Write>(self, buf: &mut W) -> Result<(), Error> {
         for item in self.into_iter() {
-            item.write_fixed(buf)?;
-            buf.write("\n".as_bytes())?;
+            write_record(&item, buf)?;
         }
 
         Ok(())
     }
+
+    fn write_fixed_all_with_header<W: Write>(self, buf: &mut W) -> Result<(), Error> {
+        write_header::<T, W>(buf)?;
+        self.write_fixed_all(buf)
+    }
+
+    fn write_fixed_all_grouped<W, K, F>(self, buf: &mut W, mut key_fn: F) -> Result<(), Error>
+    where
+        W: Write,
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut keyed: Vec<(K, T)> = self.into_iter().map(|item| (key_fn(&item), item)).collect();
+
+        // A stable sort keeps the original order within each group
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (_, item) in keyed {
+            write_record(&item, buf)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the header row for records of type `T` followed by a newline
+#[cfg(feature = "experimental-write")]
+fn write_header<T: WriteFixed, W: Write>(buf: &mut W) -> Result<(), Error> {
+    T::write_fixed_header(buf)?;
+    buf.write_all("\n".as_bytes())?;
+
+    Ok(())
+}
+
+/// Writes a single record followed by a newline
+#[cfg(feature = "experimental-write")]
+fn write_record<T: WriteFixed, W: Write>(record: &T, buf: &mut W) -> Result<(), Error> {
+    record.write_fixed(buf)?;
+    buf.write_all("\n".as_bytes())?;
+
+    Ok(())
+}
+
+/// Writes records to a buffer one at a time
+///
+/// Unlike [`WriteFixedAll`], which consumes an entire collection at once,
+/// `FixedWriter` borrows the buffer so that records can be written as they
+/// are produced. Each record is written on its own line.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{FixedWriter, WriteFixed};
+/// #[derive(WriteFixed)]
+/// struct Point {
+///     #[fixcol(width = 3)]
+///     x: u8,
+///     #[fixcol(width = 3)]
+///     y: u8,
+/// }
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// let mut writer = FixedWriter::new(&mut buffer);
+///
+/// for i in 0..3 {
+///     writer.write_record(&Point { x: i, y: i * 2 }).unwrap();
+/// }
+/// writer.finish().unwrap();
+///
+/// assert_eq!(std::str::from_utf8(&buffer).unwrap(), "0  0  \n1  2  \n2  4  \n");
+/// ```
+#[cfg(feature = "experimental-write")]
+#[derive(Debug)]
+pub struct FixedWriter<'a, W: Write> {
+    buf: &'a mut W,
+}
+
+#[cfg(feature = "experimental-write")]
+impl<'a, W: Write> FixedWriter<'a, W> {
+    /// Creates a new `FixedWriter` that writes to the supplied buffer
+    pub fn new(buf: &'a mut W) -> Self {
+        Self { buf }
+    }
+
+    /// Writes a header row naming the fields of `T` followed by a newline
+    ///
+    /// Call this before writing any records to produce a self describing
+    /// file. See [`WriteFixed::write_fixed_header`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::{FixedWriter, WriteFixed};
+    /// #[derive(WriteFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3)]
+    ///     x: u8,
+    ///     #[fixcol(width = 3)]
+    ///     y: u8,
+    /// }
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// let mut writer = FixedWriter::new(&mut buffer);
+    ///
+    /// writer.write_header::<Point>().unwrap();
+    /// writer.write_record(&Point { x: 1, y: 2 }).unwrap();
+    /// writer.finish().unwrap();
+    ///
+    /// assert_eq!(std::str::from_utf8(&buffer).unwrap(), "x  y  \n1  2  \n");
+    /// ```
+    pub fn write_header<T: WriteFixed>(&mut self) -> Result<(), Error> {
+        write_header::<T, W>(self.buf)
+    }
+
+    /// Writes a single record to the buffer followed by a newline
+    pub fn write_record<T: WriteFixed>(&mut self, record: &T) -> Result<(), Error> {
+        write_record(record, self.buf)
+    }
+
+    /// Flushes the underlying buffer and releases the borrow
+    pub fn finish(self) -> Result<(), Error> {
+        self.buf.flush()?;
+        Ok(())
+    }
+}
+
+/// Options controlling how [`Iter`] turns lines into records
+#[derive(Clone, Debug, Default)]
+struct ReadOptions {
+    skip_lines: usize,
+    skip_blank_lines: bool,
+    comment_prefix: Option<String>,
+    lenient: bool,
+    any_line_ending: bool,
+    footer_prefix: Option<String>,
+}
+
+impl ReadOptions {
+    /// Indicates whether the given line should be passed over without parsing
+    fn ignore_line(&self, line_num: usize, line: &str) -> bool {
+        if line_num <= self.skip_lines {
+            return true;
+        }
+
+        if self.skip_blank_lines && line.trim().is_empty() {
+            return true;
+        }
+
+        match &self.comment_prefix {
+            Some(prefix) => line.starts_with(prefix.as_str()),
+            None => false,
+        }
+    }
+
+    /// Indicates whether the given line is a footer ending the records
+    fn is_footer(&self, line_num: usize, line: &str) -> bool {
+        match &self.footer_prefix {
+            Some(prefix) => line_num > self.skip_lines && line.starts_with(prefix.as_str()),
+            None => false,
+        }
+    }
+}
+
+/// Builder used to configure how a fixed column file is read
+///
+/// `FixedReader` collects the options that control how lines of a data file
+/// are turned into records and then produces an [`Iter`] over the records with
+/// [`read`]. Calling [`read_fixed_all`] is equivalent to reading with a
+/// `FixedReader` that has no options set.
+///
+/// Records are separated by `\n` or `\r\n` unless
+/// [`any_line_ending`](FixedReader::any_line_ending) is set.
+///
+/// [`read`]: FixedReader::read
+/// [`read_fixed_all`]: ReadFixed::read_fixed_all
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{FixedReader, ReadFixed};
+/// # #[derive(Debug, PartialEq, Eq)]
+/// #[derive(ReadFixed)]
+/// struct Point {
+///     #[fixcol(width = 3, align = "right")]
+///     x: u8,
+///     #[fixcol(width = 3, align = "right")]
+///     y: u8,
+/// }
+///
+/// let text = "  X  Y\n# origin\n  0  0\n\n 12  7\n";
+///
+/// let points: Vec<Point> = FixedReader::new()
+///     .skip_lines(1)
+///     .comment_prefix("#")
+///     .skip_blank_lines(true)
+///     .read(text.as_bytes())
+///     .map(|res| res.unwrap())
+///     .collect();
+///
+/// assert_eq!(points, vec![Point { x: 0, y: 0 }, Point { x: 12, y: 7 }]);
+/// ```
+#[derive(Debug)]
+pub struct FixedReader<T: ReadFixed> {
+    options: ReadOptions,
+    t: PhantomData<T>,
+}
+
+impl<T: ReadFixed> FixedReader<T> {
+    /// Creates a new `FixedReader` with the default options
+    pub fn new() -> Self {
+        Self {
+            options: ReadOptions::default(),
+            t: PhantomData,
+        }
+    }
+
+    /// Skips the first `n` lines of the file (e.g., header rows)
+    pub fn skip_lines(mut self, n: usize) -> Self {
+        self.options.skip_lines = n;
+        self
+    }
+
+    /// Skips lines that are empty or contain only whitespace
+    pub fn skip_blank_lines(mut self, skip: bool) -> Self {
+        self.options.skip_blank_lines = skip;
+        self
+    }
+
+    /// Skips lines that begin with the supplied prefix
+    pub fn comment_prefix(mut self, prefix: &str) -> Self {
+        self.options.comment_prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// Skips lines that fail to parse instead of returning an error
+    ///
+    /// Only data errors are skipped. I/O errors are always returned and will
+    /// end the iteration.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.options.lenient = lenient;
+        self
+    }
+
+    /// Treats a bare `\r` as a line separator
+    ///
+    /// By default records are separated by `\n` or `\r\n`. When set, a lone
+    /// `\r`, as used by some legacy files, also ends a record. A `\r\n`
+    /// pair is still a single separator.
+    pub fn any_line_ending(mut self, any: bool) -> Self {
+        self.options.any_line_ending = any;
+        self
+    }
+
+    /// Ends the records at the first line that begins with the supplied prefix
+    ///
+    /// Some formats close with a trailer line, such as a record count or
+    /// checksum, that is not a data record. The footer line is not parsed and
+    /// nothing after it is read. Once iteration ends the footer is available
+    /// from [`Iter::footer`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::{FixedReader, ReadFixed};
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// let text = "  1  2\n  3  4\nTRLR 2\n";
+    /// let mut points = FixedReader::<Point>::new()
+    ///     .stop_at_prefix("TRLR")
+    ///     .read(text.as_bytes());
+    ///
+    /// assert_eq!(points.by_ref().count(), 2);
+    /// assert_eq!(points.footer(), Some("TRLR 2"));
+    /// ```
+    pub fn stop_at_prefix(mut self, prefix: &str) -> Self {
+        self.options.footer_prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// Consumes a buffer returning an [`Iterator`] over objects of type `T`
+    pub fn read<R: Read>(self, buf: R) -> Iter<T, R> {
+        Iter::new(buf, self.options)
+    }
+}
+
+impl<T: ReadFixed> Default for FixedReader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Iterator over the deserialized lines of a fixed column file
 ///
 /// Implements [`Iterator`] for `T`. This struct is created by a call to
-/// [`read_fixed_all`].
+/// [`read_fixed_all`] or [`FixedReader::read`].
 ///
 /// [`read_fixed_all`]: ReadFixed::read_fixed_all
 #[derive(Debug)]
@@ -130,59 +614,348 @@ where
     T: ReadFixed,
     R: Read,
 {
-    // TODO: it might be more performant do operate at a slighly lower level
-    // than mapping over ther BufReader lines iterator. If we did that, we'd use
-    // fields that look something like the following:
-    //
-    // read_buf: BufReader<R>,
-    // line_buf: String,
     failed: bool,
     line: usize,
-    lines: Lines<BufReader<R>>,
+    record_line: usize,
+    footer: Option<String>,
+    read_buf: BufReader<R>,
+    options: ReadOptions,
     t: PhantomData<T>,
 }
 
 impl<T: ReadFixed, R: Read> Iter<T, R> {
-    fn new(read: R) -> Self {
+    fn new(read: R, options: ReadOptions) -> Self {
         Self {
-            lines: BufReader::new(read).lines(),
+            read_buf: BufReader::new(read),
             line: 0,
+            record_line: 0,
+            footer: None,
             failed: false,
+            options,
             t: PhantomData,
         }
     }
+
+    /// Reads the next line without its line ending
+    ///
+    /// Returns `None` at the end of the buffer. The line is left undecoded so
+    /// that the record type decides how to handle invalid UTF-8.
+    fn next_line(&mut self) -> Option<io::Result<Vec<u8>>> {
+        let mut bytes: Vec<u8> = Vec::new();
+
+        let res = if self.options.any_line_ending {
+            read_until_any_ending(&mut self.read_buf, &mut bytes)
+        } else {
+            let res = self.read_buf.read_until(b'\n', &mut bytes);
+            if bytes.ends_with(b"\r\n") {
+                bytes.truncate(bytes.len() - 2);
+            } else if bytes.ends_with(b"\n") {
+                bytes.pop();
+            }
+            res
+        };
+
+        match res {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(bytes)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Appends the remaining lines of a record spanning several lines
+    ///
+    /// Records of a type whose [`ReadFixed::LINES`] is greater than one are
+    /// read by joining that many lines with no separator. It is an error for
+    /// the buffer to end part way through a record.
+    fn read_continuation(&mut self, record: &mut Vec<u8>) -> Result<(), Error> {
+        for read in 1..T::LINES {
+            match self.next_line() {
+                Some(Ok(bytes)) => {
+                    self.line += 1;
+                    record.extend(bytes);
+                }
+                Some(Err(e)) => return Err(Error::IoError(e)),
+                None => {
+                    let message = format!("Record ends after {} of {} lines", read, T::LINES);
+                    let text = String::from_utf8_lossy(record);
+                    return Err(DataError::custom(&text, &message).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the iterator, partitioning parsed records and errors
+    ///
+    /// Records are returned in the order they were read, as are errors. This
+    /// allows reporting every bad row in a file rather than stopping at the
+    /// first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::ReadFixed;
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// let text = "  1  2\n  3 abc\n  5  6\n";
+    /// let (points, errors) = Point::read_fixed_all(text.as_bytes()).collect_results();
+    ///
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn collect_results(self) -> (Vec<T>, Vec<Error>) {
+        let mut records = Vec::new();
+        let mut errors = Vec::new();
+
+        for res in self {
+            match res {
+                Ok(record) => records.push(record),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        (records, errors)
+    }
+
+    /// Consumes the iterator, returning every record or the first error
+    ///
+    /// Reading stops at the first error, which includes the line number it
+    /// occured on. This is equivalent to collecting into a
+    /// `Result<Vec<T>, Error>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::ReadFixed;
+    /// #[derive(ReadFixed)]
+    /// # #[derive(Debug)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// let points = Point::read_fixed_all("  1  2\n  5  6\n".as_bytes())
+    ///     .collect_or_first_error()
+    ///     .unwrap();
+    /// assert_eq!(points.len(), 2);
+    ///
+    /// let err = Point::read_fixed_all("  1  2\n  3 abc\n".as_bytes())
+    ///     .collect_or_first_error()
+    ///     .unwrap_err();
+    /// assert!(err.to_string().ends_with("Error occured on line 2\n"));
+    /// ```
+    pub fn collect_or_first_error(self) -> Result<Vec<T>, Error> {
+        self.collect()
+    }
+
+    /// Returns the footer line that ended the records, if one has been read
+    ///
+    /// A footer is only recognized when reading with
+    /// [`FixedReader::stop_at_prefix`].
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// Pairs each result with the line number it was read from
+    ///
+    /// Line numbers start at one and count every line of the buffer,
+    /// including any skipped lines, so they match the line reported by errors.
+    /// A record spanning several lines is numbered by its first line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::{FixedReader, ReadFixed};
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// let text = "  x  y\n  1  2\n  3 abc\n";
+    /// let lines: Vec<usize> = FixedReader::<Point>::new()
+    ///     .skip_lines(1)
+    ///     .read(text.as_bytes())
+    ///     .with_line_numbers()
+    ///     .map(|(line, _)| line)
+    ///     .collect();
+    ///
+    /// assert_eq!(lines, vec![2, 3]);
+    /// ```
+    pub fn with_line_numbers(self) -> LineNumbers<T, R> {
+        LineNumbers { iter: self }
+    }
+}
+
+/// Iterator over deserialized lines paired with their line numbers
+///
+/// This struct is created by [`Iter::with_line_numbers`].
+#[derive(Debug)]
+pub struct LineNumbers<T, R>
+where
+    T: ReadFixed,
+    R: Read,
+{
+    iter: Iter<T, R>,
+}
+
+impl<T: ReadFixed, R: Read> Iterator for LineNumbers<T, R> {
+    type Item = (usize, Result<T, Error>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.iter.next()?;
+        Some((self.iter.record_line, res))
+    }
+}
+
+/// Reads into `bytes` up to a `\n`, `\r`, or `\r\n` line ending
+///
+/// The line ending is consumed but not included. Returns the number of bytes
+/// consumed, which is zero only at the end of the buffer.
+fn read_until_any_ending<B: BufRead>(read: &mut B, bytes: &mut Vec<u8>) -> io::Result<usize> {
+    let mut consumed = 0;
+
+    loop {
+        let available = match read.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        if available.is_empty() {
+            return Ok(consumed);
+        }
+
+        match available.iter().position(|b| *b == b'\n' || *b == b'\r') {
+            Some(i) => {
+                let carriage_return = available[i] == b'\r';
+                bytes.extend_from_slice(&available[..i]);
+                read.consume(i + 1);
+                consumed += i + 1;
+
+                if carriage_return && read.fill_buf()?.first() == Some(&b'\n') {
+                    read.consume(1);
+                    consumed += 1;
+                }
+
+                return Ok(consumed);
+            }
+            None => {
+                let n = available.len();
+                bytes.extend_from_slice(available);
+                read.consume(n);
+                consumed += n;
+            }
+        }
+    }
 }
 
 impl<T: ReadFixed, R: Read> Iterator for Iter<T, R> {
     type Item = Result<T, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.failed {
-            None
-        } else {
+        while !self.failed && self.footer.is_none() {
             self.line += 1;
-            match self.lines.next() {
-                None => None,
+            match self.next_line() {
+                None => return None,
                 Some(Err(e)) => {
                     self.failed = true;
-                    Some(Err(Error::IoError(e)))
+                    return Some(Err(Error::IoError(e)));
                 }
-                Some(Ok(s)) => {
-                    // TODO: think about whether we want to allow it to return the
-                    // errored line and keep going
-                    match T::read_fixed_string(s) {
+                Some(Ok(mut bytes)) => {
+                    let text = String::from_utf8_lossy(&bytes);
+                    if self.options.is_footer(self.line, &text) {
+                        self.footer = Some(text.into_owned());
+                        return None;
+                    }
+                    if self.options.ignore_line(self.line, &text) {
+                        continue;
+                    }
+
+                    self.record_line = self.line;
+                    match self.read_continuation(&mut bytes) {
+                        Ok(()) => {}
+                        Err(Error::DataError(_)) if self.options.lenient => continue,
                         Err(Error::DataError(err)) => {
-                            let err_with_line = err.with_line(self.line);
-                            Some(Err(Error::DataError(err_with_line)))
+                            self.failed = true;
+                            let err_with_line = err.with_line(self.record_line);
+                            return Some(Err(Error::DataError(err_with_line)));
+                        }
+                        Err(err) => {
+                            self.failed = true;
+                            return Some(Err(err));
                         }
-                        other => Some(other),
+                    }
+
+                    match T::read_fixed(&mut bytes.as_slice()) {
+                        Err(Error::DataError(_)) if self.options.lenient => continue,
+                        Err(Error::DataError(err)) => {
+                            let err_with_line = err.with_line(self.record_line);
+                            return Some(Err(Error::DataError(err_with_line)));
+                        }
+                        other => return Some(other),
                     }
                 }
             }
         }
+
+        None
     }
 }
 
+/// A type that always occupies the same number of columns
+///
+/// A field whose type implements `FixedWidth` may omit its `width` parameter,
+/// taking its width from [`FixedWidth::WIDTH`] instead. This is implemented by
+/// `#[derive(ReadFixed)]` for enums whose variants are all keyed unit variants,
+/// using the enum's `key_width`.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::ReadFixed;
+///
+/// #[derive(Debug, PartialEq, ReadFixed)]
+/// #[fixcol(key_width = 1)]
+/// enum Side {
+///     #[fixcol(key = "B")]
+///     Buy,
+///     #[fixcol(key = "S")]
+///     Sell,
+/// }
+///
+/// #[derive(ReadFixed)]
+/// struct Order {
+///     #[fixcol(width = 4)]
+///     symbol: String,
+///     side: Side,
+///     #[fixcol(width = 5, align = "right")]
+///     quantity: u32,
+/// }
+///
+/// let order = Order::read_fixed_str("ACMES  100").unwrap();
+/// assert_eq!(order.side, Side::Sell);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the width of `{Self}` is not known",
+    label = "add a `width` parameter to this field"
+)]
+pub trait FixedWidth {
+    /// The number of columns occupied by the type
+    const WIDTH: usize;
+}
+
 /// Trait for reading from fixed width (column based) serializaiton
 ///
 /// This trait is the main entry point to using `fixcol` for deserializing
@@ -222,10 +995,19 @@ pub trait ReadFixed {
         Self: Sized,
         R: Read;
 
+    /// The number of lines each record occupies
+    ///
+    /// When greater than one, [`read_fixed_all`](ReadFixed::read_fixed_all)
+    /// joins this many lines, with no separator, into the buffer passed to
+    /// [`read_fixed`](ReadFixed::read_fixed). Derived implementations set
+    /// this with the `lines` parameter.
+    const LINES: usize = 1;
+
     /// Consumes a buffer returning objects of type `Self`
     ///
     /// Lazily reads the entier content of `buf` returning an [`Iterator`]
-    /// over deserialized objects.
+    /// over deserialized objects. Use [`FixedReader`] to configure how the
+    /// lines of `buf` are read.
     ///
     /// # Example
     /// ```
@@ -257,7 +1039,41 @@ pub trait ReadFixed {
         Self: Sized,
         R: Read,
     {
-        Iter::new(buf)
+        FixedReader::new().read(buf)
+    }
+
+    /// Reads at most the first `n` objects of type `Self` from a buffer
+    ///
+    /// Stops after `n` records have been read, so the rest of `buf` is never
+    /// read. This is useful for previewing large files. Returns the first
+    /// error encountered, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # use fixcol::ReadFixed;
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// # fn f() -> Result<(), fixcol::error::Error> {
+    /// let buf = "  1  2\n  3  4\n  5  6\n";
+    /// let points = Point::read_fixed_n(buf.as_bytes(), 2)?;
+    /// assert_eq!(points.len(), 2);
+    /// assert_eq!(points[1].x, 3);
+    /// # Ok(())
+    /// # }
+    /// # assert!(f().is_ok());
+    /// ```
+    fn read_fixed_n<R>(buf: R, n: usize) -> Result<Vec<Self>, Error>
+    where
+        Self: Sized,
+        R: Read,
+    {
+        Self::read_fixed_all(buf).take(n).collect()
     }
 
     /// Reads an instance of the object fom a `&str`
@@ -340,20 +1156,348 @@ pub trait ReadFixed {
     /// }
     ///
     /// # fn f() -> Result<(), fixcol::error::Error> {
-    /// let s = String::from(" 42  7");
-    /// let point = Point::read_fixed_string(s)?;
-    /// assert_eq!(point.x, 42);
-    /// assert_eq!(point.y, 7);
+    /// let s = String::from(" 42  7");
+    /// let point = Point::read_fixed_string(s)?;
+    /// assert_eq!(point.x, 42);
+    /// assert_eq!(point.y, 7);
+    /// # Ok(())
+    /// # }
+    /// # assert!(f().is_ok());
+    /// ```
+    fn read_fixed_string(s: String) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let mut bytes = s.as_bytes();
+        Self::read_fixed(&mut bytes)
+    }
+
+    /// Attempts to read an instance of the object from a `str`
+    ///
+    /// Returns `Ok(None)` when the text is not a record of this type, meaning
+    /// its key does not match any variant of the enum being read. A line that
+    /// has a matching key but cannot be read is still an error, so mixed files
+    /// can be read by trying several record types in turn without hiding
+    /// corrupt lines. A line too short to hold the key is also an error. Types
+    /// without a key, such as structs, never return `Ok(None)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::ReadFixed;
+    /// # #[derive(Debug, PartialEq)]
+    /// #[derive(ReadFixed)]
+    /// #[fixcol(key_width = 1)]
+    /// enum Shape {
+    ///     #[fixcol(key = "S")]
+    ///     Square(#[fixcol(width = 3, align = "right")] u16),
+    /// }
+    ///
+    /// # #[derive(Debug, PartialEq)]
+    /// #[derive(ReadFixed)]
+    /// #[fixcol(key_width = 1)]
+    /// enum Color {
+    ///     #[fixcol(key = "C")]
+    ///     Rgb(#[fixcol(width = 6)] String),
+    /// }
+    ///
+    /// # fn f() -> Result<(), fixcol::error::Error> {
+    /// let line = "Cff8800";
+    /// assert!(Shape::try_read_fixed_str(line)?.is_none());
+    /// assert!(Color::try_read_fixed_str(line)?.is_some());
+    ///
+    /// // The key matches but the record is malformed
+    /// assert!(Shape::try_read_fixed_str("S1x2").is_err());
+    /// # Ok(())
+    /// # }
+    /// # assert!(f().is_ok());
+    /// ```
+    fn try_read_fixed_str(s: &str) -> Result<Option<Self>, Error>
+    where
+        Self: Sized,
+    {
+        match Self::read_fixed_str(s) {
+            Ok(value) => Ok(Some(value)),
+            // Unknown keys within a field belong to a record that did match
+            Err(Error::DataError(err))
+                if matches!(err.inner_error(), InnerError::UnknownKey)
+                    && err.field_index().is_none() =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Reads an instance of the object starting `offset` bytes into `s`
+    ///
+    /// Returns the object along with the offset just past the bytes it was
+    /// read from, allowing successive records to be read from one buffer
+    /// without slicing it by hand. [`SliceReader`] keeps track of the offset
+    /// automatically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixcol::ReadFixed;
+    ///
+    /// #[derive(ReadFixed)]
+    /// struct Point {
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     x: u8,
+    ///     #[fixcol(width = 3, align = "right")]
+    ///     y: u8,
+    /// }
+    ///
+    /// # fn f() -> Result<(), fixcol::error::Error> {
+    /// let s = ">>123 61  4  9";
+    /// let (first, offset) = Point::read_fixed_at(s, 2)?;
+    /// let (second, offset) = Point::read_fixed_at(s, offset)?;
+    ///
+    /// assert_eq!((first.x, first.y), (123, 61));
+    /// assert_eq!((second.x, second.y), (4, 9));
+    /// assert_eq!(offset, s.len());
     /// # Ok(())
     /// # }
     /// # assert!(f().is_ok());
     /// ```
-    fn read_fixed_string(s: String) -> Result<Self, Error>
+    fn read_fixed_at(s: &str, offset: usize) -> Result<(Self, usize), Error>
     where
         Self: Sized,
     {
-        let mut bytes = s.as_bytes();
-        Self::read_fixed(&mut bytes)
+        let mut bytes = s
+            .as_bytes()
+            .get(offset..)
+            .ok_or_else(|| Error::IoError(io::ErrorKind::UnexpectedEof.into()))?;
+        let remaining = bytes.len();
+
+        let value = Self::read_fixed(&mut bytes)?;
+        Ok((value, offset + remaining - bytes.len()))
+    }
+}
+
+/// Cursor reading successive records from a single buffer
+///
+/// `SliceReader` tracks the offset of the next unread byte so that records
+/// packed into one buffer can be read one after another, each with its own
+/// type if needed. See [`ReadFixed::read_fixed_at`] to manage the offset
+/// manually.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{ReadFixed, SliceReader};
+///
+/// #[derive(ReadFixed)]
+/// struct Header {
+///     #[fixcol(width = 4)]
+///     name: String,
+/// }
+///
+/// #[derive(ReadFixed)]
+/// struct Point {
+///     #[fixcol(width = 3, align = "right")]
+///     x: u8,
+///     #[fixcol(width = 3, align = "right")]
+///     y: u8,
+/// }
+///
+/// # fn f() -> Result<(), fixcol::error::Error> {
+/// let mut reader = SliceReader::new("PTS   1  2 12 40");
+/// let header: Header = reader.read()?;
+/// assert_eq!(header.name, "PTS");
+///
+/// let mut points: Vec<Point> = Vec::new();
+/// while !reader.is_empty() {
+///     points.push(reader.read()?);
+/// }
+///
+/// assert_eq!(points.len(), 2);
+/// assert_eq!(reader.offset(), 16);
+/// # Ok(())
+/// # }
+/// # assert!(f().is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SliceReader<'a> {
+    buf: &'a str,
+    offset: usize,
+}
+
+impl<'a> SliceReader<'a> {
+    /// Creates a `SliceReader` positioned at the start of `buf`
+    pub fn new(buf: &'a str) -> Self {
+        Self { buf, offset: 0 }
+    }
+
+    /// Reads the next record, advancing past the bytes it was read from
+    ///
+    /// The offset is left unchanged if the record cannot be read.
+    pub fn read<T: ReadFixed>(&mut self) -> Result<T, Error> {
+        let (value, offset) = T::read_fixed_at(self.buf, self.offset)?;
+        self.offset = offset;
+        Ok(value)
+    }
+
+    /// The offset of the next unread byte
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The text that has not yet been read
+    pub fn remaining(&self) -> &'a str {
+        self.buf.get(self.offset..).unwrap_or_default()
+    }
+
+    /// Whether every byte of the buffer has been read
+    pub fn is_empty(&self) -> bool {
+        self.offset >= self.buf.len()
+    }
+}
+
+/// Trait for reading records that borrow from the text they are read from
+///
+/// Where [`ReadFixed`] copies each text field into a [`String`], a record
+/// implementing `ReadFixedRef` may hold `&'a str` fields that point into the
+/// line it was read from. This avoids an allocation per field when reading
+/// from a buffer already in memory, such as a memory mapped file, at the cost
+/// of the record not outliving that buffer.
+///
+/// `ReadFixedRef` is normally derived on a struct with a lifetime parameter.
+/// Its fields may be `&'a str` or any type implementing
+/// [`FixedDeserializer`](crate::FixedDeserializer), and accept the same
+/// parameters as with [`ReadFixed`]. Fields read from the end of the line and
+/// records spanning several lines are not supported. The text is already
+/// decoded, so the `utf8` and `encoding` parameters have no effect, and field
+/// boundaries must fall between characters.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::ReadFixedRef;
+///
+/// #[derive(ReadFixedRef)]
+/// struct Atom<'a> {
+///     #[fixcol(width = 2)]
+///     symbol: &'a str,
+///     #[fixcol(skip = 1, width = 3, align = "right")]
+///     number: u8,
+///     #[fixcol(skip = 1, width = 8)]
+///     name: &'a str,
+/// }
+///
+/// let text = String::from("H    1 Hydrogen\nHe   2 Helium  \n");
+/// let atoms: Vec<Atom> = Atom::read_fixed_all_ref(&text)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(atoms[1].symbol, "He");
+/// assert_eq!(atoms[1].number, 2);
+/// assert_eq!(atoms[1].name, "Helium");
+/// ```
+pub trait ReadFixedRef<'a>: Sized {
+    /// Reads an instance of the object from a single line of text
+    ///
+    /// Text fields of the returned object borrow from `line`.
+    fn read_fixed_ref(line: &'a str) -> Result<Self, Error>;
+
+    /// Reads an instance of the object from each line of `buf`
+    ///
+    /// Lines may end with `\n` or `\r\n`. Errors reading a record are
+    /// reported with its line number and reading continues with the next
+    /// line.
+    fn read_fixed_all_ref(buf: &'a str) -> RefIter<'a, Self> {
+        RefIter {
+            lines: buf.lines(),
+            line: 0,
+            t: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the records of a buffer read by [`ReadFixedRef`]
+///
+/// Created by [`ReadFixedRef::read_fixed_all_ref`].
+#[derive(Clone, Debug)]
+pub struct RefIter<'a, T> {
+    lines: core::str::Lines<'a>,
+    line: usize,
+    t: PhantomData<T>,
+}
+
+impl<'a, T: ReadFixedRef<'a>> Iterator for RefIter<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        self.line += 1;
+
+        match T::read_fixed_ref(line) {
+            Err(Error::DataError(err)) => Some(Err(Error::DataError(err.with_line(self.line)))),
+            other => Some(other),
+        }
+    }
+}
+
+/// A record that captures an entire line verbatim
+///
+/// `RawLine` implements [`ReadFixed`] by reading everything remaining in the
+/// supplied buffer into a [`String`] without any trimming or field extraction.
+/// Combined with [`read_fixed_all`] it lets you iterate over the raw lines of
+/// a file through the same API used for modeled records, which is useful for
+/// passthrough processing or while exploring a file before its schema has been
+/// defined.
+///
+/// [`read_fixed_all`]: ReadFixed::read_fixed_all
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{RawLine, ReadFixed};
+///
+/// let buf = "NODE 001 Item A\nEDGE 001 002\n".as_bytes();
+/// let lines: Vec<RawLine> = RawLine::read_fixed_all(buf)
+///     .map(|res| res.unwrap())
+///     .collect();
+///
+/// assert_eq!(lines[0].0, "NODE 001 Item A");
+/// assert_eq!(lines[1].as_str(), "EDGE 001 002");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RawLine(pub String);
+
+impl RawLine {
+    /// Returns the content of the line as a `&str`
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl From<RawLine> for String {
+    fn from(value: RawLine) -> Self {
+        value.0
+    }
+}
+
+impl ReadFixed for RawLine {
+    fn read_fixed<R>(buf: &mut R) -> Result<Self, Error>
+    where
+        Self: Sized,
+        R: Read,
+    {
+        let mut bytes: Vec<u8> = Vec::new();
+        buf.read_to_end(&mut bytes)?;
+        let s = String::from_utf8(bytes)?;
+
+        Ok(Self(s))
+    }
+}
+
+#[cfg(feature = "experimental-write")]
+impl WriteFixed for RawLine {
+    fn write_fixed<W: Write>(&self, buf: &mut W) -> Result<(), Error> {
+        buf.write_all(self.0.as_bytes())?;
+        Ok(())
     }
 }
 
@@ -395,6 +1539,40 @@ mod tests {
         assert_eq!(foo.unwrap(), Foo { foo: "bar".to_string() });
     }
 
+    #[derive(Debug, PartialEq, ReadFixed)]
+    struct Pair {
+        #[fixcol(width = 2)]
+        a: String,
+        #[fixcol(width = 2, align = "right")]
+        b: u8,
+    }
+
+    #[test]
+    fn read_fixed_at() {
+        let s = "ab 1cd 2";
+        let (pair, offset) = Pair::read_fixed_at(s, 4).unwrap();
+        assert_eq!(pair, Pair { a: "cd".to_string(), b: 2 });
+        assert_eq!(offset, 8);
+
+        assert!(Pair::read_fixed_at(s, 8).is_err());
+        assert!(Pair::read_fixed_at(s, 9).is_err());
+    }
+
+    #[test]
+    fn slice_reader() {
+        let mut reader = SliceReader::new("ab 1cdxx");
+        assert_eq!(
+            reader.read::<Pair>().unwrap(),
+            Pair { a: "ab".to_string(), b: 1 }
+        );
+        assert_eq!(reader.remaining(), "cdxx");
+
+        // A failed read leaves the offset in place
+        assert!(reader.read::<Pair>().is_err());
+        assert_eq!(reader.offset(), 4);
+        assert!(!reader.is_empty());
+    }
+
     #[test]
     fn read_fixed_all() {
         let buf = "foo\nbar\nbaz\n";
@@ -429,6 +1607,328 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn read_raw_line() {
+        let line = RawLine::read_fixed_str("  foo  bar  ").unwrap();
+        assert_eq!(line, RawLine(String::from("  foo  bar  ")));
+    }
+
+    #[test]
+    fn read_raw_lines_all() {
+        let buf = "foo\n  bar\nbaz  \n";
+
+        let actual: Vec<String> = RawLine::read_fixed_all(buf.as_bytes())
+            .map(|r| r.unwrap().into())
+            .collect();
+
+        assert_eq!(actual, vec!["foo", "  bar", "baz  "]);
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-write")]
+    fn write_raw_line() {
+        let line = RawLine(String::from(" foo bar "));
+
+        let mut v = Vec::new();
+        let res = line.write_fixed(&mut v);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " foo bar ");
+    }
+
+    #[test]
+    #[cfg(feature = "experimental-write")]
+    fn write_fixed_checked() {
+        let line = RawLine(String::from(" foo bar "));
+
+        let mut v = Vec::new();
+        let err = line.write_fixed_checked(&mut v, 8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \" foo bar \": Expected field to have width 8 but \
+            supplied value has width 9.\n"
+        );
+        assert!(v.is_empty());
+
+        let res = line.write_fixed_checked(&mut v, 9);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " foo bar ");
+    }
+
+    #[test]
+    fn reader_skip_lines() {
+        let buf = "header\nfoo\nbar\n";
+
+        let actual: Vec<Foo> = FixedReader::new()
+            .skip_lines(1)
+            .read(buf.as_bytes())
+            .map(|r| r.unwrap())
+            .collect();
+
+        let expected = vec![
+            Foo { foo: "foo".to_string() },
+            Foo { foo: "bar".to_string() },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn reader_skip_blank_and_comments() {
+        let buf = "foo\n\n# comment\n   \nbar\n";
+
+        let actual: Vec<Foo> = FixedReader::new()
+            .skip_blank_lines(true)
+            .comment_prefix("#")
+            .read(buf.as_bytes())
+            .map(|r| r.unwrap())
+            .collect();
+
+        let expected = vec![
+            Foo { foo: "foo".to_string() },
+            Foo { foo: "bar".to_string() },
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[derive(ReadFixed, Debug, PartialEq, Eq)]
+    #[fixcol(strict = true)]
+    struct Num {
+        #[fixcol(width = 3, align = "right")]
+        n: u16,
+    }
+
+    #[test]
+    fn reader_lenient() {
+        let buf = "  1\nabc\n  3\n";
+
+        let strict: Vec<Result<Num, Error>> = FixedReader::new().read(buf.as_bytes()).collect();
+        assert_eq!(strict.len(), 3);
+        assert_eq!(
+            strict[1].as_ref().unwrap_err().to_string(),
+            "Error handling data from \"abc\": invalid digit found in string\n\
+            Error occured in field 0 (`n`)\n\
+            Error occured on line 2\n"
+        );
+
+        let lenient: Vec<Num> = FixedReader::new()
+            .lenient(true)
+            .read(buf.as_bytes())
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(lenient, vec![Num { n: 1 }, Num { n: 3 }]);
+    }
+
+    #[test]
+    fn collect_results() {
+        let buf = "  1\nabc\n  3\n x \n";
+
+        let (records, errors) = Num::read_fixed_all(buf.as_bytes()).collect_results();
+        assert_eq!(records, vec![Num { n: 1 }, Num { n: 3 }]);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[1].to_string(),
+            "Error handling data from \"x \": invalid digit found in string\n\
+            Error occured in field 0 (`n`)\n\
+            Error occured on line 4\n"
+        );
+    }
+
+    #[test]
+    fn collect_or_first_error() {
+        let buf = "  1\nabc\n  3\n x \n";
+
+        let err = Num::read_fixed_all(buf.as_bytes())
+            .collect_or_first_error()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"abc\": invalid digit found in string\n\
+            Error occured in field 0 (`n`)\n\
+            Error occured on line 2\n"
+        );
+
+        let records = Num::read_fixed_all("  1\n  3\n".as_bytes())
+            .collect_or_first_error()
+            .unwrap();
+        assert_eq!(records, vec![Num { n: 1 }, Num { n: 3 }]);
+    }
+
+    #[test]
+    fn with_line_numbers() {
+        let buf = "  1\n# comment\nabc\n  3\n";
+
+        let actual: Vec<(usize, Result<Num, Error>)> = FixedReader::<Num>::new()
+            .comment_prefix("#")
+            .read(buf.as_bytes())
+            .with_line_numbers()
+            .collect();
+
+        assert_eq!(actual.len(), 3);
+        assert!(matches!(actual[0], (1, Ok(Num { n: 1 }))));
+        assert_eq!(actual[1].0, 3);
+        let err = actual[1].1.as_ref().unwrap_err();
+        assert!(err.to_string().ends_with("Error occured on line 3\n"));
+        assert!(matches!(actual[2], (4, Ok(Num { n: 3 }))));
+    }
+
+    #[test]
+    fn read_fixed_n() {
+        // Records past the first n are never parsed
+        let buf = "  1\n  2\nabc\n";
+        let records = Num::read_fixed_n(buf.as_bytes(), 2).unwrap();
+        assert_eq!(records, vec![Num { n: 1 }, Num { n: 2 }]);
+
+        let records = Num::read_fixed_n("  1\n".as_bytes(), 5).unwrap();
+        assert_eq!(records, vec![Num { n: 1 }]);
+
+        let err = Num::read_fixed_n(buf.as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"abc\": invalid digit found in string\n\
+            Error occured in field 0 (`n`)\n\
+            Error occured on line 3\n"
+        );
+    }
+
+    #[test]
+    fn reader_default_line_endings() {
+        let buf = "foo\r\nbar\rbaz\nqux";
+
+        let actual: Vec<String> = FixedReader::<Foo>::new()
+            .read(buf.as_bytes())
+            .map(|r| r.unwrap().foo)
+            .collect();
+
+        assert_eq!(actual, vec!["foo", "bar\rbaz", "qux"]);
+    }
+
+    #[test]
+    fn reader_any_line_ending() {
+        let buf = "foo\r\nbar\rbaz\n\rqux\r";
+
+        let actual: Vec<String> = FixedReader::<Foo>::new()
+            .any_line_ending(true)
+            .read(buf.as_bytes())
+            .map(|r| r.unwrap().foo)
+            .collect();
+
+        assert_eq!(actual, vec!["foo", "bar", "baz", "", "qux"]);
+    }
+
+    /// Reader that returns at most four bytes per call
+    struct Chunked<'a>(&'a [u8]);
+
+    impl Read for Chunked<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(4);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[derive(ReadFixed, Debug, PartialEq, Eq)]
+    #[fixcol(lines = 2)]
+    struct TwoLines {
+        #[fixcol(width = 3, align = "right")]
+        a: u16,
+        #[fixcol(width = 3, align = "right")]
+        b: u16,
+    }
+
+    /// Reader that returns its text and then fails
+    struct Failing<'a>(&'a [u8]);
+
+    impl Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(io::Error::other("read failed"));
+            }
+
+            let n = self.0.len().min(buf.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn reader_lenient_io_error() {
+        // The reader fails while reading the second line of the first record
+        let read = Failing("  1\n".as_bytes());
+
+        let results: Vec<Result<TwoLines, Error>> =
+            FixedReader::new().lenient(true).read(read).collect();
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(Error::IoError(_))));
+    }
+
+    #[test]
+    fn reader_lenient_truncated_record() {
+        let buf = "  1\n  2\n  3\n";
+
+        let results: Vec<Result<TwoLines, Error>> = FixedReader::new()
+            .lenient(true)
+            .read(buf.as_bytes())
+            .collect();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap(), &TwoLines { a: 1, b: 2 });
+    }
+
+    #[test]
+    fn reader_any_line_ending_split_read() {
+        // The \r\n pair is split across reads of the underlying buffer
+        let read = Chunked("foo\r\nbar\r".as_bytes());
+
+        let actual: Vec<String> = FixedReader::<Foo>::new()
+            .any_line_ending(true)
+            .read(read)
+            .map(|r| r.unwrap().foo)
+            .collect();
+
+        assert_eq!(actual, vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn reader_line_numbers_count_skipped() {
+        let buf = "header\n# comment\nabc\n";
+
+        let err = FixedReader::<Num>::new()
+            .skip_lines(1)
+            .comment_prefix("#")
+            .read(buf.as_bytes())
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        assert!(err.to_string().ends_with("Error occured on line 3\n"));
+    }
+
+    #[test]
+    fn reader_stop_at_prefix() {
+        let buf = "TRLR header\n  1\n  2\nTRLR 000002\n  3\n";
+
+        let mut iter = FixedReader::<Num>::new()
+            .skip_lines(1)
+            .stop_at_prefix("TRLR")
+            .read(buf.as_bytes());
+
+        assert!(iter.footer().is_none());
+        let actual: Vec<Num> = iter.by_ref().map(|r| r.unwrap()).collect();
+        assert_eq!(actual, vec![Num { n: 1 }, Num { n: 2 }]);
+        assert_eq!(iter.footer(), Some("TRLR 000002"));
+        assert!(iter.next().is_none());
+
+        // Without a footer every line is read
+        let mut iter = FixedReader::<Num>::new()
+            .stop_at_prefix("TRLR")
+            .read("  1\n  2\n".as_bytes());
+        assert_eq!(iter.by_ref().count(), 2);
+        assert!(iter.footer().is_none());
+    }
+
     #[test]
     fn iter_debug() {
         let buf = "foo\nbar\nbaz";
@@ -571,6 +2071,22 @@ mod tests {
         assert_eq!(to_str(buf), expected);
     }
 
+    #[test]
+    #[cfg(feature = "experimental-write")]
+    fn fixed_writer_incremental() {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut writer = FixedWriter::new(&mut buf);
+
+        let res = writer.write_record(&MyEnum::Unit);
+        assert!(res.is_ok());
+
+        let res = writer.write_record(&MyEnum::Tuple("my string".to_string(), 981));
+        assert!(res.is_ok());
+
+        assert!(writer.finish().is_ok());
+        assert_eq!(to_str(buf), "un\ntumy string        981\n");
+    }
+
     #[test]
     fn read_unit_enum_derived() {
         let expected = MyEnum::Unit;
@@ -1,3 +1,6 @@
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
 /// Represents the alignment of a field in a fixed length representation
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Alignment {
@@ -10,11 +13,291 @@ pub enum Alignment {
     /// When reading strings, whitespace will not be stripped. This can be
     /// useful to preserve tabular data. Numerical fields will ignore leading
     /// and trailing whitespace when parsing a value from text.
+    ///
+    /// When writing numbers, the value is zero padded to the full width with
+    /// any sign written first (e.g., `-0042`). In strict mode a value that does
+    /// not fit the width is an error.
     Full, // TODO: handle incorrect length writes (with strict mode)
 }
 
+impl Display for Alignment {
+    /// Formats the alignment as it is written in a `fixcol` attribute
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Alignment::Left => write!(f, "left"),
+            Alignment::Right => write!(f, "right"),
+            Alignment::Full => write!(f, "full"),
+        }
+    }
+}
+
+/// Represents which side of a value padding is placed on
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PadSide {
+    /// Padding precedes the value
+    Left,
+    /// Padding follows the value
+    Right,
+}
+
+impl Display for PadSide {
+    /// Formats the side as it is written in a `fixcol` attribute
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PadSide::Left => write!(f, "left"),
+            PadSide::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// Represents how negative numbers are written in a numeric field
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NegativeStyle {
+    /// Negative numbers are written with a leading minus sign (e.g., `-123`)
+    Sign,
+    /// Negative numbers are wrapped in parentheses (e.g., `(123)`)
+    ///
+    /// This is a common convention in accounting data.
+    Parens,
+}
+
+impl Display for NegativeStyle {
+    /// Formats the style as it is written in a `fixcol` attribute
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NegativeStyle::Sign => write!(f, "sign"),
+            NegativeStyle::Parens => write!(f, "parens"),
+        }
+    }
+}
+
+/// Represents how an IPv4 address is stored in a field
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Ipv4Style {
+    /// The address is written in dotted-quad form (e.g., `192.168.1.1`)
+    Dotted,
+    /// The address is written as four zero-padded, 3 digit octets with no
+    /// separator (e.g., `192168001001`)
+    Octets,
+}
+
+impl Display for Ipv4Style {
+    /// Formats the style as it is written in a `fixcol` attribute
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ipv4Style::Dotted => write!(f, "dotted"),
+            Ipv4Style::Octets => write!(f, "octets"),
+        }
+    }
+}
+
+/// Represents what happens when a value written to a field does not fit
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Overflow {
+    /// The value is truncated to the width of the field, keeping its start
+    ///
+    /// Numbers that cannot be shortened without changing their value fill
+    /// the field with `*` instead.
+    Truncate,
+    /// Writing the value is an error
+    Error,
+    /// Like `Truncate`, but the value keeps its end rather than its start
+    ///
+    /// For a number, whether stored as text or as a number type, this keeps
+    /// the least significant digits.
+    KeepEnd,
+}
+
+impl Display for Overflow {
+    /// Formats the policy as it is written in a `fixcol` attribute
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Overflow::Truncate => write!(f, "truncate"),
+            Overflow::Error => write!(f, "error"),
+            Overflow::KeepEnd => write!(f, "keep_end"),
+        }
+    }
+}
+
+/// A fraction stored in a column as a number of percent
+///
+/// The column's number, after applying the field's `scale`, is divided by 100
+/// when read, so `07550` in a field with `scale = 2` is read as
+/// `Percent(0.755)`. Writing multiplies by 100 before formatting the number.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{Percent, ReadFixed};
+///
+/// #[derive(ReadFixed)]
+/// struct Rate {
+///     #[fixcol(width = 5, scale = 2)]
+///     rate: Percent,
+/// }
+///
+/// let rate = Rate::read_fixed_str("07550").unwrap();
+/// assert_eq!(rate.rate, Percent(0.755));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Percent(pub f64);
+
+/// Text already formatted for its column, written exactly as it is
+///
+/// Writing a `Preformatted` field writes its text after the field's skipped
+/// columns without padding, aligning or truncating it. This avoids formatting
+/// content twice when it was rendered for its column elsewhere. In strict
+/// mode text that is not exactly the width of the field is an error. Reading
+/// a `Preformatted` field keeps all of the field's text, untrimmed.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "experimental-write")]
+/// # {
+/// use fixcol::{Preformatted, WriteFixed};
+///
+/// #[derive(WriteFixed)]
+/// #[fixcol(strict = true)]
+/// struct Entry {
+///     #[fixcol(width = 6)]
+///     code: Preformatted,
+///     #[fixcol(width = 3, align = "right")]
+///     count: u8,
+/// }
+///
+/// let entry = Entry { code: Preformatted(String::from("00-12 ")), count: 7 };
+/// let mut buf: Vec<u8> = Vec::new();
+/// entry.write_fixed(&mut buf).unwrap();
+/// assert_eq!(std::str::from_utf8(&buf).unwrap(), "00-12   7");
+///
+/// let entry = Entry { code: Preformatted(String::from("00-12")), count: 7 };
+/// assert!(entry.write_fixed(&mut Vec::new()).is_err());
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Preformatted(pub String);
+
+/// A parsed field value along with the source text it was parsed from
+///
+/// Reading a `Parsed<T>` field parses the value exactly as a `T` field would
+/// and also keeps the field's text, trimmed according to its alignment, in
+/// `raw`. This preserves the provenance of a value, for example for audit
+/// logs, without declaring the field a second time as a `String`. Writing a
+/// `Parsed<T>` writes its `value`.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{Parsed, ReadFixed};
+///
+/// #[derive(ReadFixed)]
+/// struct Payment {
+///     #[fixcol(width = 8, align = "right")]
+///     amount: Parsed<u32>,
+/// }
+///
+/// let payment = Payment::read_fixed_str("  000125").unwrap();
+/// assert_eq!(payment.amount.value, 125);
+/// assert_eq!(payment.amount.raw, "000125");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Parsed<T> {
+    /// The parsed value of the field
+    pub value: T,
+    /// The trimmed text of the field the value was parsed from
+    pub raw: String,
+}
+
+/// A field holding either of two representations
+///
+/// Reading an `Either<A, B>` field first parses the field as an `A` and, if
+/// that fails, as a `B`. It is an error if neither succeeds. This suits
+/// columns with two documented forms, such as a number or a code, or a date
+/// or a placeholder. Writing an `Either` writes whichever value it holds.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{Either, ReadFixed};
+///
+/// #[derive(ReadFixed)]
+/// struct Score {
+///     #[fixcol(width = 5, align = "right")]
+///     points: Either<u16, String>,
+/// }
+///
+/// let score = Score::read_fixed_str("  120").unwrap();
+/// assert_eq!(score.points, Either::Left(120));
+///
+/// let score = Score::read_fixed_str("  DNF").unwrap();
+/// assert_eq!(score.points, Either::Right(String::from("DNF")));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<A, B> {
+    /// The field held the first representation
+    Left(A),
+    /// The field held the second representation
+    Right(B),
+}
+
+/// A set of on/off flags stored one character per column
+///
+/// Each column of the field is one flag, with the leftmost column as bit 0
+/// of the wrapped `u32`, so a field holds at most 32 flags. A column is set
+/// when it holds the field's on character and clear when it holds its off
+/// character. These are `Y` and `N` unless the field sets the `flags`
+/// parameter (e.g., `flags = "X "`). In strict mode any other character is
+/// an error. Otherwise, it reads as clear.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::{Flags, ReadFixed};
+///
+/// const READ: u32 = 1 << 0;
+/// const WRITE: u32 = 1 << 1;
+/// const ADMIN: u32 = 1 << 2;
+///
+/// #[derive(ReadFixed)]
+/// struct User {
+///     #[fixcol(width = 6)]
+///     name: String,
+///     #[fixcol(width = 3)]
+///     permissions: Flags,
+/// }
+///
+/// let user = User::read_fixed_str("alice YYN").unwrap();
+/// assert_eq!(user.permissions, Flags(READ | WRITE));
+/// assert_eq!(user.permissions.0 & ADMIN, 0);
+/// assert!(user.permissions.is_set(1));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Flags(pub u32);
+
+impl Flags {
+    /// Whether the flag in the given column of the field is set
+    ///
+    /// Columns are counted from zero at the left of the field.
+    pub fn is_set(&self, position: u32) -> bool {
+        position < u32::BITS && self.0 & (1 << position) != 0
+    }
+}
+
 /// Represents how a field should be encoded in a fixed width column representation
+///
+/// New options are added to this struct over time, so it cannot be built with
+/// a struct literal outside of this crate. Use [`FieldDescription::new`] or
+/// [`FieldDescription::default`] and assign any other options to the result.
+///
+/// ```
+/// use fixcol::{Alignment, FieldDescription};
+///
+/// let mut desc = FieldDescription::new(1, 8, Alignment::Right, true);
+/// desc.pad = '0';
+/// ```
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub struct FieldDescription {
     /// How many characters to skip between the prior field and this one
     ///
@@ -26,4 +309,315 @@ pub struct FieldDescription {
     pub alignment: Alignment,
     /// Whether strict mode is enabled for this field.
     pub strict: bool,
+    /// Whether trailing whitespace is preserved when reading a `Left` aligned
+    /// string field.
+    pub keep_trailing: bool,
+    /// Literal text expected in the skipped columns preceding this field
+    ///
+    /// When set, strict reads require the skipped columns to match this text
+    /// exactly instead of being whitespace, and writes fill the skipped
+    /// columns with it.
+    pub skip_literal: Option<&'static str>,
+    /// The character used to fill the unused columns of a `String` field
+    ///
+    /// Reads trim only this character, so a non-space fill allows values with
+    /// significant leading or trailing spaces to be read intact.
+    pub pad: char,
+    /// How negative numbers are represented in a numeric field
+    pub negatives: NegativeStyle,
+    /// Text parsed in place of a blank numeric field
+    ///
+    /// When unset, a blank numeric field is a parse error.
+    pub blank_as: Option<&'static str>,
+    /// Which side of the value padding is placed on
+    ///
+    /// When unset, padding goes opposite the alignment, so `Right` aligned
+    /// values are padded on the left and others on the right.
+    pub pad_side: Option<PadSide>,
+    /// Characters representing `true` and `false` in a `bool` field
+    ///
+    /// The first character is the `true` token and the second is the `false`
+    /// token (e.g., `"YN"`). When unset, `bool` fields are read and written as
+    /// the text `true` and `false`.
+    pub bool_tokens: Option<&'static str>,
+    /// What happens when a value written to this field does not fit
+    ///
+    /// When unset, overflowing values are an error in strict mode and are
+    /// truncated otherwise.
+    pub on_overflow: Option<Overflow>,
+    /// The minimum number of digits written for an integer
+    ///
+    /// Integers with fewer digits are zero padded before any alignment
+    /// padding is applied, so `7` with a minimum of 4 digits in a right
+    /// aligned field of width 8 is written as `    0007`.
+    pub min_digits: Option<usize>,
+    /// The character separating the items of a `Vec` field
+    ///
+    /// When set, a `Vec` field holds a list of items joined by this character
+    /// (e.g., `A,B,C`) rather than a sequence of items each `len` characters
+    /// wide.
+    pub sep: Option<char>,
+    /// The number of implied decimal places in a float field
+    ///
+    /// When set, a float is written as a whole number of hundredths,
+    /// thousandths, etc. (e.g., `75.5` with a scale of 2 is written as `7550`)
+    /// and text without a decimal point is divided by `10^scale` when read.
+    pub scale: Option<u32>,
+    /// Fixed text written at the left edge of a numeric field
+    ///
+    /// When set, a number is aligned within the columns that remain after
+    /// the prefix (e.g., `$  1234` with a prefix of `$` and a width of 7) and
+    /// the prefix is removed before the number is parsed.
+    pub prefix: Option<&'static str>,
+    /// Whether a float field may hold `inf` or `NaN` in strict mode
+    ///
+    /// Text such as `inf` or `NaN` is usually a sign of a corrupt column
+    /// rather than an intended value, so strict fields reject it by default.
+    /// Lax fields always accept it.
+    pub allow_nonfinite: bool,
+    /// Characters marking a set and a clear column of a [`Flags`] field
+    ///
+    /// The first character is the on token and the second is the off token
+    /// (e.g., `"X "`). When unset, `Flags` fields use `Y` and `N`.
+    pub flags: Option<&'static str>,
+    /// How an IPv4 address is represented in the field
+    pub ipv4: Ipv4Style,
+    /// The name of the field this description belongs to
+    ///
+    /// Derived implementations set this to the Rust field name, which allows
+    /// custom `FixedDeserializer` and `FixedSerializer` implementations to
+    /// reference the field in their errors. Tuple struct fields are unnamed.
+    pub name: Option<&'static str>,
+    /// A human readable description of the field
+    ///
+    /// Derived implementations set this from the field's doc comment, with
+    /// its lines joined by spaces, so that documentation or headers generated
+    /// from the schema can describe each field.
+    pub description: Option<&'static str>,
+}
+
+impl FieldDescription {
+    /// Creates the description of a field from its position, width, alignment,
+    /// and strictness
+    ///
+    /// Every other option takes its default value.
+    pub fn new(skip: usize, len: usize, alignment: Alignment, strict: bool) -> Self {
+        Self {
+            skip,
+            len,
+            alignment,
+            strict,
+            ..Self::default()
+        }
+    }
+
+    /// Whether padding for this field precedes the value
+    pub(crate) fn pad_on_left(&self) -> bool {
+        match self.pad_side {
+            Some(side) => side == PadSide::Left,
+            None => self.alignment == Alignment::Right,
+        }
+    }
+
+    /// Whether writing a value that does not fit this field is an error
+    ///
+    /// `default` is used when the field does not set an overflow policy.
+    #[cfg(feature = "experimental-write")]
+    pub(crate) fn overflow_is_error(&self, default: bool) -> bool {
+        match self.on_overflow {
+            Some(policy) => policy == Overflow::Error,
+            None => default,
+        }
+    }
+
+    /// The text representing `true` and `false` in a `bool` field
+    pub(crate) fn bool_texts(&self) -> (&'static str, &'static str) {
+        let tokens = match self.bool_tokens {
+            Some(tokens) => tokens,
+            None => return ("true", "false"),
+        };
+
+        let split = tokens
+            .char_indices()
+            .nth(1)
+            .map_or(tokens.len(), |(i, _)| i);
+        tokens.split_at(split)
+    }
+
+    /// The characters marking a set and a clear column of a [`Flags`] field
+    pub(crate) fn flag_chars(&self) -> (char, char) {
+        let mut chars = self.flags.unwrap_or("YN").chars();
+        match (chars.next(), chars.next()) {
+            (Some(on), Some(off)) => (on, off),
+            _ => ('Y', 'N'),
+        }
+    }
+}
+
+impl Default for FieldDescription {
+    /// A zero width, left aligned, strict, space padded field
+    ///
+    /// With the `lax-by-default` feature the field is lax instead, matching
+    /// the default of the derives. The feature applies to the whole build,
+    /// so code that relies on strictness should set `strict` explicitly.
+    fn default() -> Self {
+        Self {
+            skip: 0,
+            len: 0,
+            alignment: Alignment::Left,
+            strict: !cfg!(feature = "lax-by-default"),
+            keep_trailing: false,
+            skip_literal: None,
+            pad: ' ',
+            negatives: NegativeStyle::Sign,
+            blank_as: None,
+            pad_side: None,
+            bool_tokens: None,
+            on_overflow: None,
+            min_digits: None,
+            sep: None,
+            scale: None,
+            prefix: None,
+            allow_nonfinite: false,
+            flags: None,
+            ipv4: Ipv4Style::Dotted,
+            name: None,
+            description: None,
+        }
+    }
+}
+
+impl Display for FieldDescription {
+    /// Formats a short human readable summary of the field
+    ///
+    /// The summary lists the field's settings (e.g., `skip 1, len 8, right,
+    /// strict`). The skip is counted from the end of the prior field rather
+    /// than the start of the line, so the summary does not give the field's
+    /// absolute columns. Options are only listed when they differ from their
+    /// defaults. Named fields are prefixed with their name (e.g.,
+    /// `amount: skip 1, ...`).
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name {
+            write!(f, "{}: ", name)?;
+        }
+
+        let strict = if self.strict { "strict" } else { "lax" };
+        write!(
+            f,
+            "skip {}, len {}, {}, {}",
+            self.skip, self.len, self.alignment, strict
+        )?;
+
+        if self.keep_trailing {
+            write!(f, ", keep trailing")?;
+        }
+
+        if let Some(literal) = self.skip_literal {
+            write!(f, ", skip literal {:?}", literal)?;
+        }
+
+        if self.pad != ' ' {
+            write!(f, ", pad {:?}", self.pad)?;
+        }
+
+        if self.negatives != NegativeStyle::Sign {
+            write!(f, ", negatives {}", self.negatives)?;
+        }
+
+        if let Some(sentinel) = self.blank_as {
+            write!(f, ", blank as {:?}", sentinel)?;
+        }
+
+        if let Some(side) = self.pad_side {
+            write!(f, ", pad side {}", side)?;
+        }
+
+        if let Some(tokens) = self.bool_tokens {
+            write!(f, ", bool tokens {:?}", tokens)?;
+        }
+
+        if let Some(policy) = self.on_overflow {
+            write!(f, ", on overflow {}", policy)?;
+        }
+
+        if let Some(digits) = self.min_digits {
+            write!(f, ", min digits {}", digits)?;
+        }
+
+        if let Some(sep) = self.sep {
+            write!(f, ", sep {:?}", sep)?;
+        }
+
+        if let Some(scale) = self.scale {
+            write!(f, ", scale {}", scale)?;
+        }
+
+        if let Some(prefix) = self.prefix {
+            write!(f, ", prefix {:?}", prefix)?;
+        }
+
+        if self.allow_nonfinite {
+            write!(f, ", allow nonfinite")?;
+        }
+
+        if let Some(tokens) = self.flags {
+            write!(f, ", flags {:?}", tokens)?;
+        }
+
+        if self.ipv4 != Ipv4Style::Dotted {
+            write!(f, ", ipv4 {}", self.ipv4)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_field_description() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 8,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        assert_eq!(desc.to_string(), "skip 1, len 8, right, strict");
+
+        let desc = FieldDescription {
+            strict: false,
+            keep_trailing: true,
+            skip_literal: Some("|"),
+            pad: '_',
+            negatives: NegativeStyle::Parens,
+            blank_as: Some("0"),
+            pad_side: Some(PadSide::Right),
+            bool_tokens: Some("YN"),
+            on_overflow: Some(Overflow::Truncate),
+            min_digits: Some(4),
+            sep: Some(','),
+            scale: Some(2),
+            prefix: Some("$"),
+            allow_nonfinite: true,
+            flags: Some("X "),
+            ipv4: Ipv4Style::Octets,
+            ..desc
+        };
+        assert_eq!(
+            desc.to_string(),
+            "skip 1, len 8, right, lax, keep trailing, skip literal \"|\", pad '_', \
+            negatives parens, blank as \"0\", pad side right, bool tokens \"YN\", \
+            on overflow truncate, min digits 4, sep ',', scale 2, prefix \"$\", allow nonfinite, \
+            flags \"X \", ipv4 octets"
+        );
+
+        let desc = FieldDescription {
+            name: Some("amount"),
+            ..FieldDescription::new(3, 8, Alignment::Right, true)
+        };
+        assert_eq!(desc.to_string(), "amount: skip 3, len 8, right, strict");
+    }
 }
@@ -1,4 +1,5 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! A crate used for *fixed* width *column* serialization and deserialization
 //!
@@ -11,9 +12,20 @@
 //! The library is built around the [`ReadFixed`] trait which is ordinarily
 //! derived on a data type (`struct` or `enum`) that represents a row of the data
 //! file. The `fixcol` attribute is used to define how fields map to the schema.
+//! For text already in memory, deriving [`ReadFixedRef`] instead reads records
+//! whose `&str` fields borrow from the buffer rather than copying it.
 //!
 //! For writing data files rudimentary serialization is provided by [`WriteFixed`]
 //! and [`WriteFixedAll`] behind the `experimental-write` feature flag.
+//! Column-major data, such as one `Vec` per column, can be written as rows with
+//! [`write_columns`].
+//! The `test-util` feature adds helpers for checking that a layout round trips
+//! through a write and read unchanged.
+//!
+//! The `std` feature is enabled by default and provides [`ReadFixed`] along
+//! with the reader and writer machinery built on `std::io`. Without it the
+//! crate is `no_std`, requiring only `alloc`, and fields can still be parsed
+//! from a `&str` with [`FixedDeserializer`] or a [`FixcolEnum`] code table.
 //!
 //! ## Examples
 //! ### Basic Example
@@ -157,6 +169,30 @@
 //! # ]);
 //! ```
 //!  
+//! Deriving `ReadFixed` on an `enum` also generates a `total_width` associated
+//! function returning the width of the widest variant, including the key. This
+//! is useful for sizing buffers when reading heterogeneous records. Embedded
+//! variants only count the width of their key.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! #[derive(ReadFixed)]
+//! #[fixcol(key_width = 4)]
+//! enum GraphItem {
+//!     #[fixcol(key = "NODE")]
+//!     Node(#[fixcol(skip = 1, width = 3)] u8),
+//!     #[fixcol(key = "EDGE")]
+//!     Edge(#[fixcol(skip = 1, width = 3)] u8, #[fixcol(skip = 1, width = 3)] u8),
+//! }
+//!
+//! assert_eq!(GraphItem::total_width(), 12);
+//! ```
+//!
+//! A `variant_labels` associated function and a `variant_label` method are
+//! generated as well. They report a human readable label for each variant,
+//! set with the [`label`](#label) parameter, separately from the key used in
+//! the data.
+//!
 //! ### Embedded Variants
 //!
 //! Often instead of having fields defined directly on an `enum` variant it is
@@ -206,6 +242,157 @@
 //! # ]);
 //! ```
 //!
+//! ### Nested Keys
+//!
+//! Some files identify a record with a primary type followed by a subtype in
+//! the next few columns. Because an embedded type reads from the columns that
+//! follow the outer key, the embedded type may itself be a keyed `enum`. The
+//! inner `enum` dispatches on its own `key_width` columns immediately after
+//! the outer key.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! # #[derive(PartialEq, Debug)]
+//! #[derive(ReadFixed)]
+//! #[fixcol(key_width = 2)]
+//! enum Message {
+//!     #[fixcol(key = "TX")]
+//!     Transmit(#[fixcol(skip = 1, width = 4)] String),
+//!     #[fixcol(key = "RX")]
+//!     Receive(#[fixcol(skip = 1, width = 4)] String),
+//! }
+//!
+//! # #[derive(PartialEq, Debug)]
+//! #[derive(ReadFixed)]
+//! #[fixcol(key_width = 3)]
+//! enum LogRow {
+//!     #[fixcol(key = "MSG", embed = true)]
+//!     Message(Message),
+//!     #[fixcol(key = "CLK")]
+//!     Clock(#[fixcol(skip = 1, width = 6, align = "right")] u32),
+//! }
+//! # let row = LogRow::read_fixed_str("MSGRX ALFA").unwrap();
+//! # assert_eq!(row, LogRow::Message(Message::Receive("ALFA".to_string())));
+//! ```
+//!
+//! ### Newtype Wrappers
+//!
+//! A tuple struct with a single field and no `fixcol` attribute on that field
+//! delegates directly to the inner type, much like an embedded variant. This
+//! makes it easy to add domain specific methods around a parsed type.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! # #[derive(PartialEq, Debug)]
+//! #[derive(ReadFixed)]
+//! struct Point {
+//!     #[fixcol(width = 3, align = "right")]
+//!     x: i16,
+//!     #[fixcol(width = 3, align = "right")]
+//!     y: i16,
+//! }
+//!
+//! #[derive(ReadFixed)]
+//! struct Origin(Point);
+//!
+//! impl Origin {
+//!     fn is_zero(&self) -> bool {
+//!         self.0.x == 0 && self.0.y == 0
+//!     }
+//! }
+//! # let origin = Origin::read_fixed_str("  0  0").unwrap();
+//! # assert!(origin.is_zero());
+//! ```
+//!
+//! ### Ignored Columns
+//!
+//! Columns that carry no useful data may be modeled with a unit `()` field.
+//! The column is consumed when reading and written as blank spaces, without
+//! materializing a value.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! # #[derive(PartialEq, Debug)]
+//! #[derive(ReadFixed)]
+//! struct Account {
+//!     #[fixcol(width = 4)]
+//!     id: String,
+//!     #[fixcol(width = 6)]
+//!     _filler: (),
+//!     #[fixcol(width = 8, align = "right")]
+//!     balance: u32,
+//! }
+//! # let acct = Account::read_fixed_str("A001------     250").unwrap();
+//! # assert_eq!(acct, Account { id: "A001".to_string(), _filler: (), balance: 250 });
+//! ```
+//!
+//! ### Code Tables
+//!
+//! A column holding one of a fixed set of codes can be modeled with an enum of
+//! unit variants deriving [`FixcolEnum`]. Each variant declares the token that
+//! represents it with the [`value`](#value) parameter. Deriving `FixcolEnum`
+//! implements [`FixedDeserializer`], and [`FixedSerializer`] when writing is
+//! enabled, so the enum can be used as a field of other records. Unrecognized
+//! tokens are an error listing the valid values.
+//!
+//! ```
+//! # use fixcol::{FixcolEnum, ReadFixed};
+//! #[derive(FixcolEnum)]
+//! # #[derive(PartialEq, Debug)]
+//! enum EyeColor {
+//!     #[fixcol(value = "Bl")]
+//!     Blue,
+//!     #[fixcol(value = "Br")]
+//!     Brown,
+//!     #[fixcol(value = "Gr")]
+//!     Green,
+//! }
+//!
+//! #[derive(ReadFixed)]
+//! struct Person {
+//!     #[fixcol(width = 10)]
+//!     name: String,
+//!     #[fixcol(width = 2)]
+//!     eye_color: EyeColor,
+//! }
+//! # let person = Person::read_fixed_str("Harold    Gr").unwrap();
+//! # assert_eq!(person.eye_color, EyeColor::Green);
+//! ```
+//!
+//! An enum that already implements [`Display`](std::fmt::Display) can instead
+//! set [`match_display`](#match-display) and omit the `value` tokens. Each
+//! variant is then read by comparing the trimmed column against its `Display`
+//! text and written as that text.
+//!
+//! ```
+//! # use fixcol::{FixcolEnum, ReadFixed};
+//! # use std::fmt;
+//! #[derive(FixcolEnum)]
+//! #[fixcol(match_display)]
+//! # #[derive(PartialEq, Debug)]
+//! enum Status {
+//!     Active,
+//!     Retired,
+//! }
+//!
+//! impl fmt::Display for Status {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         match self {
+//!             Status::Active => write!(f, "ACTIVE"),
+//!             Status::Retired => write!(f, "RETIRED"),
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(ReadFixed)]
+//! struct Account {
+//!     #[fixcol(width = 8)]
+//!     status: Status,
+//! }
+//! # let account = Account::read_fixed_str("RETIRED ").unwrap();
+//! # assert_eq!(account.status, Status::Retired);
+//! ```
+//!
 //! ## Strict Mode
 //!
 //! Strict mode may be toggled on or off setting the appropriate `fixcol` attribute
@@ -214,7 +401,16 @@
 //! conditions will be reported as errors.
 //!
 //! Strict mode is currently enabled by default, but **this may change** in a
-//! future version.
+//! future version. Enabling the `lax-by-default` feature turns it off for every
+//! type that does not set `strict` explicitly, and for
+//! [`FieldDescription::default`].
+//!
+//! Cargo features are unified across the dependency graph, so this applies to
+//! the whole build: if any crate enables `lax-by-default`, every type derived
+//! by every crate that depends on Fixcol loses strict validation unless it sets
+//! `strict` itself. Only a final application should enable the feature.
+//! **Libraries must never enable it**; a library that wants lax parsing should
+//! set `#[fixcol(strict = false)]` on its types instead.
 //!
 //! The `strict` parameter can be applied to a `struct` or `enum`, `enum` variant,
 //! or field. The setting will cascade to other levels with the innermost explicit
@@ -241,11 +437,22 @@
 //! When a given field is parsed in strict mode the following conditions become
 //! errors.
 //! - The last field on a line is not whitespace padded to the defined length.
+//!   Trailing `Option` fields that are missing entirely from a short line are
+//!   read as `None` in either mode. When strict mode is off, a last field the
+//!   line ends before entirely is read as though it were blank with a
+//!   `blank_as` of `"0"`, so numbers read as zero and `Option` fields as `None`.
 //! - Columns between defined data columns contain non-whitespace characters.
 //! - Numeric column defined with `Full` alignment are not zero-padded to the
-//!   full length.
+//!   full length. A sign, if any, comes first, as in `-0042` or `+0007`.
 //! - A `Left` aligned field beginning with whitespace.
 //! - A `Right` aligned field ending with whitespace.
+//! - Skipped columns that do not match a declared `skip_literal`.
+//! - A line longer than a declared `max_line_length`.
+//! - A field containing non-ASCII characters when `ascii_only` is set.
+//! - A text field containing control characters, such as tabs or newlines,
+//!   other than its pad character.
+//! - A required field holding only its pad character when `reject_blank` is
+//!   set.
 //!
 //! Additional rules are applied while attempting to write a record. The following
 //! are errors in strict mode.
@@ -260,6 +467,24 @@
 //! annotations that contain a list of one or more parameters in the form
 //! `#[fixcol(param1 = value1, param2 = value2, ...)]`.
 //!
+//! Boolean parameters may also be written as a bare flag, so
+//! `#[fixcol(strict)]` is equivalent to `#[fixcol(strict = true)]`.
+//!
+//! #### Allow Nonfinite
+//!
+//! Allows a strict float field to hold infinite and `NaN` values. Text such
+//! as `inf` or `NaN` parses as a float, but in a data column it usually means
+//! the column is corrupt, so strict fields reject it unless this is set. Lax
+//! fields always accept it.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(width = 8, allow_nonfinite = true)]`
+//!
 //! #### Align
 //!
 //! Indicates the text alignment of the specified field.
@@ -281,6 +506,154 @@
 //!
 //! **Example**: `#[fixcol(width = 6, align = "right")]`
 //!
+//! #### Ascii Only
+//!
+//! Rejects fields containing any non-ASCII character. The check is only
+//! applied to fields parsed in strict mode and reports the offending field.
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(ascii_only = true)]`
+//!
+//! #### At
+//!
+//! Positions the field at an absolute, zero based column rather than relative
+//! to the end of the prior field. The `skip` needed to reach the column is
+//! computed from the widths of the preceding fields, which can make sparse
+//! layouts easier to read. Columns of an enum variant's fields are counted
+//! from the start of the line, including the key. It is a compile error if
+//! the column falls before the end of the prior field, unless the field sets
+//! [`overlap`](#overlap).
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Non-negative integers
+//!
+//! **Default**: None, the field follows the prior field after `skip` columns
+//!
+//! **Example**: `#[fixcol(at = 40, width = 8)]`
+//!
+//! #### Blank As
+//!
+//! Value to use for a numeric field whose columns are entirely blank. Without
+//! it a blank numeric field is a parse error. Non-blank text that is not a
+//! valid number is still an error. Negative values must be quoted.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Any value that parses as the field's type
+//!
+//! **Default**: None
+//!
+//! **Example**: `#[fixcol(width = 5, blank_as = 0)]`
+//!
+//! #### Bool Tokens
+//!
+//! The characters representing `true` and `false` in a `bool` field, given as
+//! the `true` character followed by the `false` character. Without it a `bool`
+//! is read and written as the text `true` or `false`. Combined with an
+//! `Option<bool>` field, a blank column is read as `None`.
+//!
+//! When reading, text that matches neither token but is made up entirely of
+//! digits is read as a number, with zero as `false` and anything else as
+//! `true`. The tokens are matched first, so with `bool_tokens = "YN"` both `N`
+//! and `00` are read as `false`.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Two distinct characters
+//!
+//! **Default**: None, `true` and `false` are written out in full
+//!
+//! **Example**: `#[fixcol(width = 1, bool_tokens = "YN")]`
+//!
+//! #### Catch All
+//!
+//! Marks the variant that receives any line whose key is not recognized. The
+//! variant must hold exactly one unnamed field, usually a [`RawLine`], which is
+//! read from the full text of the line, key included. When writing, the inner
+//! value is written verbatim without a key. This allows a read-modify-write
+//! cycle to keep the lines it does not model. A catch-all variant has no key
+//! and at most one is allowed per enum.
+//!
+//! **Can be applied to**: Enum Variant
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(catch_all)]`
+//!
+//! #### Count
+//!
+//! Indicates that a field holds several adjacent items, each `width` columns
+//! wide. The field occupies `width * count` columns. This is used to read a
+//! `Range` from its start and end columns, which requires `count = 2`.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Positive integers
+//!
+//! **Default**: `1`
+//!
+//! **Example**: `#[fixcol(width = 5, count = 2)]`
+//!
+//! #### Delimiter
+//!
+//! Reads records whose fields are separated by a single character rather than
+//! found at fixed columns, as in some lightly delimited legacy exports. The
+//! line is split on the delimiter and each piece is read as the field in the
+//! same position, in the same way as by `from_fields`. Fields keep their type
+//! and alignment but their width and position are ignored. A line with the
+//! wrong number of fields is an error. Only reading is affected.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! #[derive(ReadFixed)]
+//! #[fixcol(delimiter = '|')]
+//! struct Album {
+//!     #[fixcol(width = 12)]
+//!     artist: String,
+//!     #[fixcol(width = 4)]
+//!     year: u16,
+//! }
+//!
+//! let album = Album::read_fixed_str("George|1989").unwrap();
+//! assert_eq!(album.artist, "George");
+//! assert_eq!(album.year, 1989);
+//! ```
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: Any single character
+//!
+//! **Example**: `#[fixcol(delimiter = '|')]`
+//!
+//! #### Discriminant
+//!
+//! Selects the enum variant from a column inside the record rather than from a
+//! key prefix. The value is the zero based column at which the `key_width`
+//! wide key is found. The variant's fields are then read from the start of the
+//! record, so they must account for the discriminant column, for example with
+//! a `skip_literal`. When writing, the variant's key is placed in the
+//! discriminant column after its fields are written.
+//!
+//! Because the whole record must be available, the remainder of the buffer is
+//! read. Use with [`read_fixed_all`](ReadFixed::read_fixed_all) or
+//! [`read_fixed_str`](ReadFixed::read_fixed_str).
+//!
+//! **Can be applied to**: Enum
+//!
+//! **Allowed Values**: Non-negative integers
+//!
+//! **Default**: None, the key is a prefix of the record
+//!
+//! **Example**: `#[fixcol(key_width = 1, discriminant = 8)]`
+//!
 //! #### Embed
 //!
 //! When decoding a single valued tuple-style enum variant, use the [`ReadFixed`]
@@ -294,22 +667,159 @@
 //!
 //! **Example**: `#[fixcol(embed = true)]`
 //!
+//! #### Encoding
+//!
+//! Reads the record's text as a single byte encoding rather than UTF-8, which
+//! is common for files exported from older systems. Every byte is one column
+//! and one character, so decoding never fails. Only reading is affected,
+//! records are always written as UTF-8. Requires the `encoding` feature and
+//! cannot be combined with `utf8`. See [`Encoding`].
+//!
+//! **Can be applied to**: Struct, Enum
+//!
+//! **Allowed Values**: `"latin1"` (or `"iso-8859-1"`), `"windows-1252"` (or
+//! `"cp1252"`)
+//!
+//! **Default**: None, records are UTF-8
+//!
+//! **Example**: `#[fixcol(encoding = "windows-1252")]`
+//!
+//! #### Flags
+//!
+//! The characters marking a set and a clear column of a [`Flags`] field, in
+//! which each column is one on/off flag. The first character is the on token
+//! and the second is the off token. In strict mode any other character is an
+//! error. Otherwise, it reads as clear.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: A string of two distinct characters
+//!
+//! **Default**: `"YN"`
+//!
+//! **Example**: `#[fixcol(width = 8, flags = "X ")]`
+//!
+//! #### From End
+//!
+//! Indicates the field is positioned relative to the end of the line rather
+//! than following the prior field. This supports layouts where the last few
+//! fields are anchored to the end of a line whose preamble varies in length.
+//! Fields read from the end must follow all other fields. They are laid out
+//! in declaration order with each field's `skip` columns falling after it, so
+//! the last field's `skip` is the number of columns between it and the end of
+//! the line.
+//!
+//! Because the length of the line must be known, the remainder of the buffer
+//! is read. Use with [`read_fixed_all`](ReadFixed::read_fixed_all) or
+//! [`read_fixed_str`](ReadFixed::read_fixed_str), which supply a single
+//! record at a time.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(from_end = true, width = 4)]`
+//!
+//! #### From Fields
+//!
+//! Generates a `from_fields` associated function that builds the struct from
+//! text already split into one element per field, in declaration order. This
+//! is useful in tests and for data split into columns by another tool. Each
+//! element is parsed according to its field's type and alignment, ignoring
+//! the field's position, so `skip` columns are not expected and a single
+//! valued field takes the width of its element. A slice with the wrong number
+//! of elements is an error.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! #[derive(ReadFixed)]
+//! #[fixcol(from_fields = true)]
+//! struct Album {
+//!     #[fixcol(width = 12)]
+//!     artist: String,
+//!     #[fixcol(skip = 1, width = 4)]
+//!     year: u16,
+//! }
+//!
+//! let album = Album::from_fields(&["George", "1989"]).unwrap();
+//! assert_eq!(album.artist, "George");
+//! assert_eq!(album.year, 1989);
+//! ```
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(from_fields = true)]`
+//!
+//! #### Ipv4
+//!
+//! Indicates how an `Ipv4Addr` field is represented. With `"dotted"` the
+//! address is written in its usual form, like `192.168.1.1`. With `"octets"`
+//! it is four zero-padded, 3 digit octets with no separator, like
+//! `192168001001`, which needs a field 12 characters wide.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `"dotted"`, `"octets"`
+//!
+//! **Default**: `"dotted"`
+//!
+//! **Example**: `#[fixcol(width = 12, ipv4 = "octets")]`
+//!
+//! #### Keep Trailing
+//!
+//! Preserve trailing whitespace when reading a `Left` aligned `String` field.
+//! Normally trailing whitespace in a left aligned field is considered padding
+//! and is removed. Use this parameter when trailing spaces in the column are
+//! significant. It has no effect on `Right` or `Full` aligned fields, which
+//! already preserve trailing whitespace.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(width = 10, keep_trailing = true)]`
+//!
 //! #### Key
 //!
 //! When decoding multiple record types into an enum, indicates the key that
 //! signifies this particular enum variant should be used to decode the line.
 //!
-//! To encode keys of different lengths, space pad the shorter keys so that all
-//! declared keys are explicitly `key_width` characters.
+//! Keys shorter than `key_width` are space padded to the full width when
+//! reading and writing. See [Key Align](#key-align) to control which side is
+//! padded.
 //!
 //! **Can be applied to**: Enum Variant
 //!
-//! **Allowed Values**: Strings of length `key_width`
+//! **Allowed Values**: Strings of length up to `key_width`
 //!
 //! **Default**: Must be set **explicitly**.
 //!
 //! **Example**: `#[fixcol(key = "EDGE")]`
 //!
+//! #### Key Align
+//!
+//! Indicates how keys shorter than `key_width` are aligned within the key
+//! columns. With `"left"`, the key `SP` under `key_width = 4` is written as
+//! `SP  `. With `"right"` it is written as `  SP`. Keys are matched on read
+//! with the same padding, so a right aligned key must be right justified in
+//! the data.
+//!
+//! **Can be applied to**: Enum
+//!
+//! **Allowed Values**: `"left"`, `"right"`
+//!
+//! **Default**: `"left"`
+//!
+//! **Example**: `#[fixcol(key_width = 4, key_align = "right")]`
+//!
 //! #### Key Width
 //!
 //! When decoding multiple record types into an enum, indicates how many characters
@@ -317,14 +827,297 @@
 //! which record type the line contains and therefore which enum variant should
 //! be used to decode the line.
 //!
-//! **Can be applied to**: Enum
+//! A variant may override the enum's key width when its key is longer or
+//! shorter than the others. Keys are then compared longest first, so a
+//! variant keyed `DTL` takes precedence over one keyed `D`. Overrides are not
+//! supported together with a [Discriminant](#discriminant).
+//!
+//! **Can be applied to**: Enum, Enum Variant
 //!
 //! **Allowed Values**: Positive integers
 //!
-//! **Default**: Must be set **explicitly**.
+//! **Default**: Must be set **explicitly** on the enum. Variants default to
+//! the enum's key width.
 //!
 //! **Example**: `#[fixcol(key_width = 4)]`
 //!
+//! #### Label
+//!
+//! A human readable name for an enum variant, such as for reports or
+//! documentation. The label is returned by the generated `variant_labels` and
+//! `variant_label` functions and has no effect on reading or writing, which
+//! always use the variant's key.
+//!
+//! **Can be applied to**: Enum Variant
+//!
+//! **Allowed Values**: Strings
+//!
+//! **Default**: The name of the variant
+//!
+//! **Example**: `#[fixcol(key = "N", label = "Node")]`
+//!
+//! #### Lines
+//!
+//! Indicates the number of lines each record spans. When reading with
+//! [`read_fixed_all`](ReadFixed::read_fixed_all) or a [`FixedReader`], that
+//! many lines are joined with no separator before the fields are read, so
+//! field positions count from the start of the record's first line through the
+//! end of its last. Every line but the last should be padded to its full
+//! width. A buffer that ends part way through a record is an error reported on
+//! the record's first line. Writing is unaffected and produces a single line.
+//!
+//! ```
+//! # use fixcol::ReadFixed;
+//! #[derive(ReadFixed)]
+//! #[fixcol(lines = 2)]
+//! struct Contact {
+//!     #[fixcol(width = 8)]
+//!     name: String,
+//!     #[fixcol(width = 8)]
+//!     phone: String,
+//! }
+//!
+//! let text = "Harold  \n555-0100\n";
+//! let contacts: Vec<Contact> = Contact::read_fixed_all(text.as_bytes())
+//!     .collect::<Result<_, _>>()
+//!     .unwrap();
+//! # assert_eq!(contacts.len(), 1);
+//! assert_eq!(contacts[0].phone, "555-0100");
+//! ```
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: Positive integers
+//!
+//! **Default**: `1`
+//!
+//! **Example**: `#[fixcol(lines = 3)]`
+//!
+//! #### Match Display
+//!
+//! Identifies each variant of an enum deriving [`FixcolEnum`] by its `Display`
+//! text rather than a [`value`](#value) token. Reads compare the field's text,
+//! after trimming according to the field's alignment, against the `Display`
+//! text of each variant in declaration order. Writes use the `Display` text.
+//! The enum must implement `Display` and its variants may not set `value`.
+//!
+//! **Can be applied to**: Enum deriving `FixcolEnum`
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(match_display)]`
+//!
+//! #### Max Line Length
+//!
+//! Indicates the maximum length of a line. In strict mode, reading a line that
+//! extends beyond this length is an error. This detects records that have
+//! drifted wider than the schema. Any data following the last field is
+//! consumed from the buffer when this parameter is set.
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: Non-negative integers
+//!
+//! **Default**: None (trailing data is ignored)
+//!
+//! **Example**: `#[fixcol(max_line_length = 80)]`
+//!
+//! #### Min Digits
+//!
+//! Indicates the minimum number of digits written for an integer field.
+//! Integers with fewer digits are zero padded, after any sign, before the
+//! field's alignment padding is applied. For example, `7` in a right aligned
+//! field of width 8 with `min_digits = 4` is written as `    0007`. Reading is
+//! unaffected, as leading zeros are always accepted.
+//!
+//! **Can be applied to**: Integer Field
+//!
+//! **Allowed Values**: Positive integers
+//!
+//! **Default**: None (no zero padding)
+//!
+//! **Example**: `#[fixcol(width = 8, min_digits = 4, align = "right")]`
+//!
+//! #### Negatives
+//!
+//! Indicates how negative numbers are represented in a numeric field. With
+//! `"parens"`, negative numbers are wrapped in parentheses as is common in
+//! accounting data, so `(123)` is read as `-123` and `-123` is written as
+//! `(123)`. Unmatched parentheses are an error.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `"sign"`, `"parens"`
+//!
+//! **Default**: `"sign"`
+//!
+//! **Example**: `#[fixcol(width = 8, align = "right", negatives = "parens")]`
+//!
+//! #### On Overflow
+//!
+//! Indicates what happens when a value written to the field is wider than the
+//! field. With `"truncate"` the value is cut to fit, keeping the start of the
+//! value whatever the field's alignment. With `"keep_end"` it is cut from the
+//! start instead, keeping the least significant digits of a right aligned
+//! number, whether it is stored as text or as a number type. With `"error"`
+//! the write fails. This allows, for example, a strict field to be read
+//! strictly but truncated when written.
+//!
+//! Unless the field uses `"keep_end"`, numbers are never cut in a way that
+//! changes their value. An integer that does not fit fills the field with `*`
+//! (e.g., `12345` in a field of width 3 is written as `***`, but as `345` with
+//! `"keep_end"`). A float whose whole part fits loses only fractional digits,
+//! while other floats also fill the field with `*`.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `"truncate"`, `"keep_end"`, `"error"`
+//!
+//! **Default**: `"error"` in strict mode and `"truncate"` otherwise. Floats
+//! that are not `Full` aligned are truncated in either mode.
+//!
+//! **Example**: `#[fixcol(width = 6, on_overflow = "truncate")]`
+//!
+//! #### Overlap
+//!
+//! Allows a field positioned with `at` to re-read columns that belong to the
+//! prior fields, so the same bytes can be read into two fields (e.g., a
+//! checksum restating part of an account number). The overlapping field must
+//! lie entirely within the columns of the prior fields and does not move the
+//! fields that follow it. It is not written, as its columns are written by
+//! the fields it overlaps. Without `overlap` such a field is a compile error.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(at = 4, width = 2, overlap = true)]`
+//!
+//! #### Pad
+//!
+//! Indicates the character used to fill the unused columns of a `String`
+//! field. When reading, only this character is trimmed from the padded side of
+//! the field, so a non-space pad allows values with significant leading or
+//! trailing spaces to be read intact. When writing, unused columns are filled
+//! with this character.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: A single character, written as `'_'` or `"_"`
+//!
+//! **Default**: A space
+//!
+//! **Example**: `#[fixcol(width = 8, align = "right", pad = "_")]`
+//!
+//! #### Pad Side
+//!
+//! Indicates which side of the value padding is placed on, independently of
+//! the field's alignment. Reads trim the padded side and writes pad it, while
+//! values too long for the field are still truncated according to the
+//! alignment. This supports report formats where, for example, right aligned
+//! numbers are nonetheless left justified within their column. It has no
+//! effect on `Full` aligned fields when reading.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `"left"`, `"right"`
+//!
+//! **Default**: Opposite the alignment, so `"left"` for `Right` aligned fields
+//! and `"right"` otherwise
+//!
+//! **Example**: `#[fixcol(width = 8, align = "right", pad_side = "right")]`
+//!
+//! #### Prefix
+//!
+//! Fixed text, such as a currency symbol, at the left edge of a numeric
+//! field. The number is aligned within the columns that remain after the
+//! prefix, so `1234` in a right aligned field of width 7 with a prefix of `$`
+//! is written as `$  1234`. When reading, the prefix is removed before the
+//! number is parsed. In strict mode the prefix must be present at the left
+//! edge of the field. Otherwise, it may follow whitespace or be missing.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Any string
+//!
+//! **Default**: None
+//!
+//! **Example**: `#[fixcol(width = 8, prefix = "$", align = "right")]`
+//!
+//! #### Reject Blank
+//!
+//! Rejects required fields that hold only their pad character. A blank
+//! `String` is otherwise read as empty text, and a blank number fails with a
+//! parse error specific to its type. With this set either is reported as
+//! "required field is blank". The check is only applied to fields parsed in
+//! strict mode, and `Option` fields and fields with a `blank_as` value may
+//! still be blank.
+//!
+//! **Can be applied to**: Struct
+//!
+//! **Allowed Values**: `true`, `false`
+//!
+//! **Default**: `false`
+//!
+//! **Example**: `#[fixcol(reject_blank = true)]`
+//!
+//! #### Repeat
+//!
+//! Indicates that a `Vec` field holds a list of items, each `width` columns
+//! wide, that continues to the end of the line. Only the last field may
+//! repeat. In strict mode the remainder of the line must hold a whole number
+//! of items.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: `"rest"`
+//!
+//! **Default**: None
+//!
+//! **Example**: `#[fixcol(width = 4, repeat = "rest")]`
+//!
+//! #### Scale
+//!
+//! The number of implied decimal places in a float or [`Percent`] field.
+//! Values are written as a whole number of the smallest decimal place, so
+//! `75.5` with a scale of 2 is written as `7550`, and text without a decimal
+//! point is divided by `10^scale` when read. Text with an explicit decimal
+//! point is read as written. Scaled values honor `min_digits` and overflow
+//! like integers rather than losing fractional digits.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Non-negative integers
+//!
+//! **Default**: None, floats are written with an explicit decimal point
+//!
+//! **Example**: `#[fixcol(width = 5, align = "full", scale = 2)]`
+//!
+//! #### Sep
+//!
+//! Indicates that a `Vec` field holds a list of items joined by this
+//! character, such as `A,B,C` in a single column, rather than a sequence of
+//! items each `width` characters wide. When reading, the field is trimmed
+//! according to its alignment and split on the separator. Each item is
+//! trimmed of surrounding spaces before it is parsed. Empty items between
+//! separators are parsed from empty text, which is an empty `String` but an
+//! error for most numbers. Empty items at the end of the list, such as those
+//! left by a trailing separator, are dropped, and a blank field is an empty
+//! list. When writing, the items are joined with the separator and written as
+//! one value. Items containing the separator will not read back the same.
+//!
+//! **Can be applied to**: `Vec` Field
+//!
+//! **Allowed Values**: A single character, written as `','` or `","`
+//!
+//! **Default**: None
+//!
+//! **Example**: `#[fixcol(width = 10, sep = ',')]`
+//!
 //! #### Skip
 //!
 //! Indicates the number of columns (measured in bytes) that are expected to be
@@ -338,6 +1131,22 @@
 //!
 //! **Example**: `#[fixcol(skip = 1, width = 12)]`
 //!
+//! #### Skip Literal
+//!
+//! Indicates the text expected in the skipped columns between the prior data
+//! field and the current data field, such as a `|` separator. In strict mode
+//! reads fail if the skipped columns do not match. Writes fill the skipped
+//! columns with the literal. Sets `skip` to the length of the literal if
+//! `skip` is not given.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: Any string with the same length as `skip`
+//!
+//! **Default**: None (skipped columns are blank)
+//!
+//! **Example**: `#[fixcol(skip_literal = "|", width = 12)]`
+//!
 //! #### Strict
 //!
 //! Indicates whether [strict mode](crate#strict-mode) should be enabled (See above).
@@ -346,46 +1155,125 @@
 //!
 //! **Allowed Values**: `true`, `false`
 //!
-//! **Default**: Cascades from outer context, outermost default `true`.
+//! **Default**: Cascades from outer context, outermost default `true`, or
+//! `false` when the build enables the `lax-by-default` feature (see
+//! [strict mode](crate#strict-mode)).
 //!
 //! **Example**: `#[fixcol(strict = true)]`
 //!
+//! #### Transform
+//!
+//! Names a function applied to a field's value right after it is read, such
+//! as to normalize a code or clamp a number without a newtype. The function
+//! may map the value to a new value of the same type, return a
+//! `Result<T, DataError>` to reject it, or borrow it as its `Deref` target
+//! like `str::to_uppercase` does for a `String`. See [`Transform`]. The value
+//! is written unchanged.
+//!
+//! **Can be applied to**: Field
+//!
+//! **Allowed Values**: A path to a function
+//!
+//! **Example**: `#[fixcol(width = 4, transform = "str::to_uppercase")]`
+//!
+//!
+//! #### Utf8
+//!
+//! How text that is not valid UTF-8 is handled when reading. With `"strict"`
+//! a field containing invalid UTF-8 is an error. With `"lossy"` invalid
+//! sequences are replaced with the replacement character `U+FFFD`, as by
+//! [`String::from_utf8_lossy`], and the record is read normally. Columns are
+//! counted before replacement, so the fields that follow are unaffected.
+//! Cannot be combined with `encoding`.
+//!
+//! **Can be applied to**: Struct, Enum
+//!
+//! **Allowed Values**: `"strict"`, `"lossy"`
+//!
+//! **Default**: `"strict"`
+//!
+//! **Example**: `#[fixcol(utf8 = "lossy")]`
+//!
+//! #### Value
+//!
+//! The token representing a variant of an enum deriving [`FixcolEnum`]. It is
+//! matched against the field's text after trimming according to the field's
+//! alignment.
+//!
+//! **Can be applied to**: Variant of an enum deriving `FixcolEnum`
+//!
+//! **Allowed Values**: Strings
+//!
+//! **Default**: None, required unless the enum sets `match_display`
+//!
+//! **Example**: `#[fixcol(value = "Bl")]`
 //!
 //! #### Width
 //!
 //! Indicates the number of columns (measured in bytes) used to encode the
 //! target field.
 //!
+//! The width may also be given as the path to a `const` of type `usize`,
+//! allowing layouts to share named widths. A field positioned with `at` may
+//! not follow a field whose width is set this way.
+//!
+//! The width may be omitted when the field's type implements [`FixedWidth`],
+//! as enums of keyed unit variants do, in which case the width of the type is
+//! used.
+//!
 //! **Can be applied to**: Field
 //!
-//! **Allowed Values**: Positive integers
+//! **Allowed Values**: Positive integers or the path to a `const usize`
 //!
-//! **Default**: Must be set **explicitly**.
+//! **Default**: Must be set **explicitly** unless the field's type implements
+//! [`FixedWidth`].
 //!
-//! **Example**: `#[fixcol(width = 12)]`
+//! **Example**: `#[fixcol(width = 12)]`, `#[fixcol(width = layout::NAME_WIDTH)]`
+
+extern crate alloc;
 
+#[cfg(feature = "encoding")]
+mod encoding;
 pub mod error;
+#[cfg(feature = "std")]
 mod fixcol;
 mod format;
 mod parse;
 
+#[cfg(feature = "test-util")]
+pub mod test_util;
 #[cfg(feature = "experimental-write")]
 mod write;
 
 extern crate fixcol_derive;
 
-pub use fixcol::{Iter, ReadFixed};
+#[cfg(feature = "std")]
+pub use fixcol::{
+    FixedReader, FixedWidth, Iter, LineNumbers, RawLine, ReadFixed, ReadFixedRef, RefIter,
+    SliceReader,
+};
 #[cfg(feature = "experimental-write")]
-pub use fixcol::{WriteFixed, WriteFixedAll};
+pub use fixcol::{FixedWriter, WriteFixed, WriteFixedAll};
 
+#[cfg(feature = "encoding")]
+pub use encoding::Encoding;
+pub use fixcol_derive::FixcolEnum;
+#[cfg(feature = "std")]
 pub use fixcol_derive::ReadFixed;
+#[cfg(feature = "std")]
+pub use fixcol_derive::ReadFixedRef;
 #[cfg(feature = "experimental-write")]
 pub use fixcol_derive::WriteFixed;
 
-pub use format::{Alignment, FieldDescription};
-pub use parse::FixedDeserializer;
+pub use format::{
+    Alignment, Either, FieldDescription, Flags, Ipv4Style, NegativeStyle, Overflow, PadSide,
+    Parsed, Percent, Preformatted,
+};
+#[doc(hidden)]
+pub use parse::{display_matches, display_mismatch, Borrowed, ByValue, Fallible};
+pub use parse::{FixedDeserializer, FixedDeserializerRef, Transform};
 #[cfg(feature = "experimental-write")]
-pub use write::FixedSerializer;
+pub use write::{write_columns, FixedColumn, FixedSerializer};
 
 #[cfg(test)]
 mod tests {
@@ -442,7 +1330,7 @@ mod tests {
     #[cfg(feature = "experimental-write")]
     impl WriteFixed for NumWord {
         fn write_fixed<W: Write>(&self, buf: &mut W) -> Result<(), Error> {
-            let _ = buf.write_fmt(format_args!("{:<10}{:>3}", self.name, self.value))?;
+            buf.write_fmt(format_args!("{:<10}{:>3}", self.name, self.value))?;
             Ok(())
         }
     }
@@ -1,5 +1,25 @@
-use crate::error::{DataError, Error, InnerError};
-use crate::format::{Alignment, FieldDescription};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use core::marker::PhantomData;
+use core::net::{Ipv4Addr, SocketAddr};
+use core::num::{IntErrorKind, ParseIntError, Saturating, Wrapping};
+use core::ops::{Deref, Range};
+#[cfg(feature = "std")]
+use std::path::PathBuf;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "std")]
+use crate::error::Error;
+use crate::error::{DataError, InnerError};
+use crate::format::{
+    Alignment, Either, FieldDescription, Flags, Ipv4Style, NegativeStyle, Parsed, Percent,
+    Preformatted,
+};
+#[cfg(feature = "std")]
 use crate::ReadFixed;
 
 /// A trait the represents field types that can be decoded from fixed length strings
@@ -23,6 +43,9 @@ use crate::ReadFixed;
 ///
 /// ### Custom deserialization
 ///
+/// For a simple code table like this one, deriving
+/// [`FixcolEnum`](crate::FixcolEnum) generates an equivalent implementation.
+///
 /// ```
 /// # use fixcol::ReadFixed;
 /// # use fixcol::FixedDeserializer;
@@ -145,7 +168,7 @@ use crate::ReadFixed;
 ///
 /// impl FixedDeserializer for Birthday {
 ///     fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Birthday, DataError> {
-///         let text = &s[desc.skip..desc.skip + desc.len];
+///         let text = desc.extract(s)?;
 ///         let mut parts = text.split(' ').filter(|x| *x != "");
 ///
 ///         let year = parts
@@ -201,33 +224,320 @@ pub trait FixedDeserializer {
         Self: Sized;
 }
 
-fn extract_trimmed<'a>(src: &'a str, desc: &FieldDescription) -> Result<&'a str, DataError> {
-    if desc.strict && !&src[..desc.skip].trim().is_empty() {
+/// A field type that can be decoded from text it may borrow from
+///
+/// This is the field level counterpart of [`ReadFixedRef`](crate::ReadFixedRef).
+/// A `&'a str` field borrows its text, trimmed according to the field's
+/// alignment, directly from the line. Every [`FixedDeserializer`] is also a
+/// `FixedDeserializerRef`, so borrowed and owned fields can be mixed in one
+/// record.
+pub trait FixedDeserializerRef<'a>: Sized {
+    /// Read an object of type `T` from text it may borrow from
+    ///
+    /// Uses the provided [`FieldDescription`] in the same way as
+    /// [`FixedDeserializer::parse_fixed`].
+    fn parse_fixed_ref(s: &'a str, desc: &FieldDescription) -> Result<Self, DataError>;
+}
+
+impl<'a, T: FixedDeserializer> FixedDeserializerRef<'a> for T {
+    fn parse_fixed_ref(s: &'a str, desc: &FieldDescription) -> Result<Self, DataError> {
+        T::parse_fixed(s, desc)
+    }
+}
+
+/// A function applied to a field's value after it is read
+///
+/// This is used by the `transform` parameter. It is implemented for functions
+/// that map a value to a new value of the same type, like `fn(u8) -> u8`, or
+/// that may fail, like `fn(u8) -> Result<u8, DataError>`. Functions that
+/// borrow the value as its [`Deref`] target are also accepted, so
+/// `str::to_uppercase` can transform a `String`.
+///
+/// The `Marker` parameter only distinguishes these cases and is always
+/// inferred.
+pub trait Transform<T, Marker> {
+    /// Applies the function to a value that was read
+    fn transform(&self, value: T) -> Result<T, DataError>;
+}
+
+/// Marks a [`Transform`] that maps a value to a new value
+#[doc(hidden)]
+pub struct ByValue;
+
+/// Marks a [`Transform`] that maps a value to a new value or an error
+#[doc(hidden)]
+pub struct Fallible;
+
+/// Marks a [`Transform`] that borrows the value as its [`Deref`] target
+#[doc(hidden)]
+pub struct Borrowed<U: ?Sized>(PhantomData<U>);
+
+impl<T, F: Fn(T) -> T> Transform<T, ByValue> for F {
+    fn transform(&self, value: T) -> Result<T, DataError> {
+        Ok(self(value))
+    }
+}
+
+impl<T, F: Fn(T) -> Result<T, DataError>> Transform<T, Fallible> for F {
+    fn transform(&self, value: T) -> Result<T, DataError> {
+        self(value)
+    }
+}
+
+impl<T, U, F> Transform<T, Borrowed<U>> for F
+where
+    T: Deref<Target = U>,
+    U: ?Sized,
+    F: Fn(&U) -> T,
+{
+    fn transform(&self, value: T) -> Result<T, DataError> {
+        Ok(self(&value))
+    }
+}
+
+/// Whether the `Display` text of `value` is exactly `text`
+///
+/// Used by `FixcolEnum` derives with `match_display`. The text is compared as
+/// it is formatted, so no `String` is allocated for each variant.
+#[doc(hidden)]
+pub fn display_matches<T: Display>(value: &T, text: &str) -> bool {
+    struct Matcher<'a> {
+        rest: &'a str,
+    }
+
+    impl fmt::Write for Matcher<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.rest.strip_prefix(s) {
+                Some(rest) => {
+                    self.rest = rest;
+                    Ok(())
+                }
+                None => Err(fmt::Error),
+            }
+        }
+    }
+
+    let mut matcher = Matcher { rest: text };
+    fmt::Write::write_fmt(&mut matcher, format_args!("{}", value)).is_ok()
+        && matcher.rest.is_empty()
+}
+
+/// The error for text that is not the `Display` text of any of `expected`
+///
+/// Used by `FixcolEnum` derives with `match_display`.
+#[doc(hidden)]
+pub fn display_mismatch<T: Display>(text: &str, expected: &[T]) -> DataError {
+    let expected: Vec<String> = expected
+        .iter()
+        .map(|v| format!("{:?}", v.to_string()))
+        .collect();
+    let message = format!(
+        "Unrecognized value. Expected one of {}",
+        expected.join(", ")
+    );
+
+    DataError::custom(text, &message)
+}
+
+/// Borrows the field's text, trimmed in the same way as a `String` field
+impl<'a> FixedDeserializerRef<'a> for &'a str {
+    fn parse_fixed_ref(s: &'a str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let text = extract_text(s, desc)?;
+        check_control_chars(s, desc)?;
+        Ok(text)
+    }
+}
+
+fn check_skip_literal(src: &str, desc: &FieldDescription) -> Result<(), DataError> {
+    if let (true, Some(literal)) = (desc.strict, desc.skip_literal) {
+        let end = core::cmp::min(desc.skip, src.len());
+        if &src[..end] != literal {
+            return Err(DataError::skip_literal_error(String::from(src), literal));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the skipped columns preceding a field in strict mode
+///
+/// The skipped columns are the full gap between the end of the prior field
+/// and the start of this one, so data from the prior field that overruns its
+/// width is caught here. They must match the field's `skip_literal` if it has
+/// one and otherwise be whitespace.
+fn check_gap(src: &str, desc: &FieldDescription) -> Result<usize, DataError> {
+    check_skip_literal(src, desc)?;
+
+    let start = core::cmp::min(desc.skip, src.len());
+    if desc.strict && desc.skip_literal.is_none() && !&src[..start].trim().is_empty() {
         return Err(DataError::whitespace_error(String::from(src)));
     }
 
-    let end = std::cmp::min(desc.skip + desc.len, src.len());
+    Ok(start)
+}
 
-    let slice = &src[desc.skip..end];
+/// Slices the described field out of `src` without trimming it
+///
+/// The slice is short when `src` ends within or before the field.
+fn extract_field<'a>(src: &'a str, desc: &FieldDescription) -> Result<&'a str, DataError> {
+    let start = check_gap(src, desc)?;
+
+    let end = core::cmp::min(desc.skip + desc.len, src.len());
+
+    Ok(&src[start..end])
+}
+
+fn extract_trimmed<'a>(src: &'a str, desc: &FieldDescription) -> Result<&'a str, DataError> {
+    let slice = extract_field(src, desc)?;
 
     let res = match (desc.strict, desc.alignment) {
-        (true, Alignment::Left) => slice.trim_end(),
-        (true, Alignment::Right) => slice.trim_start(),
         (true, Alignment::Full) => slice,
+        (true, _) if desc.pad_on_left() => slice.trim_start(),
+        (true, _) => slice.trim_end(),
         _ => slice.trim_start().trim_end(),
     };
 
     Ok(res)
 }
 
+impl FieldDescription {
+    /// Extracts the text of the described field from a line segment
+    ///
+    /// Slices the field out of `s` and trims it according to the field's
+    /// alignment, applying the same strict mode checks on skipped columns and
+    /// whitespace as the built in [`FixedDeserializer`] implementations. This
+    /// is intended for use in custom deserializers.
+    ///
+    /// ```
+    /// use fixcol::{Alignment, FieldDescription};
+    ///
+    /// let desc = FieldDescription::new(1, 5, Alignment::Right, true);
+    ///
+    /// assert_eq!(desc.extract("   42").unwrap(), "42");
+    /// assert!(desc.extract("x  42").is_err());
+    /// ```
+    pub fn extract<'a>(&self, s: &'a str) -> Result<&'a str, DataError> {
+        extract_trimmed(s, self)
+    }
+
+    /// Whether the described field of a line segment holds only its pad
+    /// character
+    ///
+    /// A field the segment ends before is also blank. The skipped columns
+    /// preceding the field are not considered.
+    ///
+    /// ```
+    /// use fixcol::{Alignment, FieldDescription};
+    ///
+    /// let desc = FieldDescription::new(1, 3, Alignment::Left, true);
+    ///
+    /// assert!(desc.is_blank("x   "));
+    /// assert!(!desc.is_blank("  1 "));
+    /// ```
+    pub fn is_blank(&self, s: &str) -> bool {
+        let start = core::cmp::min(self.skip, s.len());
+        let end = core::cmp::min(self.skip + self.len, s.len());
+
+        s.get(start..core::cmp::max(start, end))
+            .is_some_and(|field| field.chars().all(|c| c == self.pad))
+    }
+}
+
+/// Returns the `blank_as` text to parse in place of a blank numeric field
+fn blank_sentinel(trimmed: &str, desc: &FieldDescription) -> Option<&'static str> {
+    match desc.blank_as {
+        Some(sentinel) if trimmed.trim().is_empty() => Some(sentinel),
+        _ => None,
+    }
+}
+
+/// Rewrites a negative number in parentheses, such as `(123)`, as `-123`
+fn normalize_negative<'a>(
+    trimmed: &'a str,
+    desc: &FieldDescription,
+) -> Result<Cow<'a, str>, DataError> {
+    if desc.negatives != NegativeStyle::Parens {
+        return Ok(Cow::Borrowed(trimmed));
+    }
+
+    match (trimmed.strip_prefix('('), trimmed.ends_with(')')) {
+        (Some(inner), true) => Ok(Cow::Owned(format!("-{}", &inner[..inner.len() - 1]))),
+        (None, false) => Ok(Cow::Borrowed(trimmed)),
+        _ => Err(DataError::custom(
+            trimmed,
+            "Unmatched parentheses around negative number",
+        )),
+    }
+}
+
+/// Parses a number following the field's `prefix`
+///
+/// In strict mode the prefix must be at the left edge of the field. Otherwise
+/// it may be preceded by whitespace or missing altogether. The rest of the
+/// field is parsed by `parse` as a field of the remaining width.
+fn parse_prefixed<T, F>(
+    s: &str,
+    desc: &FieldDescription,
+    prefix: &str,
+    parse: F,
+) -> Result<T, DataError>
+where
+    F: FnOnce(&str, &FieldDescription) -> Result<T, DataError>,
+{
+    let start = check_gap(s, desc)?;
+    let end = core::cmp::min(desc.skip + desc.len, s.len());
+    let field = &s[start..core::cmp::max(start, end)];
+
+    let rest = match field.strip_prefix(prefix) {
+        Some(rest) => rest,
+        None if desc.strict => {
+            let message = format!("Expected prefix {:?}", prefix);
+            return Err(DataError::custom(field, &message));
+        }
+        None => {
+            let trimmed = field.trim_start();
+            trimmed.strip_prefix(prefix).unwrap_or(trimmed)
+        }
+    };
+
+    let inner = FieldDescription {
+        skip: 0,
+        len: desc.len - (field.len() - rest.len()),
+        skip_literal: None,
+        prefix: None,
+        ..*desc
+    };
+
+    parse(rest, &inner)
+}
+
 macro_rules! fixed_deserializer_float_impl {
     ($t:ty) => {
         impl FixedDeserializer for $t {
             fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<$t, DataError> {
+                if let Some(prefix) = desc.prefix {
+                    return parse_prefixed(s, desc, prefix, <$t>::parse_fixed);
+                }
+
                 let trimmed = extract_trimmed(s, desc)?;
-                trimmed.parse::<$t>().map_err(|e| {
+                let trimmed = blank_sentinel(trimmed, desc).unwrap_or(trimmed);
+                let trimmed = normalize_negative(trimmed, desc)?;
+                let value = trimmed.parse::<$t>().map_err(|e| {
                     DataError::new_err(trimmed.to_string(), InnerError::ParseFloatError(e))
-                })
+                })?;
+
+                if desc.strict && !desc.allow_nonfinite && !value.is_finite() {
+                    return Err(DataError::custom(&trimmed, "Value is not a finite number"));
+                }
+
+                // Text with an explicit decimal point is read as written
+                match desc.scale {
+                    Some(scale) if !trimmed.contains('.') => {
+                        let divisor: $t = (0..scale).fold(1.0, |d, _| d * 10.0);
+                        Ok(value / divisor)
+                    }
+                    _ => Ok(value),
+                }
             }
         }
     };
@@ -236,24 +546,56 @@ macro_rules! fixed_deserializer_float_impl {
 fixed_deserializer_float_impl!(f32);
 fixed_deserializer_float_impl!(f64);
 
+/// Builds the error for text that could not be parsed as the integer type `ty`
+///
+/// Text that is a valid integer, but out of range for `ty`, is reported as
+/// [`InnerError::OutOfRange`] naming the type.
+fn int_parse_error(text: &str, err: ParseIntError, ty: &'static str) -> DataError {
+    let out_of_range = matches!(
+        err.kind(),
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow
+    ) || text.parse::<i128>().is_ok();
+
+    let inner = match out_of_range {
+        true => InnerError::OutOfRange(ty),
+        false => InnerError::ParseIntError(err),
+    };
+
+    DataError::new_err(text.to_string(), inner)
+}
+
 macro_rules! fixed_deserializer_int_impl {
     ($t:ty) => {
         impl FixedDeserializer for $t {
             fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<$t, DataError> {
+                if let Some(prefix) = desc.prefix {
+                    return parse_prefixed(s, desc, prefix, <$t>::parse_fixed);
+                }
+
                 let trimmed = extract_trimmed(s, desc)?;
 
-                if desc.strict && desc.alignment == Alignment::Full && trimmed.len() != s.len() {
-                    let trimmed_len = trimmed.len();
-                    Err(DataError::new_data_width_error(
-                        String::from(trimmed),
-                        trimmed_len,
-                        s.len(),
-                    ))
-                } else {
-                    trimmed.parse::<$t>().map_err(|e| {
-                        DataError::new_err(trimmed.to_string(), InnerError::ParseIntError(e))
-                    })
-                }
+                // A strict `Full` field is not trimmed, so it must be exactly
+                // an optional sign followed by zero-padded digits, like
+                // `-0042` or `+0007`. Any space padding fails to parse.
+                let trimmed = match blank_sentinel(trimmed, desc) {
+                    Some(sentinel) => sentinel,
+                    None if desc.strict
+                        && desc.alignment == Alignment::Full
+                        && trimmed.len() != desc.len =>
+                    {
+                        return Err(DataError::new_data_width_error(
+                            String::from(trimmed),
+                            desc.len,
+                            trimmed.len(),
+                        ));
+                    }
+                    None => trimmed,
+                };
+
+                let trimmed = normalize_negative(trimmed, desc)?;
+                trimmed
+                    .parse::<$t>()
+                    .map_err(|e| int_parse_error(&trimmed, e, stringify!($t)))
             }
         }
     };
@@ -274,23 +616,168 @@ fixed_deserializer_int_impl!(i128);
 fixed_deserializer_int_impl!(usize);
 fixed_deserializer_int_impl!(isize);
 
+/// Extracts the text of a text field, trimming its padding
+fn extract_text<'a>(s: &'a str, desc: &FieldDescription) -> Result<&'a str, DataError> {
+    let start = check_gap(s, desc)?;
+    let end = core::cmp::min(desc.skip + desc.len, s.len());
+    let slice = &s[start..core::cmp::max(start, end)];
+
+    let trimmed = match desc.alignment {
+        Alignment::Full => slice,
+        Alignment::Left if desc.keep_trailing => slice,
+        _ if desc.pad_on_left() && desc.pad == ' ' => slice.trim_start(),
+        _ if desc.pad_on_left() => slice.trim_start_matches(desc.pad),
+        _ if desc.pad == ' ' => slice.trim_end(),
+        _ => slice.trim_end_matches(desc.pad),
+    };
+
+    Ok(trimmed)
+}
+
+/// Checks a text field for control characters in strict mode
+///
+/// A control character in a text column usually means the record is
+/// misaligned. The pad character is allowed even if it is a control character.
+fn check_control_chars(s: &str, desc: &FieldDescription) -> Result<(), DataError> {
+    if !desc.strict {
+        return Ok(());
+    }
+
+    let start = core::cmp::min(desc.skip, s.len());
+    let end = core::cmp::min(desc.skip + desc.len, s.len());
+    let field = &s[start..core::cmp::max(start, end)];
+
+    match field
+        .char_indices()
+        .find(|(_, c)| c.is_control() && *c != desc.pad)
+    {
+        Some((position, c)) => {
+            let message = format!("Control character {:?} at byte {} of field", c, position);
+            Err(DataError::custom(field, &message))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Reads the field's text, trimming its padding
+///
+/// In strict mode text containing control characters, such as tabs or
+/// newlines, is an error.
 impl FixedDeserializer for String {
     fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<String, DataError> {
-        let slice = &s[desc.skip..desc.skip + desc.len];
+        let text = extract_text(s, desc)?;
+        check_control_chars(s, desc)?;
+        Ok(text.to_string())
+    }
+}
 
-        let trimmed = match desc.alignment {
-            Alignment::Left => slice.trim_end(),
-            Alignment::Right => slice.trim_start(),
-            Alignment::Full => slice,
-        };
+/// Reads `true` and `false`, or the tokens set by the field's `bool_tokens`
+///
+/// Text that matches neither token but is made up entirely of digits is read
+/// as a number, where zero is `false` and anything else is `true` (e.g., `00`
+/// and `01`). The tokens take precedence, so with `bool_tokens = "10"` the
+/// text `1` is `true` and `0` is `false`.
+impl FixedDeserializer for bool {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<bool, DataError> {
+        let text = extract_trimmed(s, desc)?;
+        let (true_text, false_text) = desc.bool_texts();
+
+        match text {
+            t if t == true_text => Ok(true),
+            f if f == false_text => Ok(false),
+            n if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) => {
+                Ok(n.bytes().any(|b| b != b'0'))
+            }
+            _ => {
+                let message = format!("Expected \"{}\" or \"{}\"", true_text, false_text);
+                Err(DataError::custom(text, &message))
+            }
+        }
+    }
+}
+
+/// Reads a path, trimmed in the same way as a `String`
+#[cfg(feature = "std")]
+impl FixedDeserializer for PathBuf {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<PathBuf, DataError> {
+        String::parse_fixed(s, desc).map(PathBuf::from)
+    }
+}
+
+/// Reads an address and port such as `1.2.3.4:80` or `[::1]:8080`
+impl FixedDeserializer for SocketAddr {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<SocketAddr, DataError> {
+        let text = extract_trimmed(s, desc)?;
+
+        text.parse()
+            .map_err(|_| DataError::custom(text, "Expected a socket address"))
+    }
+}
+
+/// Reads an IPv4 address in the form set by the field's `ipv4` style
+///
+/// Dotted addresses look like `192.168.1.1`. Octet addresses are four
+/// zero-padded, 3 digit octets with no separator, like `192168001001`.
+impl FixedDeserializer for Ipv4Addr {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Ipv4Addr, DataError> {
+        let text = extract_trimmed(s, desc)?;
+
+        match desc.ipv4 {
+            Ipv4Style::Dotted => text
+                .parse()
+                .map_err(|_| DataError::custom(text, "Expected an IPv4 address")),
+            Ipv4Style::Octets => {
+                let err = || DataError::custom(text, "Expected four 3 digit octets");
+                if text.len() != 12 || !text.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(err());
+                }
+
+                let mut octets = [0; 4];
+                for (i, octet) in octets.iter_mut().enumerate() {
+                    *octet = text[i * 3..i * 3 + 3].parse().map_err(|_| err())?;
+                }
+
+                Ok(Ipv4Addr::from(octets))
+            }
+        }
+    }
+}
+
+/// Reads a time stored as a whole number of seconds since the Unix epoch
+#[cfg(feature = "std")]
+impl FixedDeserializer for SystemTime {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<SystemTime, DataError> {
+        let secs = u64::parse_fixed(s, desc)?;
 
-        Ok(trimmed.to_string())
+        UNIX_EPOCH
+            .checked_add(Duration::from_secs(secs))
+            .ok_or_else(|| DataError::custom(&secs.to_string(), "Timestamp is out of range"))
     }
 }
 
+/// Consumes the column without reading any data
+///
+/// Useful for modeling filler or ignored columns without allocating a
+/// `String` that is never used.
+impl FixedDeserializer for () {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<(), DataError> {
+        check_skip_literal(s, desc)
+    }
+}
+
+#[cfg(feature = "std")]
 impl<T: ReadFixed> FixedDeserializer for T {
     fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
-        let slice = &s[desc.skip..desc.skip + desc.len];
+        // The inner record's columns must stay in place, so the field is
+        // sliced the same way as `extract` but not trimmed
+        let slice = extract_field(s, desc)?;
+        if slice.len() < desc.len {
+            return Err(DataError::new_data_width_error(
+                String::from(slice),
+                desc.len,
+                slice.len(),
+            ));
+        }
 
         let obj = T::read_fixed_str(slice).map_err(|e| match e {
             Error::DataError(e) => e,
@@ -305,7 +792,11 @@ impl<T: ReadFixed> FixedDeserializer for T {
 
 impl<T: FixedDeserializer> FixedDeserializer for Option<T> {
     fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
-        let slice = &s[desc.skip..desc.skip + desc.len];
+        let start = check_gap(s, desc)?;
+        let end = core::cmp::min(desc.skip + desc.len, s.len());
+
+        // A line that ends within the field may leave it short or empty
+        let slice = &s[start..core::cmp::max(start, end)];
 
         if slice.trim_start().is_empty() {
             Ok(None)
@@ -315,11 +806,507 @@ impl<T: FixedDeserializer> FixedDeserializer for Option<T> {
     }
 }
 
+/// Captures a field's parse error instead of failing the whole record
+///
+/// A field that fails to parse is read as `Err` holding the error, so the rest
+/// of the record is still read.
+impl<T: FixedDeserializer> FixedDeserializer for Result<T, DataError> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        Ok(T::parse_fixed(s, desc))
+    }
+}
+
+impl FixedDeserializer for Percent {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        f64::parse_fixed(s, desc).map(|value| Percent(value / 100.0))
+    }
+}
+
+/// Keeps all of the field's text, without trimming its padding
+impl FixedDeserializer for Preformatted {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let full = FieldDescription { alignment: Alignment::Full, ..*desc };
+        extract_text(s, &full).map(|text| Preformatted(text.to_string()))
+    }
+}
+
+/// Keeps the field's trimmed text alongside its parsed value
+///
+/// The text covers every column after the field's skipped columns, so a field
+/// holding several items keeps the text of all of them.
+impl<T: FixedDeserializer> FixedDeserializer for Parsed<T> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let value = T::parse_fixed(s, desc)?;
+
+        let whole = FieldDescription {
+            len: s.len().saturating_sub(desc.skip),
+            ..*desc
+        };
+        let raw = extract_trimmed(s, &whole)?.to_string();
+
+        Ok(Parsed { value, raw })
+    }
+}
+
+/// Reads each column of the field as one flag, the leftmost being bit 0
+impl FixedDeserializer for Flags {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let start = check_gap(s, desc)?;
+        let end = core::cmp::min(desc.skip + desc.len, s.len());
+        let field = &s[start..core::cmp::max(start, end)];
+
+        if desc.len > u32::BITS as usize {
+            return Err(DataError::custom(
+                field,
+                "Flags fields hold at most 32 flags",
+            ));
+        }
+
+        let (on, off) = desc.flag_chars();
+        let mut bits = 0;
+        for (position, c) in field.chars().enumerate() {
+            if c == on {
+                bits |= 1 << position;
+            } else if desc.strict && c != off {
+                let message = format!("Expected {:?} or {:?} in flags field", on, off);
+                return Err(DataError::custom(field, &message));
+            }
+        }
+
+        Ok(Flags(bits))
+    }
+}
+
+/// Reads the field as an `A`, falling back to a `B` if that fails
+///
+/// When neither succeeds, the error describes both failures.
+impl<A: FixedDeserializer, B: FixedDeserializer> FixedDeserializer for Either<A, B> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let left_err = match A::parse_fixed(s, desc) {
+            Ok(value) => return Ok(Either::Left(value)),
+            Err(e) => e,
+        };
+
+        let right_err = match B::parse_fixed(s, desc) {
+            Ok(value) => return Ok(Either::Right(value)),
+            Err(e) => e,
+        };
+
+        let message = format!(
+            "Matched neither representation\n  {}\n  {}",
+            left_err.to_string().trim_end(),
+            right_err.to_string().trim_end()
+        );
+        Err(DataError::custom(left_err.value(), &message))
+    }
+}
+
+impl<T: FixedDeserializer> FixedDeserializer for Wrapping<T> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        T::parse_fixed(s, desc).map(Wrapping)
+    }
+}
+
+impl<T: FixedDeserializer> FixedDeserializer for Saturating<T> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        T::parse_fixed(s, desc).map(Saturating)
+    }
+}
+
+/// Reads a range stored as its start and end in adjacent columns
+///
+/// Each bound is `len` characters wide, so when deriving the field should
+/// set `count = 2`. In strict mode a start that is after the end is an error.
+impl<T: FixedDeserializer + PartialOrd> FixedDeserializer for Range<T> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        let split = core::cmp::min(desc.skip + desc.len, s.len());
+        let (first, second) = s.split_at(split);
+
+        let end_desc = FieldDescription { skip: 0, skip_literal: None, ..*desc };
+        let start = T::parse_fixed(first, desc)?;
+        let end = T::parse_fixed(second, &end_desc)?;
+
+        if desc.strict && start > end {
+            let text = s.get(desc.skip..).unwrap_or("");
+            return Err(DataError::custom(
+                text,
+                "Range start is after its end (strict)",
+            ));
+        }
+
+        Ok(start..end)
+    }
+}
+
+/// Reads a list of items joined by `sep` from a single field
+///
+/// The field is trimmed according to its alignment before being split. Each
+/// item is trimmed of surrounding spaces and parsed on its own, so empty items
+/// between separators are parsed from empty text. Empty items at the end of
+/// the list, such as those left by a trailing separator, are dropped.
+fn parse_separated<T: FixedDeserializer>(
+    s: &str,
+    desc: &FieldDescription,
+    sep: char,
+) -> Result<Vec<T>, DataError> {
+    let text = extract_trimmed(s, desc)?;
+    let mut items: Vec<&str> = text.split(sep).map(str::trim).collect();
+    while items.last().is_some_and(|item| item.is_empty()) {
+        items.pop();
+    }
+
+    items
+        .into_iter()
+        .map(|item| {
+            let item_desc = FieldDescription {
+                skip: 0,
+                len: item.len(),
+                alignment: Alignment::Left,
+                skip_literal: None,
+                sep: None,
+                ..*desc
+            };
+            T::parse_fixed(item, &item_desc)
+        })
+        .collect()
+}
+
+/// Reads a list of items each `len` characters wide
+///
+/// The items follow the skipped columns and continue to the end of the
+/// supplied text. In strict mode the text must hold a whole number of items.
+/// Otherwise, a short final item is read as if it were padded with spaces.
+///
+/// When the field sets `sep`, the field instead holds a list of items joined
+/// by that character.
+impl<T: FixedDeserializer> FixedDeserializer for Vec<T> {
+    fn parse_fixed(s: &str, desc: &FieldDescription) -> Result<Self, DataError> {
+        if let Some(sep) = desc.sep {
+            return parse_separated(s, desc, sep);
+        }
+
+        check_skip_literal(s, desc)?;
+
+        let items = s.get(desc.skip..).unwrap_or("");
+        let remainder = items.len() % desc.len.max(1);
+        if desc.strict && remainder != 0 {
+            return Err(DataError::new_data_width_error(
+                items.to_string(),
+                items.len() - remainder + desc.len,
+                items.len(),
+            ));
+        }
+
+        let item_desc = FieldDescription { skip: 0, skip_literal: None, ..*desc };
+
+        let mut out = Vec::new();
+        let mut start = 0;
+        while start < items.len() {
+            let end = core::cmp::min(start + desc.len.max(1), items.len());
+            let item = format!("{:<width$}", &items[start..end], width = desc.len);
+            out.push(T::parse_fixed(item.as_str(), &item_desc)?);
+            start = end;
+        }
+
+        Ok(out)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests {
     use std::str::from_utf8;
 
     use super::*;
+    use crate::format::PadSide;
+
+    #[test]
+    fn extract_unit() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 3,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual: Result<(), DataError> = <()>::parse_fixed(" abc", &desc);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn display_text_matches() {
+        assert!(display_matches(&Alignment::Right, "right"));
+        assert!(display_matches(&12, "12"));
+        assert!(!display_matches(&Alignment::Right, "rig"));
+        assert!(!display_matches(&Alignment::Right, "rights"));
+        assert!(!display_matches(&12, ""));
+
+        let err = display_mismatch("center", &[Alignment::Left, Alignment::Right]);
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"center\": Unrecognized value. Expected one of \"left\", \"right\"\n"
+        );
+    }
+
+    #[test]
+    fn field_description_extract() {
+        let desc = FieldDescription {
+            skip: 2,
+            len: 4,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(desc.extract("  ab  ").unwrap(), "ab");
+        assert_eq!(
+            desc.extract("  ab  ").unwrap(),
+            String::parse_fixed("  ab  ", &desc).unwrap()
+        );
+        assert!(desc.extract("  ab").is_ok());
+        assert!(desc.extract("x ab  ").is_err());
+        assert!(desc.extract("   ab ").unwrap().starts_with(' '));
+    }
+
+    #[test]
+    fn extract_blank_as() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 4,
+            alignment: Alignment::Full,
+            strict: true,
+            blank_as: Some("-1"),
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(i16::parse_fixed("    ", &desc).unwrap(), -1);
+        assert_eq!(i16::parse_fixed("0042", &desc).unwrap(), 42);
+        assert!(i16::parse_fixed("  x ", &desc).is_err());
+
+        let desc = FieldDescription { alignment: Alignment::Right, ..desc };
+        assert_eq!(f32::parse_fixed("    ", &desc).unwrap(), -1.0);
+    }
+
+    #[test]
+    fn extract_system_time() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 12,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = SystemTime::parse_fixed("  1700000000", &desc).unwrap();
+        assert_eq!(actual, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+
+        let actual = SystemTime::parse_fixed("         -10", &desc);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn extract_pad_side() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            pad_side: Some(PadSide::Right),
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(u16::parse_fixed("42   ", &desc).unwrap(), 42);
+        assert!(u16::parse_fixed("   42", &desc).is_err());
+        assert_eq!(String::parse_fixed("ab   ", &desc).unwrap(), "ab");
+    }
+
+    #[test]
+    fn extract_path() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 12,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        let actual = PathBuf::parse_fixed(" /tmp/a.txt  ", &desc).unwrap();
+        assert_eq!(actual, PathBuf::from("/tmp/a.txt"));
+    }
+
+    #[test]
+    fn extract_socket_addr() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 14,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = SocketAddr::parse_fixed("    1.2.3.4:80", &desc).unwrap();
+        assert_eq!(actual, SocketAddr::from(([1, 2, 3, 4], 80)));
+
+        let actual = SocketAddr::parse_fixed("    [::1]:8080", &desc).unwrap();
+        assert_eq!(actual, SocketAddr::from(([0, 0, 0, 0, 0, 0, 0, 1], 8080)));
+
+        let err = SocketAddr::parse_fixed("       1.2.3.4", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"1.2.3.4\": Expected a socket address\n"
+        );
+    }
+
+    #[test]
+    fn extract_ipv4_addr() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 15,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Ipv4Addr::parse_fixed("192.168.1.1    ", &desc).unwrap();
+        assert_eq!(actual, Ipv4Addr::new(192, 168, 1, 1));
+
+        let desc = FieldDescription { len: 12, ipv4: Ipv4Style::Octets, ..desc };
+        let actual = Ipv4Addr::parse_fixed("192168001001", &desc).unwrap();
+        assert_eq!(actual, Ipv4Addr::new(192, 168, 1, 1));
+
+        let actual = Ipv4Addr::parse_fixed("010000000255", &desc).unwrap();
+        assert_eq!(actual, Ipv4Addr::new(10, 0, 0, 255));
+
+        let err = Ipv4Addr::parse_fixed("192168001256", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"192168001256\": Expected four 3 digit octets\n"
+        );
+        assert!(Ipv4Addr::parse_fixed("19216801001 ", &desc).is_err());
+        assert!(Ipv4Addr::parse_fixed("192.168.1.1 ", &desc).is_err());
+    }
+
+    #[test]
+    fn extract_preformatted() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 6,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Preformatted::parse_fixed("  AB 1 ", &desc).unwrap();
+        assert_eq!(actual, Preformatted(String::from(" AB 1 ")));
+    }
+
+    #[test]
+    fn extract_range() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Range::<u32>::parse_fixed("   12  40", &desc).unwrap();
+        assert_eq!(actual, 12..40);
+
+        let err = Range::<u32>::parse_fixed("   40  12", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"  40  12\": Range start is after its end (strict)\n"
+        );
+
+        let desc = FieldDescription { strict: false, ..desc };
+        let actual = Range::<u32>::parse_fixed("   40  12", &desc).unwrap();
+        assert_eq!((actual.start, actual.end), (40, 12));
+    }
+
+    #[test]
+    fn extract_shorter_than_skip() {
+        let desc = FieldDescription {
+            skip: 4,
+            len: 2,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(desc.extract("  ").unwrap(), "");
+        assert!(desc.extract(" x").is_err());
+    }
+
+    #[test]
+    fn extract_separated() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 10,
+            alignment: Alignment::Left,
+            strict: true,
+            sep: Some(','),
+            ..FieldDescription::default()
+        };
+
+        let actual = Vec::<String>::parse_fixed(" A, B,C    ", &desc).unwrap();
+        assert_eq!(actual, vec!["A", "B", "C"]);
+
+        // Interior empty items are kept while trailing ones are dropped
+        let actual = Vec::<String>::parse_fixed(" A,,B,,    ", &desc).unwrap();
+        assert_eq!(actual, vec!["A", "", "B"]);
+
+        let actual = Vec::<u16>::parse_fixed(" 1,20,300  ", &desc).unwrap();
+        assert_eq!(actual, vec![1, 20, 300]);
+
+        let actual = Vec::<u16>::parse_fixed("           ", &desc).unwrap();
+        assert!(actual.is_empty());
+
+        assert!(Vec::<u16>::parse_fixed(" 1,,3      ", &desc).is_err());
+    }
+
+    #[test]
+    fn extract_string_gap_strict() {
+        let desc = FieldDescription {
+            skip: 2,
+            len: 3,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(String::parse_fixed("  abc", &desc).unwrap(), "abc");
+        assert!(String::parse_fixed(" xabc", &desc).is_err());
+
+        let desc = FieldDescription { strict: false, ..desc };
+        assert_eq!(String::parse_fixed(" xabc", &desc).unwrap(), "abc");
+    }
+
+    #[test]
+    fn extract_string_control_chars_strict() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(String::parse_fixed(" ab  ", &desc).unwrap(), "ab");
+
+        let err = String::parse_fixed(" a\tb ", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"a\tb \": Control character '\\t' at byte 1 of field\n"
+        );
+
+        // A control character used as the pad is allowed
+        let pad = FieldDescription { pad: '\0', ..desc };
+        assert_eq!(String::parse_fixed(" ab\0\0", &pad).unwrap(), "ab");
+
+        let desc = FieldDescription { strict: false, ..desc };
+        assert_eq!(String::parse_fixed(" a\tb ", &desc).unwrap(), "a\tb");
+    }
 
     #[test]
     fn extract_string_left() {
@@ -328,6 +1315,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "abc".to_string();
@@ -341,6 +1329,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "abc".to_string();
@@ -354,6 +1343,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "bc".to_string();
@@ -364,39 +1354,102 @@ mod tests {
     fn extract_string_left_truncate() {
         let desc = FieldDescription {
             skip: 0,
-            len: 2,
-            alignment: Alignment::Left,
+            len: 2,
+            alignment: Alignment::Left,
+            strict: false,
+            ..FieldDescription::default()
+        };
+        let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
+        let expected = "ab".to_string();
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn extract_string_left_ws() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 6,
+            alignment: Alignment::Left,
+            strict: false,
+            ..FieldDescription::default()
+        };
+        let actual: String = String::parse_fixed("a bc  ", &desc).unwrap();
+        let expected = "a bc".to_string();
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn extract_string_left_leading_ws() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 6,
+            alignment: Alignment::Left,
+            strict: false,
+            ..FieldDescription::default()
+        };
+        let actual: String = String::parse_fixed(" abc  ", &desc).unwrap();
+        let expected = " abc".to_string();
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn extract_string_left_keep_trailing() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 6,
+            alignment: Alignment::Left,
+            strict: false,
+            keep_trailing: true,
+            ..FieldDescription::default()
+        };
+        let actual: String = String::parse_fixed(" abc   ", &desc).unwrap();
+        let expected = "abc   ".to_string();
+        assert_eq!(actual, expected)
+    }
+
+    #[test]
+    fn extract_string_right_keep_trailing() {
+        // keep_trailing has no effect on leading whitespace
+        let desc = FieldDescription {
+            skip: 0,
+            len: 6,
+            alignment: Alignment::Right,
             strict: false,
+            keep_trailing: true,
+            ..FieldDescription::default()
         };
-        let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
-        let expected = "ab".to_string();
+        let actual: String = String::parse_fixed("  abc ", &desc).unwrap();
+        let expected = "abc ".to_string();
         assert_eq!(actual, expected)
     }
 
     #[test]
-    fn extract_string_left_ws() {
+    fn extract_string_right_pad_char() {
+        // only the pad character is trimmed so leading spaces survive
         let desc = FieldDescription {
             skip: 0,
-            len: 6,
-            alignment: Alignment::Left,
+            len: 8,
+            alignment: Alignment::Right,
             strict: false,
+            pad: '_',
+            ..FieldDescription::default()
         };
-        let actual: String = String::parse_fixed("a bc  ", &desc).unwrap();
-        let expected = "a bc".to_string();
-        assert_eq!(actual, expected)
+        let actual: String = String::parse_fixed("___ ab c", &desc).unwrap();
+        assert_eq!(actual, " ab c")
     }
 
     #[test]
-    fn extract_string_left_leading_ws() {
+    fn extract_string_left_pad_char() {
         let desc = FieldDescription {
             skip: 0,
-            len: 6,
+            len: 8,
             alignment: Alignment::Left,
             strict: false,
+            pad: '*',
+            ..FieldDescription::default()
         };
-        let actual: String = String::parse_fixed(" abc  ", &desc).unwrap();
-        let expected = " abc".to_string();
-        assert_eq!(actual, expected)
+        let actual: String = String::parse_fixed("ab c ***", &desc).unwrap();
+        assert_eq!(actual, "ab c ")
     }
 
     #[test]
@@ -406,6 +1459,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "".to_string();
@@ -419,6 +1473,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "abc".to_string();
@@ -432,6 +1487,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "abc".to_string();
@@ -445,6 +1501,7 @@ mod tests {
             len: 2,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "bc".to_string();
@@ -458,6 +1515,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "ab".to_string();
@@ -471,6 +1529,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("  a bc", &desc).unwrap();
         let expected = "a bc".to_string();
@@ -484,6 +1543,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed(" abc  ", &desc).unwrap();
         let expected = "abc  ".to_string();
@@ -497,6 +1557,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abcdef", &desc).unwrap();
         let expected = "abcdef".to_string();
@@ -510,6 +1571,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abcdef", &desc).unwrap();
         let expected = "bcd".to_string();
@@ -523,6 +1585,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "abc   ".to_string();
@@ -536,6 +1599,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("   abc", &desc).unwrap();
         let expected = "   abc".to_string();
@@ -549,6 +1613,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "bc   ".to_string();
@@ -562,6 +1627,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed("abc   ", &desc).unwrap();
         let expected = "abc ".to_string();
@@ -575,6 +1641,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed(" a bc ", &desc).unwrap();
         let expected = " a bc ".to_string();
@@ -588,6 +1655,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed(" ab c ", &desc).unwrap();
         let expected = "ab ".to_string();
@@ -601,6 +1669,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: String = String::parse_fixed(" ab c ", &desc).unwrap();
         let expected = "ab c".to_string();
@@ -615,18 +1684,21 @@ mod tests {
                 len: 6,
                 alignment: Alignment::Full,
                 strict: false,
+                ..FieldDescription::default()
             },
             FieldDescription {
                 skip: 0,
                 len: 6,
                 alignment: Alignment::Left,
                 strict: false,
+                ..FieldDescription::default()
             },
             FieldDescription {
                 skip: 0,
                 len: 6,
                 alignment: Alignment::Right,
                 strict: false,
+                ..FieldDescription::default()
             },
         ];
         let expected: f32 = 3.14;
@@ -655,6 +1727,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual: f32 = f32::parse_fixed(" 3.14 ", &desc).unwrap();
         let expected: f32 = 3.14;
@@ -665,6 +1738,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual: Result<f32, DataError> = f32::parse_fixed(" 3.14 ", &desc);
 
@@ -684,6 +1758,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual: f32 = f32::parse_fixed(" 3.14 ", &desc).unwrap();
         let expected: f32 = 3.14;
@@ -694,6 +1769,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: f32 = f32::parse_fixed(" 3.14 ", &desc).unwrap();
         let expected: f32 = 0.14;
@@ -707,6 +1783,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual: f32 = f32::parse_fixed(" 3.14 ", &desc).unwrap();
         let expected: f32 = 3.14;
@@ -720,6 +1797,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: f32 = f32::parse_fixed(" 3.14 ", &desc).unwrap();
         let expected: f32 = 3.14;
@@ -730,6 +1808,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
         match f32::parse_fixed(" 3.14 ", &desc) {
             Ok(_) => panic!("Expected parse_fixed call to fail"),
@@ -747,12 +1826,162 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual: Result<f32, DataError> = f32::parse_fixed(" 3a14 ", &desc);
         let expected = "Error handling data from \"3a14\": invalid float literal\n";
         assert_eq!(actual.unwrap_err().to_string(), expected);
     }
 
+    #[test]
+    fn extract_scaled_float() {
+        let desc = FieldDescription {
+            len: 5,
+            alignment: Alignment::Right,
+            scale: Some(2),
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(f64::parse_fixed("07550", &desc).unwrap(), 75.5);
+        assert_eq!(f32::parse_fixed("  -25", &desc).unwrap(), -0.25);
+
+        // An explicit decimal point is read as written
+        assert_eq!(f64::parse_fixed("75.50", &desc).unwrap(), 75.5);
+
+        assert_eq!(
+            Percent::parse_fixed("07550", &desc).unwrap(),
+            Percent(0.755)
+        );
+    }
+
+    #[test]
+    fn extract_nonfinite_float() {
+        let desc = FieldDescription {
+            len: 4,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert!(f64::parse_fixed("inf ", &desc).is_err());
+        assert!(f32::parse_fixed("NaN ", &desc).is_err());
+        assert_eq!(f64::parse_fixed("1.5 ", &desc).unwrap(), 1.5);
+
+        let allowed = FieldDescription { allow_nonfinite: true, ..desc };
+        assert_eq!(
+            f64::parse_fixed("-inf", &allowed).unwrap(),
+            f64::NEG_INFINITY
+        );
+        assert!(f32::parse_fixed("NaN ", &allowed).unwrap().is_nan());
+
+        let lax = FieldDescription { strict: false, ..desc };
+        assert_eq!(f64::parse_fixed("inf ", &lax).unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    fn extract_prefixed_number() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 7,
+            alignment: Alignment::Right,
+            prefix: Some("$"),
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(u32::parse_fixed(" $  1234", &desc).unwrap(), 1234);
+        assert_eq!(f64::parse_fixed(" $  12.5", &desc).unwrap(), 12.5);
+        assert!(u32::parse_fixed("   $1234", &desc).is_err());
+        assert!(u32::parse_fixed("    1234", &desc).is_err());
+
+        let desc = FieldDescription { alignment: Alignment::Full, ..desc };
+        assert_eq!(i16::parse_fixed(" $-00042", &desc).unwrap(), -42);
+
+        // Lax fields allow whitespace before the prefix, or no prefix at all
+        let desc = FieldDescription {
+            strict: false,
+            alignment: Alignment::Right,
+            ..desc
+        };
+        assert_eq!(u32::parse_fixed("   $1234", &desc).unwrap(), 1234);
+        assert_eq!(u32::parse_fixed("    1234", &desc).unwrap(), 1234);
+    }
+
+    #[test]
+    fn extract_parsed() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 6,
+            alignment: Alignment::Right,
+            ..FieldDescription::default()
+        };
+
+        let parsed = Parsed::<u32>::parse_fixed("   0125", &desc).unwrap();
+        assert_eq!(parsed, Parsed { value: 125, raw: String::from("0125") });
+
+        // Errors parsing the value are reported as for the inner type
+        let err = Parsed::<u32>::parse_fixed("   01x5", &desc).unwrap_err();
+        let expected = u32::parse_fixed("   01x5", &desc).unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string());
+
+        let desc = FieldDescription {
+            len: 2,
+            alignment: Alignment::Left,
+            ..desc
+        };
+        let parsed = Parsed::<Vec<u8>>::parse_fixed(" 1 2 3 ", &desc).unwrap();
+        assert_eq!(
+            parsed,
+            Parsed {
+                value: vec![1, 2, 3],
+                raw: String::from("1 2 3")
+            }
+        );
+    }
+
+    #[test]
+    fn extract_flags() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert_eq!(Flags::parse_fixed(" YNNY", &desc).unwrap(), Flags(0b1001));
+        assert_eq!(Flags::parse_fixed(" NNNN", &desc).unwrap(), Flags(0));
+        assert!(Flags::parse_fixed(" YN?Y", &desc).is_err());
+
+        let desc = FieldDescription { strict: false, flags: Some("X "), ..desc };
+        assert_eq!(Flags::parse_fixed(" X X?", &desc).unwrap(), Flags(0b0101));
+
+        let desc = FieldDescription { len: 33, ..desc };
+        assert!(Flags::parse_fixed(" X", &desc).is_err());
+    }
+
+    #[test]
+    fn extract_either() {
+        let desc = FieldDescription {
+            len: 5,
+            alignment: Alignment::Right,
+            ..FieldDescription::default()
+        };
+
+        let value = Either::<u16, String>::parse_fixed("  120", &desc).unwrap();
+        assert_eq!(value, Either::Left(120));
+
+        let value = Either::<u16, String>::parse_fixed("  DNF", &desc).unwrap();
+        assert_eq!(value, Either::Right(String::from("DNF")));
+
+        let err = Either::<u16, f32>::parse_fixed("  DNF", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"DNF\": Matched neither representation\n  \
+            Error handling data from \"DNF\": invalid digit found in string\n  \
+            Error handling data from \"DNF\": invalid float literal\n"
+        );
+    }
+
     #[test]
     fn strict_numeric_zero_padding() {
         // validate "strict" behavior
@@ -762,6 +1991,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("042", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -771,6 +2001,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("042", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -780,6 +2011,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed(" 42", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -789,6 +2021,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed(" 42", &desc);
         assert!(actual.is_err());
@@ -798,6 +2031,168 @@ mod tests {
         );
     }
 
+    #[test]
+    fn strict_signed_zero_padding() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        assert_eq!(i16::parse_fixed("-0042", &desc).unwrap(), -42);
+        assert_eq!(i16::parse_fixed("+0007", &desc).unwrap(), 7);
+        assert_eq!(i16::parse_fixed("00042", &desc).unwrap(), 42);
+        assert_eq!(u16::parse_fixed("+0007", &desc).unwrap(), 7);
+        assert!(u16::parse_fixed("-0042", &desc).is_err());
+
+        // Space padding is rejected wherever it appears
+        assert!(i16::parse_fixed("  -42", &desc).is_err());
+        assert!(i16::parse_fixed("-  42", &desc).is_err());
+        assert!(i16::parse_fixed("-42  ", &desc).is_err());
+        assert!(i16::parse_fixed(" +007", &desc).is_err());
+
+        let desc = FieldDescription { negatives: NegativeStyle::Parens, ..desc };
+        assert_eq!(i16::parse_fixed("(042)", &desc).unwrap(), -42);
+        assert!(i16::parse_fixed("( 42)", &desc).is_err());
+
+        // Lax fields may be space padded
+        let desc = FieldDescription {
+            strict: false,
+            negatives: NegativeStyle::Sign,
+            ..desc
+        };
+        assert_eq!(i16::parse_fixed("  -42", &desc).unwrap(), -42);
+        assert_eq!(i16::parse_fixed("-0042", &desc).unwrap(), -42);
+    }
+
+    #[test]
+    fn strict_numeric_full_skip() {
+        let desc = FieldDescription {
+            skip: 2,
+            len: 3,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        let actual = u8::parse_fixed("  042", &desc).unwrap();
+        assert_eq!(actual, 42);
+    }
+
+    #[test]
+    fn strict_numeric_full_short() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 4,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        let err = u8::parse_fixed("042", &desc).unwrap_err();
+        assert_eq!(err.value(), "042");
+        assert_eq!(err.expected_width(), Some(4));
+        assert_eq!(err.actual_width(), Some(3));
+    }
+
+    #[test]
+    fn extract_vec() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 3,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+        let actual = Vec::<u16>::parse_fixed("   1 22333", &desc).unwrap();
+        assert_eq!(actual, vec![1, 22, 333]);
+
+        let actual = Vec::<u16>::parse_fixed(" ", &desc).unwrap();
+        assert_eq!(actual, Vec::<u16>::new());
+
+        let err = Vec::<u16>::parse_fixed("   1 2", &desc).unwrap_err();
+        assert_eq!(err.expected_width(), Some(6));
+        assert_eq!(err.actual_width(), Some(5));
+    }
+
+    #[test]
+    fn extract_vec_lax() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 3,
+            alignment: Alignment::Left,
+            strict: false,
+            ..FieldDescription::default()
+        };
+        let actual = Vec::<String>::parse_fixed("ab cd e", &desc).unwrap();
+        assert_eq!(actual, vec!["ab", "cd", "e"]);
+    }
+
+    #[test]
+    fn negative_parens() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 6,
+            alignment: Alignment::Right,
+            strict: true,
+            negatives: NegativeStyle::Parens,
+            ..FieldDescription::default()
+        };
+        assert_eq!(i32::parse_fixed(" (123)", &desc).unwrap(), -123);
+        assert_eq!(i32::parse_fixed("   123", &desc).unwrap(), 123);
+        assert_eq!(f64::parse_fixed(" (1.5)", &desc).unwrap(), -1.5);
+
+        let err = i32::parse_fixed("  (123", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"(123\": \
+            Unmatched parentheses around negative number\n"
+        );
+
+        let err = i32::parse_fixed("  123)", &desc).unwrap_err();
+        assert_eq!(err.value(), "123)");
+
+        // the default style does not accept parentheses
+        let desc = FieldDescription { negatives: NegativeStyle::Sign, ..desc };
+        assert!(i32::parse_fixed(" (123)", &desc).is_err());
+    }
+
+    #[test]
+    fn strict_skip_literal() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 3,
+            alignment: Alignment::Right,
+            strict: true,
+            skip_literal: Some("|"),
+            ..FieldDescription::default()
+        };
+        assert_eq!(u16::parse_fixed("| 42", &desc).unwrap(), 42);
+        assert_eq!(String::parse_fixed("|abc", &desc).unwrap(), "abc");
+
+        let err = u16::parse_fixed("  42", &desc).unwrap_err();
+        match err.inner_error() {
+            InnerError::SkipLiteralError(lit) => assert_eq!(lit, "|"),
+            _ => panic!("Expected SkipLiteralError"),
+        }
+
+        let err = String::parse_fixed(":abc", &desc).unwrap_err();
+        assert!(matches!(err.inner_error(), InnerError::SkipLiteralError(_)));
+    }
+
+    #[test]
+    fn lax_skip_literal() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 3,
+            alignment: Alignment::Right,
+            strict: false,
+            skip_literal: Some("|"),
+            ..FieldDescription::default()
+        };
+        assert_eq!(u16::parse_fixed("  42", &desc).unwrap(), 42);
+        assert_eq!(String::parse_fixed(":abc", &desc).unwrap(), "abc");
+    }
+
     #[test]
     fn strict_left_align() {
         // testing "strict" behavior
@@ -807,6 +2202,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed(" 42  ", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -816,6 +2212,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed(" 42  ", &desc);
         assert!(actual.is_err());
@@ -829,6 +2226,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("42   ", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -843,6 +2241,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("  42 ", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -852,6 +2251,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("  42 ", &desc);
         assert!(actual.is_err());
@@ -865,6 +2265,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
         let actual = u8::parse_fixed("   42", &desc).unwrap();
         assert_eq!(actual, 42);
@@ -904,6 +2305,7 @@ mod tests {
                 len: 2,
                 alignment: Alignment::Left,
                 strict: true,
+                ..FieldDescription::default()
             },
         );
 
@@ -917,12 +2319,138 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let actual = Option::<u16>::parse_fixed("   42", &desc).unwrap();
         assert_eq!(actual, Some(42));
     }
 
+    #[test]
+    fn parse_int_out_of_range() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 10,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let err = u32::parse_fixed("9999999999", &desc).unwrap_err();
+        assert!(matches!(err.inner_error(), InnerError::OutOfRange("u32")));
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"9999999999\": value 9999999999 does not fit in u32\n"
+        );
+
+        let err = usize::parse_fixed("        -1", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"-1\": value -1 does not fit in usize\n"
+        );
+
+        let err = i8::parse_fixed("      -129", &desc).unwrap_err();
+        assert!(matches!(err.inner_error(), InnerError::OutOfRange("i8")));
+
+        let err = u8::parse_fixed("       1x2", &desc).unwrap_err();
+        assert!(matches!(err.inner_error(), InnerError::ParseIntError(_)));
+    }
+
+    #[test]
+    fn parse_bool() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        assert!(bool::parse_fixed("true ", &desc).unwrap());
+        assert!(!bool::parse_fixed("false", &desc).unwrap());
+
+        let desc = FieldDescription { len: 1, bool_tokens: Some("YN"), ..desc };
+
+        assert!(bool::parse_fixed("Y", &desc).unwrap());
+        assert!(!bool::parse_fixed("N", &desc).unwrap());
+        assert_eq!(Option::<bool>::parse_fixed(" ", &desc).unwrap(), None);
+        assert_eq!(Option::<bool>::parse_fixed("Y", &desc).unwrap(), Some(true));
+
+        let err = bool::parse_fixed("X", &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"X\": Expected \"Y\" or \"N\"\n"
+        );
+    }
+
+    #[test]
+    fn parse_bool_numeric() {
+        let desc = FieldDescription {
+            len: 2,
+            alignment: Alignment::Right,
+            ..FieldDescription::default()
+        };
+
+        assert!(bool::parse_fixed("01", &desc).unwrap());
+        assert!(bool::parse_fixed("10", &desc).unwrap());
+        assert!(bool::parse_fixed(" 7", &desc).unwrap());
+        assert!(!bool::parse_fixed("00", &desc).unwrap());
+        assert!(!bool::parse_fixed(" 0", &desc).unwrap());
+        assert!(bool::parse_fixed("-1", &desc).is_err());
+
+        // Tokens are matched before numbers
+        let desc = FieldDescription { bool_tokens: Some("YN"), ..desc };
+        assert!(!bool::parse_fixed(" N", &desc).unwrap());
+        assert!(!bool::parse_fixed("00", &desc).unwrap());
+        assert!(bool::parse_fixed("01", &desc).unwrap());
+    }
+
+    #[test]
+    fn parse_result() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Result::<u16, DataError>::parse_fixed("   42", &desc).unwrap();
+        assert_eq!(actual.unwrap(), 42);
+
+        let actual = Result::<u16, DataError>::parse_fixed("  4x2", &desc).unwrap();
+        assert_eq!(actual.unwrap_err().value(), "4x2");
+    }
+
+    #[test]
+    fn parse_wrapping() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Wrapping::<u32>::parse_fixed("   42", &desc).unwrap();
+        assert_eq!(actual, Wrapping(42));
+    }
+
+    #[test]
+    fn parse_saturating() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let actual = Saturating::<u8>::parse_fixed("  200", &desc).unwrap();
+        assert_eq!(actual, Saturating(200));
+        assert_eq!(actual + Saturating(100), Saturating(255));
+    }
+
     #[test]
     fn parse_option_none() {
         let desc = FieldDescription {
@@ -930,6 +2458,7 @@ mod tests {
             len: 5,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let actual = Option::<u16>::parse_fixed("     ", &desc).unwrap();
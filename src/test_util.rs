@@ -0,0 +1,92 @@
+//! Helpers for testing fixed width layouts
+//!
+//! Available with the `test-util` feature, which also enables
+//! `experimental-write`.
+
+use std::fmt::Debug;
+
+use crate::{FixedWriter, ReadFixed, WriteFixed};
+
+/// Asserts that records survive being written and read back unchanged
+///
+/// Writes `records` one per line, reads the output back with
+/// [`read_fixed_all`](ReadFixed::read_fixed_all), and asserts the result
+/// equals the original records. Panics if writing or reading fails or if any
+/// record differs.
+///
+/// # Example
+///
+/// ```
+/// use fixcol::test_util::assert_round_trip;
+/// use fixcol::{ReadFixed, WriteFixed};
+///
+/// #[derive(Debug, PartialEq, ReadFixed, WriteFixed)]
+/// struct Point {
+///     #[fixcol(width = 3, align = "right")]
+///     x: u16,
+///     #[fixcol(width = 3, align = "right")]
+///     y: u16,
+/// }
+///
+/// assert_round_trip(&[Point { x: 1, y: 2 }, Point { x: 300, y: 40 }]);
+/// ```
+pub fn assert_round_trip<T>(records: &[T])
+where
+    T: ReadFixed + WriteFixed + PartialEq + Debug,
+{
+    let mut buf: Vec<u8> = Vec::new();
+    let mut writer = FixedWriter::new(&mut buf);
+    for record in records {
+        if let Err(err) = writer.write_record(record) {
+            panic!("Failed to write {:?}: {}", record, err);
+        }
+    }
+    if let Err(err) = writer.finish() {
+        panic!("Failed to finish writing: {}", err);
+    }
+
+    let actual: Vec<T> = T::read_fixed_all(buf.as_slice())
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|err| panic!("Failed to read written records: {}", err));
+
+    assert_eq!(
+        actual.as_slice(),
+        records,
+        "Records changed after a round trip"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[derive(Debug, PartialEq)]
+    struct Padded(String);
+
+    impl ReadFixed for Padded {
+        fn read_fixed<R: std::io::Read>(buf: &mut R) -> Result<Self, Error> {
+            let mut s = String::new();
+            buf.read_to_string(&mut s)?;
+            Ok(Self(s.trim_end().to_string()))
+        }
+    }
+
+    impl WriteFixed for Padded {
+        fn write_fixed<W: std::io::Write>(&self, buf: &mut W) -> Result<(), Error> {
+            write!(buf, "{:<4}", self.0)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip(&[Padded("ab".to_string()), Padded("abcd".to_string())]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Records changed after a round trip")]
+    fn round_trip_mismatch() {
+        assert_round_trip(&[Padded("ab  ".to_string())]);
+    }
+}
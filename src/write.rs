@@ -1,7 +1,15 @@
 use std::io::Write;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::num::{Saturating, Wrapping};
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::error::{DataError, Error};
-use crate::format::{Alignment, FieldDescription};
+use crate::format::{
+    Alignment, Either, FieldDescription, Flags, Ipv4Style, NegativeStyle, Overflow, Parsed,
+    Percent, Preformatted,
+};
 use crate::WriteFixed;
 
 /// A trait that represents the field types that can be encoded to fixed length strings
@@ -23,63 +31,331 @@ const SPACES: [u8; 256] = [b' '; 256];
 fn write_spaces<W: Write>(buf: &mut W, num: usize) -> Result<(), Error> {
     let mut bytes_to_write: usize = num;
 
-    while bytes_to_write > 256 {
-        buf.write(&SPACES)?;
-        bytes_to_write -= 256;
+    while bytes_to_write > 0 {
+        let chunk = bytes_to_write.min(SPACES.len());
+        buf.write_all(&SPACES[..chunk])?;
+        bytes_to_write -= chunk;
     }
 
-    buf.write(&SPACES[..bytes_to_write])?;
+    Ok(())
+}
+
+fn write_pad<W: Write>(buf: &mut W, pad: char, num: usize) -> Result<(), Error> {
+    if pad == ' ' {
+        return write_spaces(buf, num);
+    }
+
+    let mut bytes = [0; 4];
+    let pad = pad.encode_utf8(&mut bytes).as_bytes();
+    for _ in 0..num {
+        buf.write_all(pad)?;
+    }
 
     Ok(())
 }
 
-impl FixedSerializer for String {
+fn write_skip<W: Write>(buf: &mut W, desc: &FieldDescription) -> Result<(), Error> {
+    match desc.skip_literal {
+        Some(literal) => {
+            let bytes = literal.as_bytes();
+            let len = std::cmp::min(bytes.len(), desc.skip);
+            buf.write_all(&bytes[..len])?;
+            write_spaces(buf, desc.skip - len)
+        }
+        None => write_spaces(buf, desc.skip),
+    }
+}
+
+/// Writes the bytes as they are, without assuming they are UTF-8
+///
+/// Bytes are padded, truncated and checked for overflow in the same way as
+/// the text of a `str`. `Vec<u8>` is written as a sequence of numbers, like
+/// any other `Vec`, so an owned column of raw bytes should use `Box<[u8]>`.
+impl FixedSerializer for [u8] {
     fn write_fixed_field<W: Write>(
         &self,
         buf: &mut W,
         desc: &FieldDescription,
     ) -> Result<(), Error> {
-        // If strict fail on overflow
-        if desc.strict && self.len() > desc.len {
-            return Err(DataError::new_data_width_error(self.clone(), desc.len, self.len()).into());
+        let too_wide = || {
+            let text = String::from_utf8_lossy(self).into_owned();
+            DataError::new_data_width_error(text, desc.len, self.len()).into()
+        };
+
+        // Fail on overflow unless the field truncates
+        if self.len() > desc.len && desc.overflow_is_error(desc.strict) {
+            return Err(too_wide());
         }
 
         // if strict and full-align fail on too short also
-        if desc.strict && desc.alignment == Alignment::Full && self.len() != desc.len {
-            return Err(DataError::new_data_width_error(self.clone(), desc.len, self.len()).into());
+        if desc.strict && desc.alignment == Alignment::Full && self.len() < desc.len {
+            return Err(too_wide());
         }
 
         // If so we'll need to truncate
-        let string_is_too_long = self.len() > desc.len;
+        let is_too_long = self.len() > desc.len;
 
-        write_spaces(buf, desc.skip)?;
+        write_skip(buf, desc)?;
 
-        match desc.alignment {
-            Alignment::Left | Alignment::Full => {
-                if string_is_too_long {
-                    buf.write(&self[0..desc.len].as_bytes())?;
-                } else {
-                    buf.write(&self.as_bytes())?;
-                    let spaces_to_pad = desc.len - self.len();
-                    write_spaces(buf, spaces_to_pad)?;
-                }
-            }
-            Alignment::Right => {
-                if string_is_too_long {
-                    let start = self.len() - desc.len;
-                    buf.write(&self[start..].as_bytes())?;
-                } else {
-                    let spaces_to_pad = desc.len - self.len();
-                    write_spaces(buf, spaces_to_pad)?;
-                    buf.write(&self.as_bytes())?;
-                }
-            }
+        // Truncation keeps the start of the value unless asked to keep the end
+        let bytes = match desc.on_overflow {
+            Some(Overflow::KeepEnd) if is_too_long => &self[self.len() - desc.len..],
+            _ if is_too_long => &self[0..desc.len],
+            _ => self,
+        };
+
+        let spaces_to_pad = desc.len - bytes.len();
+        if desc.pad_on_left() {
+            write_pad(buf, desc.pad, spaces_to_pad)?;
+            buf.write_all(bytes)?;
+        } else {
+            buf.write_all(bytes)?;
+            write_pad(buf, desc.pad, spaces_to_pad)?;
         }
 
         Ok(())
     }
 }
 
+impl FixedSerializer for &[u8] {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        (**self).write_fixed_field(buf, desc)
+    }
+}
+
+impl FixedSerializer for Box<[u8]> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        (**self).write_fixed_field(buf, desc)
+    }
+}
+
+impl FixedSerializer for str {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        self.as_bytes().write_fixed_field(buf, desc)
+    }
+}
+
+impl FixedSerializer for &str {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        (**self).write_fixed_field(buf, desc)
+    }
+}
+
+impl FixedSerializer for String {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        self.as_str().write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes `true` and `false`, or the tokens set by the field's `bool_tokens`
+impl FixedSerializer for bool {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        let (true_text, false_text) = desc.bool_texts();
+        let text = if *self { true_text } else { false_text };
+        text.write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes a path as text
+///
+/// Paths that are not valid UTF-8 are an error in strict mode. Otherwise,
+/// invalid sequences are replaced as by [`Path::to_string_lossy`](std::path::Path::to_string_lossy).
+impl FixedSerializer for PathBuf {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        let text = self.to_string_lossy();
+
+        if desc.strict && self.to_str().is_none() {
+            return Err(DataError::custom(&text, "Path is not valid UTF-8").into());
+        }
+
+        text.into_owned().write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes an address and port such as `1.2.3.4:80` or `[::1]:8080`
+impl FixedSerializer for SocketAddr {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        self.to_string().write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes an IPv4 address in the form set by the field's `ipv4` style
+impl FixedSerializer for Ipv4Addr {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        match desc.ipv4 {
+            Ipv4Style::Dotted => self.to_string().write_fixed_field(buf, desc),
+            Ipv4Style::Octets => {
+                let [a, b, c, d] = self.octets();
+                format!("{:03}{:03}{:03}{:03}", a, b, c, d).write_fixed_field(buf, desc)
+            }
+        }
+    }
+}
+
+/// Writes a time as a whole number of seconds since the Unix epoch
+///
+/// Times before the epoch are an error. Fractions of a second are truncated.
+impl FixedSerializer for SystemTime {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        let secs = self
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| {
+                let before = format!("-{}", e.duration().as_secs());
+                DataError::custom(&before, "Timestamp is before the Unix epoch")
+            })?
+            .as_secs();
+
+        secs.write_fixed_field(buf, desc)
+    }
+}
+
+/// Left pads the digits of a formatted number with zeros to fill `width`
+///
+/// The sign, if any, is kept as the first character so that `-42` padded to
+/// five characters becomes `-0042` rather than `00-42`.
+fn zero_pad(num: &str, width: usize) -> String {
+    let (sign, digits) = match num.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", num),
+    };
+
+    let zeros = width.saturating_sub(num.len());
+    format!("{}{}{}", sign, "0".repeat(zeros), digits)
+}
+
+/// Left pads the digits of a formatted integer with zeros to the field's
+/// `min_digits`, if set
+fn pad_min_digits(num: String, desc: &FieldDescription) -> String {
+    match desc.min_digits {
+        Some(digits) => {
+            let sign = usize::from(num.starts_with('-'));
+            zero_pad(&num, digits + sign)
+        }
+        None => num,
+    }
+}
+
+/// Fits a formatted number that is wider than its field
+///
+/// Cutting digits from a number would write a different value, so the field
+/// is instead filled with `*` to mark the overflow. The one exception is a
+/// float whose whole part fits, which loses only fractional digits. A field
+/// that opts in with [`Overflow::KeepEnd`] keeps the least significant
+/// characters instead.
+fn fit_number(num: String, desc: &FieldDescription) -> String {
+    if desc.on_overflow == Some(Overflow::KeepEnd) {
+        return num[num.len() - desc.len..].to_string();
+    }
+
+    let whole_part_fits = match num.find('.') {
+        Some(point) => point <= desc.len && !num.ends_with(')'),
+        None => false,
+    };
+
+    if whole_part_fits {
+        let mut s = num[..desc.len].to_string();
+        if s.ends_with('.') {
+            s.pop();
+        }
+        s
+    } else {
+        "*".repeat(desc.len)
+    }
+}
+
+/// Formats a number's sign according to the field's [`NegativeStyle`]
+///
+/// `Full` aligned numbers are zero padded to fill the field, leaving room
+/// for the sign or parentheses.
+fn format_number(num: String, desc: &FieldDescription, zero_padded: bool) -> String {
+    match (desc.negatives, num.strip_prefix('-')) {
+        (NegativeStyle::Parens, Some(digits)) if zero_padded => {
+            format!("({})", zero_pad(digits, desc.len.saturating_sub(2)))
+        }
+        (NegativeStyle::Parens, Some(digits)) => format!("({})", digits),
+        _ if zero_padded => zero_pad(&num, desc.len),
+        _ => num,
+    }
+}
+
+/// Writes a number after the field's `prefix`
+///
+/// The number is formatted by `write` into the columns that remain after the
+/// prefix, then written following the field's skipped columns and the prefix.
+/// Nothing is written if the number cannot be formatted. A prefix wider than
+/// the field is an overflow error.
+fn write_prefixed<W, F>(
+    buf: &mut W,
+    desc: &FieldDescription,
+    prefix: &str,
+    write: F,
+) -> Result<(), Error>
+where
+    W: Write,
+    F: FnOnce(&mut Vec<u8>, &FieldDescription) -> Result<(), Error>,
+{
+    if prefix.len() > desc.len {
+        let text = String::from(prefix);
+        return Err(DataError::new_data_width_error(text, desc.len, prefix.len()).into());
+    }
+
+    let inner = FieldDescription {
+        skip: 0,
+        len: desc.len - prefix.len(),
+        skip_literal: None,
+        prefix: None,
+        ..*desc
+    };
+
+    let mut num: Vec<u8> = Vec::new();
+    write(&mut num, &inner)?;
+
+    write_skip(buf, desc)?;
+    buf.write_all(prefix.as_bytes())?;
+    buf.write_all(&num)?;
+    Ok(())
+}
+
 macro_rules! fixed_serializer_int_impl {
     ($t:ty) => {
         impl FixedSerializer for $t {
@@ -88,29 +364,32 @@ macro_rules! fixed_serializer_int_impl {
                 buf: &mut W,
                 desc: &FieldDescription,
             ) -> Result<(), Error> {
-                let mut s = self.to_string();
+                if let Some(prefix) = desc.prefix {
+                    return write_prefixed(buf, desc, prefix, |num, inner| {
+                        self.write_fixed_field(num, inner)
+                    });
+                }
+
+                let num = pad_min_digits(self.to_string(), desc);
+                let mut s = format_number(num, desc, desc.alignment == Alignment::Full);
+
                 if s.len() > desc.len {
-                    if desc.strict {
+                    if desc.overflow_is_error(desc.strict) {
                         let len = s.len();
                         return Err(DataError::new_data_width_error(s, desc.len, len).into());
                     }
-                    // truncate if not strict
-                    s = s.as_str()[..desc.len].to_string();
+                    s = fit_number(s, desc);
                 }
 
                 let padding = desc.len - s.len();
 
-                match desc.alignment {
-                    Alignment::Left | Alignment::Full => {
-                        write_spaces(buf, desc.skip)?;
-                        buf.write(s.as_bytes())?;
-                        write_spaces(buf, padding)?;
-                    }
-                    Alignment::Right => {
-                        let skip = padding + desc.skip;
-                        write_spaces(buf, skip)?;
-                        buf.write(s.as_bytes())?;
-                    }
+                write_skip(buf, desc)?;
+                if desc.pad_on_left() {
+                    write_spaces(buf, padding)?;
+                    buf.write_all(s.as_bytes())?;
+                } else {
+                    buf.write_all(s.as_bytes())?;
+                    write_spaces(buf, padding)?;
                 }
 
                 Ok(())
@@ -132,6 +411,64 @@ fixed_serializer_int_impl!(i64);
 fixed_serializer_int_impl!(usize);
 fixed_serializer_int_impl!(isize);
 
+/// Formats a float as a whole number of its implied decimal places
+///
+/// With a scale of 2, `75.5` is written as `7550`.
+fn scaled_text(value: f64, scale: u32) -> String {
+    let scaled = (value * f64::powi(10.0, scale as i32)).round();
+
+    // Avoid writing negative zero as `-0`
+    let scaled = if scaled == 0.0 { 0.0 } else { scaled };
+    format!("{:.0}", scaled)
+}
+
+/// Writes a float given its default text representation and value
+///
+/// A float with a `scale` is written as a whole number like an integer, so
+/// it honors `min_digits` and is never truncated in a way that changes its
+/// value.
+fn write_float<W: Write>(
+    buf: &mut W,
+    desc: &FieldDescription,
+    text: String,
+    value: f64,
+) -> Result<(), Error> {
+    if let Some(prefix) = desc.prefix {
+        return write_prefixed(buf, desc, prefix, |num, inner| {
+            write_float(num, inner, text, value)
+        });
+    }
+
+    let full = desc.alignment == Alignment::Full;
+    let (num, overflow_default) = match desc.scale {
+        Some(scale) => (pad_min_digits(scaled_text(value, scale), desc), desc.strict),
+        // A truncated `Full` value would not read back the same
+        None => (text, desc.strict && full),
+    };
+
+    let mut s = format_number(num, desc, full);
+    if s.len() > desc.len {
+        if desc.overflow_is_error(overflow_default) {
+            let len = s.len();
+            return Err(DataError::new_data_width_error(s, desc.len, len).into());
+        }
+        s = fit_number(s, desc);
+    }
+
+    let padding = desc.len - s.len();
+
+    write_skip(buf, desc)?;
+    if desc.pad_on_left() {
+        write_spaces(buf, padding)?;
+        buf.write_all(s.as_bytes())?;
+    } else {
+        buf.write_all(s.as_bytes())?;
+        write_spaces(buf, padding)?;
+    }
+
+    Ok(())
+}
+
 // TODO: These are likely completely broken and need to support fmt options
 impl FixedSerializer for f32 {
     fn write_fixed_field<W: Write>(
@@ -139,60 +476,113 @@ impl FixedSerializer for f32 {
         buf: &mut W,
         desc: &FieldDescription,
     ) -> Result<(), Error> {
-        let mut s = self.to_string();
-        if s.len() > desc.len {
-            s = s.as_str()[..desc.len].to_string();
-        }
+        write_float(buf, desc, self.to_string(), f64::from(*self))
+    }
+}
 
-        let padding = desc.len - s.len();
+impl FixedSerializer for f64 {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        write_float(buf, desc, self.to_string(), *self)
+    }
+}
 
-        match desc.alignment {
-            Alignment::Left | Alignment::Full => {
-                write_spaces(buf, desc.skip)?;
-                buf.write(s.as_bytes())?;
-                write_spaces(buf, padding)?;
-            }
-            Alignment::Right => {
-                let skip = padding + desc.skip;
-                write_spaces(buf, skip)?;
-                buf.write(s.as_bytes())?;
-            }
+impl FixedSerializer for Percent {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        (self.0 * 100.0).write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes the text exactly as it is, without padding or truncating it
+///
+/// In strict mode text that is not exactly the width of the field is an
+/// error.
+impl FixedSerializer for Preformatted {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        if desc.strict && self.0.len() != desc.len {
+            let err = DataError::new_data_width_error(self.0.clone(), desc.len, self.0.len());
+            return Err(err.into());
         }
 
+        write_skip(buf, desc)?;
+        buf.write_all(self.0.as_bytes())?;
+
         Ok(())
     }
 }
 
-impl FixedSerializer for f64 {
+impl<T: FixedSerializer> FixedSerializer for Parsed<T> {
     fn write_fixed_field<W: Write>(
         &self,
         buf: &mut W,
         desc: &FieldDescription,
     ) -> Result<(), Error> {
-        let mut s = self.to_string();
-        if s.len() > desc.len {
-            s = s.as_str()[..desc.len].to_string();
-        }
+        self.value.write_fixed_field(buf, desc)
+    }
+}
 
-        let padding = desc.len - s.len();
+/// Writes one column per flag, the leftmost being bit 0
+///
+/// A set flag beyond the width of the field does not fit and is handled
+/// like any other overflowing value.
+impl FixedSerializer for Flags {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        let (on, off) = desc.flag_chars();
+        let text = |len: usize| -> String {
+            (0..len)
+                .map(|position| {
+                    if self.is_set(position as u32) {
+                        on
+                    } else {
+                        off
+                    }
+                })
+                .collect()
+        };
 
-        match desc.alignment {
-            Alignment::Left | Alignment::Full => {
-                write_spaces(buf, desc.skip)?;
-                buf.write(s.as_bytes())?;
-                write_spaces(buf, padding)?;
-            }
-            Alignment::Right => {
-                let skip = padding + desc.skip;
-                write_spaces(buf, skip)?;
-                buf.write(s.as_bytes())?;
-            }
+        let needed = (u32::BITS - self.0.leading_zeros()) as usize;
+        if desc.len > u32::BITS as usize {
+            let message = "Flags fields hold at most 32 flags";
+            return Err(DataError::custom(&text(needed), message).into());
+        }
+        if needed > desc.len && desc.overflow_is_error(desc.strict) {
+            return Err(DataError::new_data_width_error(text(needed), desc.len, needed).into());
         }
 
+        write_skip(buf, desc)?;
+        buf.write_all(text(desc.len).as_bytes())?;
         Ok(())
     }
 }
 
+impl<A: FixedSerializer, B: FixedSerializer> FixedSerializer for Either<A, B> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        match self {
+            Either::Left(value) => value.write_fixed_field(buf, desc),
+            Either::Right(value) => value.write_fixed_field(buf, desc),
+        }
+    }
+}
+
 impl<T: WriteFixed> FixedSerializer for T {
     fn write_fixed_field<W: Write>(
         &self,
@@ -203,6 +593,18 @@ impl<T: WriteFixed> FixedSerializer for T {
     }
 }
 
+/// Writes a column of blank spaces
+impl FixedSerializer for () {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        write_skip(buf, desc)?;
+        write_spaces(buf, desc.len)
+    }
+}
+
 impl<T: FixedSerializer> FixedSerializer for Option<T> {
     fn write_fixed_field<W: Write>(
         &self,
@@ -214,19 +616,687 @@ impl<T: FixedSerializer> FixedSerializer for Option<T> {
             Some(t) => t.write_fixed_field(buf, desc),
         }
     }
-}
+}
+
+impl<T: FixedSerializer> FixedSerializer for Saturating<T> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        self.0.write_fixed_field(buf, desc)
+    }
+}
+
+impl<T: FixedSerializer> FixedSerializer for Wrapping<T> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        self.0.write_fixed_field(buf, desc)
+    }
+}
+
+/// Writes a list of items joined by `sep` as a single field
+///
+/// Each item is written without padding and the joined list is then written
+/// as text, following the field's alignment and overflow policy.
+fn write_separated<T: FixedSerializer, W: Write>(
+    items: &[T],
+    buf: &mut W,
+    desc: &FieldDescription,
+    sep: char,
+) -> Result<(), Error> {
+    let item_desc = FieldDescription {
+        skip: 0,
+        alignment: Alignment::Left,
+        strict: false,
+        skip_literal: None,
+        pad: ' ',
+        pad_side: None,
+        sep: None,
+        ..*desc
+    };
+
+    let mut texts: Vec<String> = Vec::new();
+    for item in items {
+        let mut v: Vec<u8> = Vec::new();
+        item.write_fixed_field(&mut v, &item_desc)?;
+        let text = String::from_utf8(v)?;
+        texts.push(text.trim_end().to_string());
+    }
+
+    let joined = texts.join(sep.encode_utf8(&mut [0; 4]));
+    joined.as_str().write_fixed_field(buf, desc)
+}
+
+/// Writes a list of items each `len` characters wide
+impl<T: FixedSerializer> FixedSerializer for Vec<T> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        if let Some(sep) = desc.sep {
+            return write_separated(self, buf, desc, sep);
+        }
+
+        write_skip(buf, desc)?;
+
+        let item_desc = FieldDescription { skip: 0, skip_literal: None, ..*desc };
+
+        for item in self {
+            item.write_fixed_field(buf, &item_desc)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a range as its start followed by its end, each `len` characters wide
+impl<T: FixedSerializer> FixedSerializer for Range<T> {
+    fn write_fixed_field<W: Write>(
+        &self,
+        buf: &mut W,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        let end_desc = FieldDescription { skip: 0, skip_literal: None, ..*desc };
+
+        self.start.write_fixed_field(buf, desc)?;
+        self.end.write_fixed_field(buf, &end_desc)
+    }
+}
+
+/// A column of values that can be written one row at a time
+///
+/// This is implemented for slices, arrays and `Vec`s of any [`FixedSerializer`] so
+/// that columns of different types can be passed together to
+/// [`write_columns`].
+pub trait FixedColumn {
+    /// The number of rows in the column
+    fn row_count(&self) -> usize;
+
+    /// Writes the value in the given row using the supplied [`FieldDescription`]
+    fn write_row(
+        &self,
+        row: usize,
+        buf: &mut dyn Write,
+        desc: &FieldDescription,
+    ) -> Result<(), Error>;
+}
+
+/// Writes the value in one row of a slice
+fn write_slice_row<T: FixedSerializer>(
+    column: &[T],
+    row: usize,
+    mut buf: &mut dyn Write,
+    desc: &FieldDescription,
+) -> Result<(), Error> {
+    column[row].write_fixed_field(&mut buf, desc)
+}
+
+impl<T: FixedSerializer> FixedColumn for &[T] {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn write_row(
+        &self,
+        row: usize,
+        buf: &mut dyn Write,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        write_slice_row(self, row, buf, desc)
+    }
+}
+
+impl<T: FixedSerializer, const N: usize> FixedColumn for [T; N] {
+    fn row_count(&self) -> usize {
+        N
+    }
+
+    fn write_row(
+        &self,
+        row: usize,
+        buf: &mut dyn Write,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        write_slice_row(self, row, buf, desc)
+    }
+}
+
+impl<T: FixedSerializer> FixedColumn for Vec<T> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn write_row(
+        &self,
+        row: usize,
+        buf: &mut dyn Write,
+        desc: &FieldDescription,
+    ) -> Result<(), Error> {
+        write_slice_row(self, row, buf, desc)
+    }
+}
+
+/// Writes column-major data as fixed width rows (newline delimited)
+///
+/// Each row holds one value from every column, in the order the columns are
+/// supplied, written using that column's [`FieldDescription`]. All of the
+/// columns must have the same number of rows. Errors writing a value report
+/// its row as the line and its column as the field.
+///
+/// # Example
+/// ```
+/// use fixcol::{write_columns, Alignment, FieldDescription};
+///
+/// let names = vec!["Alice", "Bob"];
+/// let ages: Vec<u8> = vec![34, 7];
+///
+/// let name_desc = FieldDescription::new(0, 6, Alignment::Left, true);
+/// let age_desc = FieldDescription::new(0, 3, Alignment::Right, true);
+///
+/// let mut buffer: Vec<u8> = Vec::new();
+/// write_columns(&mut buffer, &[(&names, name_desc), (&ages, age_desc)]).unwrap();
+///
+/// let s = std::str::from_utf8(&buffer).unwrap();
+/// assert_eq!(s, "Alice  34\nBob     7\n");
+/// ```
+pub fn write_columns<W: Write>(
+    buf: &mut W,
+    columns: &[(&dyn FixedColumn, FieldDescription)],
+) -> Result<(), Error> {
+    let rows = columns.first().map_or(0, |(column, _)| column.row_count());
+
+    for (index, (column, _)) in columns.iter().enumerate() {
+        if column.row_count() != rows {
+            let message = format!("Expected {} rows but found {}", rows, column.row_count());
+            return Err(DataError::custom("", &message)
+                .with_field(index, None)
+                .into());
+        }
+    }
+
+    for row in 0..rows {
+        for (index, (column, desc)) in columns.iter().enumerate() {
+            match column.write_row(row, buf, desc) {
+                Err(Error::DataError(err)) => {
+                    let err = err.with_field(index, None).with_line(row + 1);
+                    return Err(Error::DataError(err));
+                }
+                other => other?,
+            }
+        }
+
+        buf.write_all("\n".as_bytes())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str;
+
+    use regex::Regex;
+
+    use super::*;
+    use crate::format::{Overflow, PadSide};
+
+    fn to_str(inp: Vec<u8>) -> String {
+        use std::str;
+        str::from_utf8(inp.as_slice()).unwrap().to_string()
+    }
+
+    #[test]
+    fn write_str() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 6,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = "abc".write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "    abc");
+
+        let mut v = Vec::new();
+        let res = "abcdefg".write_fixed_field(&mut v, &desc);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn write_bytes() {
+        let desc = FieldDescription {
+            len: 6,
+            pad: '.',
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        // Bytes are written as is, including invalid UTF-8
+        let mut v = Vec::new();
+        let bytes: &[u8] = &[0x41, 0xFF, 0x00];
+        bytes.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(v, vec![0x41, 0xFF, 0x00, b'.', b'.', b'.']);
+
+        let desc = FieldDescription { alignment: Alignment::Right, ..desc };
+        let mut v = Vec::new();
+        let boxed: Box<[u8]> = Box::from(&b"AB"[..]);
+        boxed.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(v, b"....AB");
+
+        // Overflow is an error in strict mode and truncates otherwise
+        let mut v = Vec::new();
+        let long: &[u8] = b"ABCDEFGH";
+        assert!(long.write_fixed_field(&mut v, &desc).is_err());
+
+        let desc = FieldDescription { strict: false, ..desc };
+        long.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(v, b"ABCDEF");
+    }
+
+    #[test]
+    fn write_system_time() {
+        use std::time::Duration;
+
+        let desc = FieldDescription {
+            skip: 0,
+            len: 12,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_500);
+        let res = time.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "  1700000000");
+
+        let mut v = Vec::new();
+        let time = UNIX_EPOCH - Duration::from_secs(10);
+        let err = time.write_fixed_field(&mut v, &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"-10\": Timestamp is before the Unix epoch\n"
+        );
+    }
+
+    #[test]
+    fn write_bool() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = true.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "true ");
+
+        let desc = FieldDescription { len: 1, bool_tokens: Some("YN"), ..desc };
+
+        let mut v = Vec::new();
+        let res = false.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "N");
+
+        let mut v = Vec::new();
+        let res = None::<bool>.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " ");
+    }
+
+    #[test]
+    fn write_wrapping() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = Wrapping(42u32).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   42");
+    }
+
+    #[test]
+    fn write_saturating() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = Saturating(42u32).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   42");
+    }
+
+    #[test]
+    fn write_path() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 8,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = PathBuf::from("a/b.txt").write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " a/b.txt");
+    }
+
+    #[test]
+    fn write_socket_addr() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 12,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = SocketAddr::from(([10, 0, 0, 1], 443)).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "10.0.0.1:443");
+
+        let mut v = Vec::new();
+        let res = SocketAddr::from(([192, 168, 100, 200], 8080)).write_fixed_field(&mut v, &desc);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn write_ipv4_addr() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 12,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = Ipv4Addr::new(10, 0, 0, 1).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "10.0.0.1    ");
+
+        let desc = FieldDescription { ipv4: Ipv4Style::Octets, ..desc };
+        let mut v = Vec::new();
+        let res = Ipv4Addr::new(192, 168, 1, 1).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "192168001001");
+    }
+
+    #[test]
+    fn write_preformatted() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = Preformatted(String::from("AB 12")).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " AB 12");
+
+        let mut v = Vec::new();
+        let res = Preformatted(String::from("AB12")).write_fixed_field(&mut v, &desc);
+        assert!(res.is_err());
+
+        // Lax fields are written verbatim whatever their width
+        let desc = FieldDescription { strict: false, ..desc };
+        let mut v = Vec::new();
+        let res = Preformatted(String::from("AB12")).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " AB12");
+    }
+
+    #[test]
+    fn write_range() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = (12u32..40).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   12  40");
+    }
+
+    #[test]
+    fn write_separated() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 10,
+            alignment: Alignment::Right,
+            strict: true,
+            sep: Some(','),
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = vec![1u16, 20, 300].write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   1,20,300");
+
+        let mut v = Vec::new();
+        let res = vec!["A", "B"].write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "        A,B");
+
+        let mut v = Vec::new();
+        let res = vec![1234u16; 3].write_fixed_field(&mut v, &desc);
+        assert!(res.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_path_non_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = PathBuf::from(OsStr::from_bytes(b"a\xffb"));
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Left,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let err = path.write_fixed_field(&mut v, &desc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error handling data from \"a\u{fffd}b\": Path is not valid UTF-8\n"
+        );
+
+        let lax = FieldDescription { strict: false, ..desc };
+        let mut v = Vec::new();
+        let res = path.write_fixed_field(&mut v, &lax);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "a\u{fffd}b");
+    }
+
+    #[test]
+    fn write_vec() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 3,
+            alignment: Alignment::Right,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = vec![1u16, 22, 333].write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   1 22333");
+    }
+
+    #[test]
+    fn write_negative_parens() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 6,
+            alignment: Alignment::Right,
+            strict: true,
+            negatives: NegativeStyle::Parens,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = (-123i32).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " (123)");
+
+        let mut v = Vec::new();
+        let res = 123i32.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   123");
+
+        let mut v = Vec::new();
+        let res = (-1.5f64).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " (1.5)");
+
+        let desc = FieldDescription { alignment: Alignment::Full, ..desc };
+
+        let mut v = Vec::new();
+        let res = (-42i16).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "(0042)");
+    }
+
+    #[test]
+    fn write_min_digits() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 8,
+            alignment: Alignment::Right,
+            strict: true,
+            min_digits: Some(4),
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = 7u32.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "    0007");
+
+        let mut v = Vec::new();
+        let res = (-7i32).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "   -0007");
+
+        let mut v = Vec::new();
+        let res = 123456u32.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "  123456");
+
+        let desc = FieldDescription {
+            alignment: Alignment::Left,
+            negatives: NegativeStyle::Parens,
+            ..desc
+        };
+
+        let mut v = Vec::new();
+        let res = (-7i64).write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "(0007)  ");
+    }
+
+    #[test]
+    fn write_string_pad() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 6,
+            alignment: Alignment::Right,
+            strict: true,
+            pad: '_',
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = String::from(" ab").write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " ___ ab");
+
+        let desc = FieldDescription {
+            alignment: Alignment::Left,
+            pad: 'é',
+            ..desc
+        };
+
+        let mut v = Vec::new();
+        let res = String::from("ab").write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " abéééé");
+    }
 
-#[cfg(test)]
-mod tests {
-    use core::str;
+    #[test]
+    fn write_skip_literal() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            alignment: Alignment::Right,
+            strict: true,
+            skip_literal: Some("|"),
+            ..FieldDescription::default()
+        };
 
-    use regex::Regex;
+        let mut v = Vec::new();
+        let res = 42u16.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "|  42");
 
-    use super::*;
+        let mut v = Vec::new();
+        let res = String::from("ab").write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "|  ab");
+    }
 
-    fn to_str(inp: Vec<u8>) -> String {
-        use std::str;
-        str::from_utf8(inp.as_slice()).unwrap().to_string()
+    #[test]
+    fn write_unit() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = ().write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "     ");
     }
 
     //
@@ -240,6 +1310,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -258,6 +1329,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -276,6 +1348,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: false,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -296,6 +1369,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -312,6 +1386,176 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_scaled_float() {
+        let desc = FieldDescription {
+            len: 5,
+            alignment: Alignment::Full,
+            scale: Some(2),
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        75.5f64.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "07550");
+
+        let mut v = Vec::new();
+        Percent(0.755).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "07550");
+
+        let desc = FieldDescription { alignment: Alignment::Right, ..desc };
+
+        let mut v = Vec::new();
+        (-0.25f32).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "  -25");
+
+        // Scaled floats overflow like integers rather than losing digits
+        let mut v = Vec::new();
+        assert!(1234.5f64.write_fixed_field(&mut v, &desc).is_err());
+
+        let desc = FieldDescription { strict: false, ..desc };
+        let mut v = Vec::new();
+        1234.5f64.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "*****");
+    }
+
+    #[test]
+    fn write_flags() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 4,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        Flags(0b1001).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), " YNNY");
+
+        // Set flags beyond the width of the field overflow
+        let mut v = Vec::new();
+        assert!(Flags(0b10001).write_fixed_field(&mut v, &desc).is_err());
+        assert!(v.is_empty());
+
+        let desc = FieldDescription { strict: false, flags: Some("X "), ..desc };
+        let mut v = Vec::new();
+        Flags(0b10101).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), " X X ");
+    }
+
+    #[test]
+    fn write_either() {
+        let desc = FieldDescription {
+            len: 5,
+            alignment: Alignment::Right,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        Either::<u16, &str>::Left(120)
+            .write_fixed_field(&mut v, &desc)
+            .unwrap();
+        assert_eq!(to_str(v), "  120");
+
+        let mut v = Vec::new();
+        Either::<u16, &str>::Right("DNF")
+            .write_fixed_field(&mut v, &desc)
+            .unwrap();
+        assert_eq!(to_str(v), "  DNF");
+    }
+
+    #[test]
+    fn write_prefixed_number() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 7,
+            alignment: Alignment::Right,
+            prefix: Some("$"),
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        1234u32.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), " $  1234");
+
+        let mut v = Vec::new();
+        12.5f64.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), " $  12.5");
+
+        let desc = FieldDescription { alignment: Alignment::Full, ..desc };
+        let mut v = Vec::new();
+        (-42i16).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), " $-00042");
+
+        // The number must fit in the columns after the prefix
+        let mut v = Vec::new();
+        assert!(1234567u32.write_fixed_field(&mut v, &desc).is_err());
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn write_on_overflow() {
+        let strict = FieldDescription {
+            skip: 0,
+            len: 4,
+            alignment: Alignment::Left,
+            strict: true,
+            on_overflow: Some(Overflow::Truncate),
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = "abcdef".write_fixed_field(&mut v, &strict);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "abcd");
+
+        // Numbers are never cut to a different value
+        let mut v = Vec::new();
+        let res = 123456u32.write_fixed_field(&mut v, &strict);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "****");
+
+        let mut v = Vec::new();
+        let res = (-1.25f32).write_fixed_field(&mut v, &strict);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "-1.2");
+
+        let mut v = Vec::new();
+        let res = 12345.5f64.write_fixed_field(&mut v, &strict);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "****");
+
+        let mut v = Vec::new();
+        let res = 1234.5f64.write_fixed_field(&mut v, &strict);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "1234");
+
+        let lax = FieldDescription {
+            strict: false,
+            on_overflow: Some(Overflow::Error),
+            ..strict
+        };
+
+        let mut v = Vec::new();
+        let res = "abcdef".write_fixed_field(&mut v, &lax);
+        assert!(res.is_err());
+
+        let mut v = Vec::new();
+        let res = 123456u32.write_fixed_field(&mut v, &lax);
+        assert!(res.is_err());
+
+        let mut v = Vec::new();
+        let res = 1.23456f64.write_fixed_field(&mut v, &lax);
+        assert!(res.is_err());
+
+        let mut v = Vec::new();
+        let res = 3.5f64.write_fixed_field(&mut v, &lax);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "3.5 ");
+    }
+
     #[test]
     fn skip_string_left() {
         let desc = FieldDescription {
@@ -319,6 +1563,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -337,6 +1582,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -355,6 +1601,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "foo".to_string();
@@ -373,6 +1620,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -391,6 +1639,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Right,
             strict: false,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -399,7 +1648,42 @@ mod tests {
         let res = foo.write_fixed_field(&mut v, &desc);
 
         assert!(res.is_ok());
-        assert_eq!(to_str(v), " defg");
+        assert_eq!(to_str(v), " abcd");
+    }
+
+    #[test]
+    fn truncate_keep_end() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 4,
+            alignment: Alignment::Right,
+            strict: true,
+            on_overflow: Some(Overflow::KeepEnd),
+            ..FieldDescription::default()
+        };
+
+        // A number held as text keeps its least significant digits
+        let mut v = Vec::new();
+        "1234567".write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "4567");
+
+        // So does a number that opts in
+        let mut v = Vec::new();
+        1234567u32.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "4567");
+
+        let mut v = Vec::new();
+        (-1234567i32).write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "4567");
+
+        let mut v = Vec::new();
+        123.375f64.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), ".375");
+
+        // Numbers that fit are unchanged
+        let mut v = Vec::new();
+        42u32.write_fixed_field(&mut v, &desc).unwrap();
+        assert_eq!(to_str(v), "  42");
     }
 
     #[test]
@@ -409,6 +1693,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Left,
             strict: false,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -427,6 +1712,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -449,6 +1735,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -471,6 +1758,7 @@ mod tests {
             len: 4,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo = "abcdefg".to_string();
@@ -496,6 +1784,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo: u16 = 12345;
@@ -514,6 +1803,7 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo: u16 = 12345;
@@ -532,6 +1822,7 @@ mod tests {
             len: 8,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo: i16 = -12345;
@@ -550,6 +1841,7 @@ mod tests {
             len: 8,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo: i16 = -12345;
@@ -561,6 +1853,104 @@ mod tests {
         assert_eq!(to_str(v), "   -12345");
     }
 
+    #[test]
+    fn write_pad_side() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Right,
+            strict: true,
+            pad_side: Some(PadSide::Right),
+            ..FieldDescription::default()
+        };
+
+        let mut v = Vec::new();
+        let res = 42u16.write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "42   ");
+
+        // truncation is unaffected by the pad side
+        let desc = FieldDescription { strict: false, len: 3, ..desc };
+        let mut v = Vec::new();
+        let res = "abcd".write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "abc");
+
+        let desc = FieldDescription {
+            on_overflow: Some(Overflow::KeepEnd),
+            ..desc
+        };
+        let mut v = Vec::new();
+        let res = "abcd".write_fixed_field(&mut v, &desc);
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "bcd");
+    }
+
+    #[test]
+    fn write_u16_full() {
+        let desc = FieldDescription {
+            skip: 1,
+            len: 5,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let foo: u16 = 42;
+
+        let mut v = Vec::new();
+        let res = foo.write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " 00042");
+    }
+
+    #[test]
+    fn write_i16_full() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 5,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let foo: i16 = -42;
+
+        let mut v = Vec::new();
+        let res = foo.write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "-0042");
+
+        let foo: i16 = 42;
+
+        let mut v = Vec::new();
+        let res = foo.write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "00042");
+    }
+
+    #[test]
+    fn write_i16_full_exact() {
+        let desc = FieldDescription {
+            skip: 0,
+            len: 3,
+            alignment: Alignment::Full,
+            strict: true,
+            ..FieldDescription::default()
+        };
+
+        let foo: i16 = -42;
+
+        let mut v = Vec::new();
+        let res = foo.write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), "-42");
+    }
+
     #[test]
     fn overflow_u16() {
         let desc = FieldDescription {
@@ -568,6 +1958,7 @@ mod tests {
             len: 3,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let foo: u16 = 123;
@@ -602,15 +1993,16 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.14;
+        let foo: f32 = 2.75;
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
 
         assert!(res.is_ok());
-        assert_eq!(to_str(v), " 3.14  ");
+        assert_eq!(to_str(v), " 2.75  ");
     }
 
     #[test]
@@ -620,9 +2012,10 @@ mod tests {
             len: 6,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.141592654;
+        let foo: f32 = std::f32::consts::PI;
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
@@ -638,15 +2031,22 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.14;
+        let foo: f32 = 2.75;
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
 
         assert!(res.is_ok());
-        assert_eq!(to_str(v), " 3.14  ");
+        assert_eq!(to_str(v), " 002.75");
+
+        let mut v = Vec::new();
+        let res = (-2.5f64).write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_ok());
+        assert_eq!(to_str(v), " -002.5");
     }
 
     #[test]
@@ -656,9 +2056,17 @@ mod tests {
             len: 6,
             alignment: Alignment::Full,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.141592654;
+        let foo: f32 = std::f32::consts::PI;
+
+        let mut v = Vec::new();
+        let res = foo.write_fixed_field(&mut v, &desc);
+
+        assert!(res.is_err());
+
+        let desc = FieldDescription { strict: false, ..desc };
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
@@ -674,15 +2082,16 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.14;
+        let foo: f32 = 2.75;
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
 
         assert!(res.is_ok());
-        assert_eq!(to_str(v), "   3.14");
+        assert_eq!(to_str(v), "   2.75");
     }
 
     #[test]
@@ -692,9 +2101,10 @@ mod tests {
             len: 6,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
-        let foo: f32 = 3.141592654;
+        let foo: f32 = std::f32::consts::PI;
 
         let mut v = Vec::new();
         let res = foo.write_fixed_field(&mut v, &desc);
@@ -707,6 +2117,38 @@ mod tests {
     // Long Writes
     ///////////////////////////////////
 
+    /// Records the length of each write
+    #[derive(Default)]
+    struct WriteSizes(Vec<usize>);
+
+    impl Write for WriteSizes {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.push(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_spaces_boundaries() {
+        let cases: [(usize, &[usize]); 5] = [
+            (0, &[]),
+            (255, &[255]),
+            (256, &[256]),
+            (257, &[256, 1]),
+            (512, &[256, 256]),
+        ];
+
+        for (num, expected) in cases {
+            let mut sizes = WriteSizes::default();
+            write_spaces(&mut sizes, num).unwrap();
+            assert_eq!(sizes.0, expected, "writing {} spaces", num);
+        }
+    }
+
     #[test]
     fn write_trailing_over_gap() {
         // 200 spaces, 5 chars, 100 spaces
@@ -715,6 +2157,7 @@ mod tests {
             len: 105,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 12345;
@@ -736,6 +2179,7 @@ mod tests {
             len: 205,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 12345;
@@ -757,6 +2201,7 @@ mod tests {
             len: 310,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 1234567890;
@@ -778,6 +2223,7 @@ mod tests {
             len: 300,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 12345;
@@ -799,6 +2245,7 @@ mod tests {
             len: 1000,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 12345;
@@ -820,6 +2267,7 @@ mod tests {
             len: 1000,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let num: u64 = 12345;
@@ -841,6 +2289,7 @@ mod tests {
             len: 2000,
             alignment: Alignment::Left,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let s = "abcdefghij".repeat(100);
@@ -862,6 +2311,7 @@ mod tests {
             len: 2000,
             alignment: Alignment::Right,
             strict: true,
+            ..FieldDescription::default()
         };
 
         let s = "abcdefghij".repeat(100);
@@ -874,4 +2324,48 @@ mod tests {
         let re = Regex::new(r"^ {2000}(abcdefghij){100}$").unwrap();
         assert!(re.is_match(str::from_utf8(&v).unwrap()));
     }
+
+    #[test]
+    fn write_columns_rows() {
+        let codes = vec!["A1", "B22", "C"];
+        let counts: [u16; 3] = [5, 40, 300];
+        let desc = FieldDescription { len: 3, ..FieldDescription::default() };
+        let count_desc = FieldDescription { skip: 1, ..desc };
+
+        let mut v: Vec<u8> = Vec::new();
+        write_columns(&mut v, &[(&codes, desc), (&counts, count_desc)]).unwrap();
+        assert_eq!(to_str(v), "A1  5  \nB22 40 \nC   300\n");
+
+        // Nothing is written for empty columns
+        let mut v: Vec<u8> = Vec::new();
+        write_columns(&mut v, &[(&Vec::<u8>::new(), desc)]).unwrap();
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn write_columns_errors() {
+        let codes = vec!["A1", "B22"];
+        let counts: Vec<u16> = vec![5, 4000];
+        let desc = FieldDescription::new(0, 3, Alignment::Left, true);
+
+        let mut v: Vec<u8> = Vec::new();
+        let res = write_columns(&mut v, &[(&codes, desc), (&&counts[..1], desc)]);
+        match res {
+            Err(Error::DataError(err)) => {
+                assert_eq!(err.field_index(), Some(1));
+                assert!(err.to_string().contains("Expected 2 rows but found 1"));
+            }
+            _ => panic!("Expected a data error"),
+        }
+
+        let mut v: Vec<u8> = Vec::new();
+        let res = write_columns(&mut v, &[(&codes, desc), (&counts, desc)]);
+        match res {
+            Err(Error::DataError(err)) => {
+                assert_eq!(err.field_index(), Some(1));
+                assert!(err.to_string().contains("Error occured on line 2"));
+            }
+            _ => panic!("Expected a data error"),
+        }
+    }
 }
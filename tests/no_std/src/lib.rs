@@ -0,0 +1,42 @@
+//! A `no_std` crate using the derives available without the `std` feature
+//!
+//! This crate is not a member of the workspace. CI builds it to check that the
+//! code the derives generate compiles without the standard library prelude.
+#![no_std]
+
+use fixcol::error::DataError;
+use fixcol::{FieldDescription, FixcolEnum, FixedDeserializer};
+
+#[derive(Debug, PartialEq, Eq, FixcolEnum)]
+pub enum EyeColor {
+    #[fixcol(value = "Bl")]
+    Blue,
+    #[fixcol(value = "Br")]
+    Brown,
+    #[fixcol(value = "Gr")]
+    Green,
+}
+
+pub fn eye_color(s: &str, desc: &FieldDescription) -> Result<EyeColor, DataError> {
+    EyeColor::parse_fixed(s, desc)
+}
+
+#[derive(Debug, PartialEq, Eq, FixcolEnum)]
+#[fixcol(match_display)]
+pub enum Status {
+    Active,
+    Retired,
+}
+
+impl core::fmt::Display for Status {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Status::Active => write!(f, "ACTIVE"),
+            Status::Retired => write!(f, "RETIRED"),
+        }
+    }
+}
+
+pub fn status(s: &str, desc: &FieldDescription) -> Result<Status, DataError> {
+    Status::parse_fixed(s, desc)
+}
@@ -4,7 +4,7 @@ use fixcol::ReadFixed;
 #[cfg(feature = "experimental-write")]
 use fixcol::{WriteFixed, WriteFixedAll};
 
-const SAMPLE_DATA: &'static str = r#"NODE ME
+const SAMPLE_DATA: &str = r#"NODE ME
 NODE NH
 EDGE ME NH  327819
 NODE VT
@@ -19,9 +19,6 @@ EDGE CT RI     412
 EDGE RI MA 2948120
 "#;
 
-// TODO: Need a test case for unexpected EoF since that's usually a config error
-// not actually an IO error despite being reported as such.
-
 // TODO: "Width must be specified for all fields" should we provid an "until end of line option"?
 
 #[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
@@ -49,7 +46,7 @@ fn edge(from: &str, to: &str, weight: u64) -> GraphObject {
     GraphObject::Edge {
         from: from.to_owned(),
         to: to.to_owned(),
-        weight: weight,
+        weight,
     }
 }
 
@@ -79,6 +76,21 @@ fn read_enums() {
     assert_eq!(graph, expected);
 }
 
+#[test]
+fn read_enum_short_key() {
+    let err = GraphObject::read_fixed_str("").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"\": Line too short to contain a key of width 4\n"
+    );
+
+    let err = GraphObject::read_fixed_str("NO").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"NO\": Line too short to contain a key of width 4\n"
+    );
+}
+
 #[test]
 #[cfg(feature = "experimental-write")]
 fn write_enum() {
@@ -108,3 +120,413 @@ fn write_enum() {
     assert!(res.is_ok());
     assert_eq!(outs, SAMPLE_DATA);
 }
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_enum_grouped() {
+    let inp = vec![
+        node("ME"),
+        edge("ME", "NH", 327819),
+        node("NH"),
+        edge("VT", "NH", 1283),
+        node("VT"),
+    ];
+
+    // Nodes before edges, each in their original order
+    let mut v: Vec<u8> = Vec::new();
+    let res = inp.write_fixed_all_grouped(&mut v, |obj| matches!(obj, GraphObject::Edge { .. }));
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(&v).unwrap(),
+        "NODE ME\nNODE NH\nNODE VT\nEDGE ME NH  327819\nEDGE VT NH    1283\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn enum_serialized_len() {
+    assert_eq!(node("ME").serialized_len().unwrap(), 7);
+    assert_eq!(edge("ME", "NH", 327819).serialized_len().unwrap(), 18);
+
+    // Agrees with the bytes write_fixed actually writes
+    let mut v: Vec<u8> = Vec::new();
+    edge("VT", "NH", 1283).write_fixed(&mut v).unwrap();
+    assert_eq!(edge("VT", "NH", 1283).serialized_len().unwrap(), v.len());
+}
+
+#[test]
+fn enum_total_width() {
+    // EDGE is the widest variant: 4 + 3 + 3 + 8
+    assert_eq!(GraphObject::total_width(), 18);
+
+    let longest = SAMPLE_DATA.lines().map(|line| line.len()).max().unwrap();
+    assert_eq!(GraphObject::total_width(), longest);
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 4)]
+enum Shape {
+    #[fixcol(key = "SQ", label = "Square shape")]
+    Square(#[fixcol(width = 3, align = "right")] u16),
+    #[fixcol(key = "RECT")]
+    Rect(
+        #[fixcol(width = 3, align = "right")] u16,
+        #[fixcol(width = 3, align = "right")] u16,
+    ),
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 3, key_align = "right")]
+enum Code {
+    #[fixcol(key = "A")]
+    Alpha,
+    #[fixcol(key = "BB")]
+    Beta,
+}
+
+#[test]
+fn read_short_keys() {
+    assert_eq!(Shape::read_fixed_str("SQ    5").unwrap(), Shape::Square(5));
+    assert_eq!(
+        Shape::read_fixed_str("RECT  3 12").unwrap(),
+        Shape::Rect(3, 12)
+    );
+    assert_eq!(Code::read_fixed_str("  A").unwrap(), Code::Alpha);
+    assert_eq!(Code::read_fixed_str(" BB").unwrap(), Code::Beta);
+    assert!(Code::read_fixed_str("A  ").is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_short_keys() {
+    let mut v = Vec::new();
+    let res = vec![Shape::Square(5), Shape::Rect(3, 12)].write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "SQ    5\nRECT  3 12\n");
+
+    let mut v = Vec::new();
+    let res = vec![Code::Alpha, Code::Beta].write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "  A\n BB\n");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 1, discriminant = 8)]
+enum Transaction {
+    #[fixcol(key = "D")]
+    Deposit {
+        #[fixcol(width = 8)]
+        account: String,
+        #[fixcol(skip_literal = "D", width = 6, align = "right")]
+        amount: u32,
+    },
+    #[fixcol(key = "W")]
+    Withdrawal(
+        #[fixcol(width = 8)] String,
+        #[fixcol(skip_literal = "W", width = 6, align = "right")] u32,
+    ),
+    #[fixcol(key = "X")]
+    Closed,
+}
+
+#[test]
+fn read_discriminant() {
+    assert_eq!(
+        Transaction::read_fixed_str("ACCT0001D   150").unwrap(),
+        Transaction::Deposit {
+            account: "ACCT0001".to_string(),
+            amount: 150
+        }
+    );
+    assert_eq!(
+        Transaction::read_fixed_str("ACCT0002W    75").unwrap(),
+        Transaction::Withdrawal("ACCT0002".to_string(), 75)
+    );
+    assert_eq!(
+        Transaction::read_fixed_str("        X").unwrap(),
+        Transaction::Closed
+    );
+}
+
+#[test]
+fn read_discriminant_unknown() {
+    let err = Transaction::read_fixed_str("ACCT0001Q   150").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"Q\": Unrecognized enum key\n"
+    );
+}
+
+#[test]
+fn discriminant_total_width() {
+    assert_eq!(Transaction::total_width(), 15);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_discriminant() {
+    let mut v = Vec::new();
+    let records = vec![
+        Transaction::Deposit {
+            account: "ACCT0001".to_string(),
+            amount: 150,
+        },
+        Transaction::Withdrawal("ACCT0002".to_string(), 75),
+        Transaction::Closed,
+    ];
+    let res = records.write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(&v).unwrap(),
+        "ACCT0001D   150\nACCT0002W    75\n        X\n"
+    );
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(strict = true, key_width = 1)]
+enum Record {
+    #[fixcol(key = "H")]
+    Header(#[fixcol(width = 4)] String),
+    #[fixcol(key = "DTL", key_width = 3)]
+    Detail(#[fixcol(width = 3, align = "right")] u16),
+    #[fixcol(key = "D")]
+    Data(#[fixcol(width = 2)] String),
+}
+
+#[test]
+fn read_variant_key_width() {
+    assert_eq!(
+        Record::read_fixed_str("Habcd").unwrap(),
+        Record::Header("abcd".to_string())
+    );
+    assert_eq!(
+        Record::read_fixed_str("DTL 42").unwrap(),
+        Record::Detail(42)
+    );
+    assert_eq!(
+        Record::read_fixed_str("Dab").unwrap(),
+        Record::Data("ab".to_string())
+    );
+
+    let err = Record::read_fixed_str("Xyz").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"Xyz\": Unrecognized enum key\n"
+    );
+}
+
+#[test]
+fn variant_key_width_total_width() {
+    assert_eq!(Record::total_width(), 6);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_variant_key_width() {
+    let mut v = Vec::new();
+    let records = vec![
+        Record::Header("abcd".to_string()),
+        Record::Detail(42),
+        Record::Data("ab".to_string()),
+    ];
+    let res = records.write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "Habcd\nDTL 42\nDab\n");
+}
+
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 2)]
+enum Located {
+    #[fixcol(key = "AT")]
+    At(#[fixcol(at = 5, width = 3)] String),
+}
+
+#[test]
+fn read_variant_at() {
+    // Columns are counted from the start of the line, including the key
+    assert_eq!(
+        Located::read_fixed_str("AT   xyz").unwrap(),
+        Located::At("xyz".to_string())
+    );
+    assert_eq!(Located::total_width(), 8);
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 2)]
+enum Overlapping {
+    #[fixcol(key = "RS")]
+    Restated(
+        #[fixcol(width = 4)] String,
+        #[fixcol(at = 4, width = 2, overlap)] u8,
+        #[fixcol(width = 2)] String,
+    ),
+}
+
+#[test]
+fn read_variant_overlap() {
+    // The overlapping field does not move the fields that follow it
+    assert_eq!(
+        Overlapping::read_fixed_str("RSAB12XY").unwrap(),
+        Overlapping::Restated("AB12".to_string(), 12, "XY".to_string())
+    );
+    assert_eq!(Overlapping::total_width(), 8);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_variant_overlap() {
+    let record = Overlapping::Restated("AB12".to_string(), 0, "XY".to_string());
+
+    let mut v = Vec::new();
+    let res = record.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "RSAB12XY");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 4)]
+enum Passthrough {
+    #[fixcol(key = "NODE")]
+    Node(#[fixcol(skip = 1, width = 2)] String),
+    #[fixcol(catch_all)]
+    Other(fixcol::RawLine),
+}
+
+#[test]
+fn read_catch_all() {
+    let buf = "NODE ME\nEDGE ME NH  327819\nNODE NH\n".as_bytes();
+    let records: Vec<Passthrough> = Passthrough::read_fixed_all(buf)
+        .map(|res| res.unwrap())
+        .collect();
+
+    assert_eq!(
+        records,
+        vec![
+            Passthrough::Node("ME".to_string()),
+            Passthrough::Other(fixcol::RawLine("EDGE ME NH  327819".to_string())),
+            Passthrough::Node("NH".to_string()),
+        ]
+    );
+    assert_eq!(Passthrough::total_width(), 7);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_catch_all() {
+    let text = "NODE ME\nEDGE ME NH  327819\nNODE NH\n";
+    let mut records: Vec<Passthrough> = Passthrough::read_fixed_all(text.as_bytes())
+        .map(|res| res.unwrap())
+        .collect();
+    records[0] = Passthrough::Node("VT".to_string());
+
+    let mut v = Vec::new();
+    let res = records.write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(&v).unwrap(),
+        "NODE VT\nEDGE ME NH  327819\nNODE NH\n"
+    );
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 1, discriminant = 4)]
+enum Entry {
+    #[fixcol(key = "A")]
+    Active(#[fixcol(width = 4)] String),
+    #[fixcol(catch_all)]
+    Other(fixcol::RawLine),
+}
+
+#[test]
+fn read_catch_all_discriminant() {
+    assert_eq!(
+        Entry::read_fixed_str("0001A").unwrap(),
+        Entry::Active("0001".to_string())
+    );
+    assert_eq!(
+        Entry::read_fixed_str("0002Z extra").unwrap(),
+        Entry::Other(fixcol::RawLine("0002Z extra".to_string()))
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_catch_all_discriminant() {
+    let mut v = Vec::new();
+    let records = vec![
+        Entry::Active("0001".to_string()),
+        Entry::Other(fixcol::RawLine("0002Z extra".to_string())),
+    ];
+    let res = records.write_fixed_all(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "0001A\n0002Z extra\n");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(key_width = 1)]
+enum Mixed {
+    #[fixcol(key = "HDR", key_width = 3)]
+    Header,
+    #[fixcol(key = "D")]
+    Data(#[fixcol(width = 2)] String),
+    #[fixcol(catch_all)]
+    Other(fixcol::RawLine),
+}
+
+#[test]
+fn read_catch_all_variant_key_width() {
+    assert_eq!(Mixed::read_fixed_str("HDR").unwrap(), Mixed::Header);
+    assert_eq!(
+        Mixed::read_fixed_str("Dab").unwrap(),
+        Mixed::Data("ab".to_string())
+    );
+    assert_eq!(
+        Mixed::read_fixed_str("Xyz123").unwrap(),
+        Mixed::Other(fixcol::RawLine("Xyz123".to_string()))
+    );
+}
+
+#[test]
+fn variant_labels() {
+    // Labels default to the variant name and keys keep their padding
+    assert_eq!(
+        Shape::variant_labels(),
+        &[("SQ  ", "Square shape"), ("RECT", "Rect")]
+    );
+    assert_eq!(Shape::Square(5).variant_label(), "Square shape");
+    assert_eq!(Shape::Rect(3, 12).variant_label(), "Rect");
+
+    // The catch-all variant has a label but no key
+    assert_eq!(Passthrough::variant_labels(), &[("NODE", "Node")]);
+    let other = Passthrough::Other(fixcol::RawLine("EDGE".to_string()));
+    assert_eq!(other.variant_label(), "Other");
+}
+
+#[test]
+fn try_read_mixed_types() {
+    let read = |line: &str| -> Option<String> {
+        if let Some(code) = Code::try_read_fixed_str(line).unwrap() {
+            return Some(format!("{:?}", code));
+        }
+        Shape::try_read_fixed_str(line)
+            .unwrap()
+            .map(|shape| format!("{:?}", shape))
+    };
+
+    assert_eq!(read("SQ    5"), Some(String::from("Square(5)")));
+    assert_eq!(read("  A"), Some(String::from("Alpha")));
+    assert_eq!(read("ZZZZ"), None);
+
+    // A matching key with a malformed record is an error, not a mismatch
+    assert!(Shape::try_read_fixed_str("SQ   xx").is_err());
+}
@@ -1,6 +1,6 @@
 extern crate fixcol;
 
-use fixcol::ReadFixed;
+use fixcol::{Alignment, FieldDescription, FixedDeserializer, ReadFixed};
 #[cfg(feature = "experimental-write")]
 use fixcol::{WriteFixed, WriteFixedAll};
 
@@ -61,7 +61,7 @@ fn molecule_data() -> Vec<MoleculeRow> {
     ]
 }
 
-const SAMPLE_TEXT: &'static str = r#"Mol 0    Water   
+const SAMPLE_TEXT: &str = r#"Mol 0    Water   
 Atm    0    0 Hydrogen
 Atm    1    0 Hydrogen
 Atm    2    0 Oxygen  
@@ -85,6 +85,15 @@ fn read_inner() {
     );
 }
 
+#[test]
+fn read_inner_short_field() {
+    let desc = FieldDescription::new(0, 19, Alignment::Left, true);
+    let err = AtomS::parse_fixed("    0    0 Hydro", &desc).unwrap_err();
+
+    assert_eq!(err.expected_width(), Some(19));
+    assert_eq!(err.actual_width(), Some(16));
+}
+
 #[test]
 fn read_nested() {
     let mut buf = SAMPLE_TEXT.as_bytes();
@@ -107,3 +116,134 @@ fn write_nested() {
     let text = std::str::from_utf8(v.as_slice()).unwrap();
     assert_eq!(text, SAMPLE_TEXT);
 }
+
+// Two level keys: the outer enum dispatches on a three character record type
+// and the embedded enum dispatches on a two character subtype that follows.
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, Eq, ReadFixed)]
+#[fixcol(key_width = 2)]
+enum Message {
+    #[fixcol(key = "TX")]
+    Transmit {
+        #[fixcol(skip = 1, width = 4)]
+        to: String,
+        #[fixcol(skip = 1, width = 5, align = "right")]
+        size: u16,
+    },
+    #[fixcol(key = "RX")]
+    Receive {
+        #[fixcol(skip = 1, width = 4)]
+        from: String,
+    },
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, Eq, ReadFixed)]
+#[fixcol(key_width = 3)]
+enum LogRow {
+    #[fixcol(key = "MSG", embed = true)]
+    Message(Message),
+    #[fixcol(key = "CLK")]
+    Clock(#[fixcol(skip = 1, width = 6, align = "right")] u32),
+}
+
+const LOG_TEXT: &str = r#"CLK 123456
+MSGTX ALFA   512
+MSGRX BRVO
+MSGTX CHRL     7
+CLK 123999
+"#;
+
+fn log_data() -> Vec<LogRow> {
+    vec![
+        LogRow::Clock(123456),
+        LogRow::Message(Message::Transmit { to: "ALFA".to_owned(), size: 512 }),
+        LogRow::Message(Message::Receive { from: "BRVO".to_owned() }),
+        LogRow::Message(Message::Transmit { to: "CHRL".to_owned(), size: 7 }),
+        LogRow::Clock(123999),
+    ]
+}
+
+#[test]
+fn read_two_level_key() {
+    let actual: Vec<LogRow> = LogRow::read_fixed_all(LOG_TEXT.as_bytes())
+        .map(|o| o.unwrap())
+        .collect();
+
+    assert_eq!(actual, log_data());
+}
+
+#[test]
+fn read_two_level_unknown_subkey() {
+    let err = LogRow::read_fixed_str("MSGZZ ALFA   512").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"ZZ\": Unrecognized enum key\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_two_level_key() {
+    let mut v = Vec::new();
+    let res = log_data().write_fixed_all(&mut v);
+
+    assert!(res.is_ok());
+
+    let text = std::str::from_utf8(v.as_slice()).unwrap();
+    assert_eq!(text, LOG_TEXT);
+}
+
+// A single field tuple struct without a fixcol attribute delegates to the
+// inner type, which allows adding methods around a parsed type.
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, Eq, ReadFixed)]
+struct Atom(AtomS);
+
+impl Atom {
+    fn label(&self) -> String {
+        format!("{}-{}", self.0.molecule, self.0.name)
+    }
+}
+
+const ATOM_TEXT: &str = "    1    2 Hydrogen";
+
+fn atom() -> Atom {
+    Atom(AtomS {
+        id: 1,
+        molecule: 2,
+        name: "Hydrogen".to_string(),
+    })
+}
+
+#[test]
+fn read_newtype() {
+    let actual = Atom::read_fixed_str(ATOM_TEXT).unwrap();
+    assert_eq!(actual, atom());
+    assert_eq!(actual.label(), "2-Hydrogen");
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_newtype() {
+    let mut v = Vec::new();
+    let res = atom().write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), ATOM_TEXT);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_newtype_header() {
+    let mut v = Vec::new();
+    let res = Atom::write_fixed_header(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "id   molec name    "
+    );
+}
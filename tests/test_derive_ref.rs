@@ -0,0 +1,103 @@
+extern crate fixcol;
+
+use fixcol::{ReadFixed, ReadFixedRef};
+
+#[derive(Debug, PartialEq, Eq, ReadFixedRef)]
+#[fixcol(strict = true)]
+struct Planet<'a> {
+    #[fixcol(width = 8)]
+    name: &'a str,
+    #[fixcol(skip = 1, width = 3, align = "right")]
+    moons: u16,
+    #[fixcol(skip = 1, width = 6, align = "right")]
+    kind: &'a str,
+}
+
+const PLANETS: &str = "Mercury    0  rocky\nJupiter   95    gas\r\nNeptune   16    ice\n";
+
+#[test]
+fn read_borrowed() {
+    let planets: Vec<Planet> = Planet::read_fixed_all_ref(PLANETS)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(
+        planets,
+        vec![
+            Planet { name: "Mercury", moons: 0, kind: "rocky" },
+            Planet { name: "Jupiter", moons: 95, kind: "gas" },
+            Planet { name: "Neptune", moons: 16, kind: "ice" },
+        ]
+    );
+
+    // The text fields point into the buffer
+    let range = PLANETS.as_bytes().as_ptr_range();
+    assert!(range.contains(&planets[1].name.as_ptr()));
+}
+
+#[test]
+fn read_borrowed_errors() {
+    let text = "Mercury    0  rocky\nVenus      x  rocky\nEarth      1\n";
+    let mut planets = Planet::read_fixed_all_ref(text);
+
+    assert!(planets.next().unwrap().is_ok());
+
+    // Errors report the line and reading continues
+    let err = planets.next().unwrap().unwrap_err();
+    assert!(err.to_string().ends_with("Error occured on line 2\n"));
+    match err {
+        fixcol::error::Error::DataError(err) => assert_eq!(err.span(), Some((9, 3))),
+        _ => panic!("Expected a data error"),
+    }
+
+    let err = planets.next().unwrap().unwrap_err();
+    match err {
+        fixcol::error::Error::DataError(err) => assert_eq!(err.field_name(), Some("kind")),
+        _ => panic!("Expected a data error"),
+    }
+
+    assert!(planets.next().is_none());
+}
+
+#[derive(Debug, PartialEq, ReadFixed, ReadFixedRef)]
+#[fixcol(strict = false)]
+struct Reading(
+    #[fixcol(width = 4)] String,
+    #[fixcol(width = 5, align = "right")] f32,
+);
+
+#[test]
+fn read_owned_fields() {
+    // A record without a lifetime reads the same as with ReadFixed
+    let line = "T01  2.5";
+    assert_eq!(
+        Reading::read_fixed_ref(line).unwrap(),
+        Reading::read_fixed_str(line).unwrap()
+    );
+
+    // The last field of a lax record may be cut short
+    assert_eq!(
+        Reading::read_fixed_ref("T02  7").unwrap(),
+        Reading(String::from("T02"), 7.0)
+    );
+
+    // Or missing altogether, reading a number as zero
+    assert_eq!(
+        Reading::read_fixed_ref("T03 ").unwrap(),
+        Reading(String::from("T03"), 0.0)
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, ReadFixedRef)]
+struct Code<'a> {
+    #[fixcol(width = 6)]
+    code: &'a str,
+    #[fixcol(at = 0, width = 2, overlap)]
+    region: &'a str,
+}
+
+#[test]
+fn read_borrowed_overlap() {
+    let code = Code::read_fixed_ref("EU1234").unwrap();
+    assert_eq!(code, Code { code: "EU1234", region: "EU" });
+}
@@ -36,3 +36,779 @@ fn derive_write_struct() {
         std::str::from_utf8("42                 3".as_bytes()).unwrap()
     );
 }
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Banner {
+    #[fixcol(width = 8, keep_trailing = true)]
+    text: String,
+    #[fixcol(width = 8)]
+    trimmed: String,
+}
+
+#[test]
+fn derive_read_keep_trailing() {
+    let banner = Banner::read_fixed_str("ab cd   ab cd   ").unwrap();
+    assert_eq!(
+        banner,
+        Banner {
+            text: String::from("ab cd   "),
+            trimmed: String::from("ab cd"),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_keep_trailing() {
+    let banner = Banner {
+        text: String::from("ab cd   "),
+        trimmed: String::from("ab cd"),
+    };
+
+    let mut v = Vec::new();
+    let res = banner.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "ab cd   ab cd   "
+    );
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Padded {
+    #[fixcol(width = 3, align = "right")]
+    id: u16,
+    #[fixcol(width = 5)]
+    _pad: (),
+    #[fixcol(width = 4)]
+    code: String,
+}
+
+#[test]
+fn derive_read_unit_field() {
+    let padded = Padded::read_fixed_str(" 42xxxxxABCD").unwrap();
+    assert_eq!(
+        padded,
+        Padded {
+            id: 42,
+            _pad: (),
+            code: String::from("ABCD"),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_unit_field() {
+    let padded = Padded {
+        id: 42,
+        _pad: (),
+        code: String::from("ABCD"),
+    };
+
+    let mut v = Vec::new();
+    let res = padded.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), " 42     ABCD");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(strict = true)]
+struct Piped {
+    #[fixcol(width = 3, align = "right")]
+    id: u16,
+    #[fixcol(width = 4, skip_literal = "|")]
+    code: String,
+    #[fixcol(width = 3, skip = 1, skip_literal = "|", align = "right")]
+    count: u8,
+}
+
+#[test]
+fn derive_read_skip_literal() {
+    let piped = Piped::read_fixed_str(" 42|ABCD|  7").unwrap();
+    assert_eq!(
+        piped,
+        Piped {
+            id: 42,
+            code: String::from("ABCD"),
+            count: 7,
+        }
+    );
+
+    let res = Piped::read_fixed_str(" 42 ABCD|  7");
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_skip_literal() {
+    let piped = Piped {
+        id: 42,
+        code: String::from("ABCD"),
+        count: 7,
+    };
+
+    let mut v = Vec::new();
+    let res = piped.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), " 42|ABCD|  7");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Trailer {
+    #[fixcol(width = 3)]
+    kind: String,
+    #[fixcol(width = 4, from_end = true, align = "right")]
+    count: u16,
+    #[fixcol(width = 3, skip = 1, from_end = true)]
+    code: String,
+}
+
+#[test]
+fn derive_read_from_end() {
+    let expected = Trailer {
+        kind: String::from("HDR"),
+        count: 12,
+        code: String::from("XYZ"),
+    };
+
+    let trailer = Trailer::read_fixed_str("HDR  12XYZ ").unwrap();
+    assert_eq!(trailer, expected);
+
+    // The preamble between the fields may vary in length
+    let trailer = Trailer::read_fixed_str("HDR some variable text  12XYZ ").unwrap();
+    assert_eq!(trailer, expected);
+}
+
+#[test]
+fn derive_read_from_end_short() {
+    let res = Trailer::read_fixed_str("HDR  ");
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_from_end() {
+    let trailer = Trailer {
+        kind: String::from("HDR"),
+        count: 12,
+        code: String::from("XYZ"),
+    };
+
+    let mut v = Vec::new();
+    let res = trailer.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "HDR  12XYZ ");
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_header() {
+    use fixcol::{FixedWriter, WriteFixedAll};
+
+    let mut v = Vec::new();
+    Trailer::write_fixed_header(&mut v).unwrap();
+
+    // Names are truncated to the field width
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "kincouncod ");
+
+    let trailers = vec![Trailer {
+        kind: String::from("HDR"),
+        count: 12,
+        code: String::from("XYZ"),
+    }];
+
+    let mut v = Vec::new();
+    trailers.write_fixed_all_with_header(&mut v).unwrap();
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "kincouncod \nHDR  12XYZ \n"
+    );
+
+    let mut v = Vec::new();
+    let mut writer = FixedWriter::new(&mut v);
+    writer.write_header::<Trailer>().unwrap();
+    writer.finish().unwrap();
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "kincouncod \n");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Filled {
+    #[fixcol(width = 6, align = "right", pad = "_")]
+    right: String,
+    #[fixcol(width = 6, pad = "*")]
+    left: String,
+}
+
+#[test]
+fn derive_read_pad() {
+    let filled = Filled::read_fixed_str("__ a bx y***").unwrap();
+    assert_eq!(
+        filled,
+        Filled {
+            right: String::from(" a b"),
+            left: String::from("x y"),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_pad() {
+    let filled = Filled {
+        right: String::from(" a b"),
+        left: String::from("x y"),
+    };
+
+    let mut v = Vec::new();
+    let res = filled.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "__ a bx y***");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Dotted {
+    #[fixcol(width = 5, align = "right", pad = '.')]
+    code: String,
+}
+
+#[test]
+fn derive_read_pad_char() {
+    let dotted = Dotted::read_fixed_str("..abc").unwrap();
+    assert_eq!(dotted, Dotted { code: String::from("abc") });
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_pad_char() {
+    let dotted = Dotted { code: String::from("abc") };
+
+    let mut v = Vec::new();
+    let res = dotted.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "..abc");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Report {
+    #[fixcol(width = 6, align = "right", pad_side = "right")]
+    amount: u32,
+    #[fixcol(width = 4)]
+    code: String,
+}
+
+#[test]
+fn derive_read_pad_side() {
+    let report = Report::read_fixed_str("42    ABCD").unwrap();
+    assert_eq!(report, Report { amount: 42, code: String::from("ABCD") });
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_pad_side() {
+    let report = Report { amount: 42, code: String::from("ABCD") };
+
+    let mut v = Vec::new();
+    let res = report.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "42    ABCD");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, PartialEq)]
+struct Ledger {
+    #[fixcol(width = 6)]
+    account: String,
+    #[fixcol(width = 8, align = "right", negatives = "parens")]
+    balance: i64,
+    #[fixcol(width = 8, align = "right", negatives = "parens")]
+    rate: f64,
+}
+
+#[test]
+fn derive_read_negative_parens() {
+    let ledger = Ledger::read_fixed_str("CASH    (1234)  (0.25)").unwrap();
+    assert_eq!(
+        ledger,
+        Ledger {
+            account: String::from("CASH"),
+            balance: -1234,
+            rate: -0.25,
+        }
+    );
+
+    let res = Ledger::read_fixed_str("CASH    (1234   (0.25)");
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_negative_parens() {
+    let ledger = Ledger {
+        account: String::from("CASH"),
+        balance: -1234,
+        rate: 0.25,
+    };
+
+    let mut v = Vec::new();
+    let res = ledger.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "CASH    (1234)    0.25"
+    );
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(strict = true)]
+struct Adjacency {
+    #[fixcol(width = 4)]
+    node: String,
+    #[fixcol(width = 4, skip = 1, repeat = "rest", align = "right")]
+    edges: Vec<u16>,
+}
+
+#[test]
+fn derive_read_repeat_rest() {
+    let adj = Adjacency::read_fixed_str("NODE    1  22 333").unwrap();
+    assert_eq!(
+        adj,
+        Adjacency {
+            node: String::from("NODE"),
+            edges: vec![1, 22, 333],
+        }
+    );
+
+    let adj = Adjacency::read_fixed_str("NODE ").unwrap();
+    assert_eq!(adj.edges, Vec::<u16>::new());
+
+    // strict mode requires a whole number of items
+    let res = Adjacency::read_fixed_str("NODE    1  2");
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_repeat_rest() {
+    let adj = Adjacency {
+        node: String::from("NODE"),
+        edges: vec![1, 22, 333],
+    };
+
+    let mut v = Vec::new();
+    let res = adj.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "NODE    1  22 333"
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, ReadFixed)]
+struct Memo {
+    #[fixcol(width = 3)]
+    id: u16,
+    #[fixcol(width = 10000)]
+    text: String,
+}
+
+#[test]
+fn derive_read_wide_field() {
+    let line = format!("42 {:<10000}", "hello");
+    let memo = Memo::read_fixed_str(&line).unwrap();
+
+    assert_eq!(memo, Memo { id: 42, text: String::from("hello") });
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Sparse {
+    #[fixcol(width = 4)]
+    id: u16,
+    #[fixcol(at = 10, width = 3)]
+    code: String,
+    #[fixcol(skip = 1, width = 2)]
+    n: u8,
+}
+
+#[test]
+fn derive_read_at() {
+    let sparse = Sparse::read_fixed_str("12        ABC 42").unwrap();
+    assert_eq!(sparse, Sparse { id: 12, code: String::from("ABC"), n: 42 });
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_at() {
+    let sparse = Sparse { id: 12, code: String::from("ABC"), n: 42 };
+
+    let mut v = Vec::new();
+    let res = sparse.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "12        ABC 42"
+    );
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Restated {
+    #[fixcol(width = 6)]
+    account: String,
+    #[fixcol(width = 3, align = "right")]
+    amount: u16,
+    // The branch is the last two digits of the account
+    #[fixcol(at = 4, width = 2, overlap = true)]
+    branch: u8,
+}
+
+#[test]
+fn derive_read_overlap() {
+    let restated = Restated::read_fixed_str("ACCT42 17").unwrap();
+    assert_eq!(
+        restated,
+        Restated {
+            account: String::from("ACCT42"),
+            amount: 17,
+            branch: 42
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_overlap() {
+    let restated = Restated {
+        account: String::from("ACCT42"),
+        amount: 17,
+        branch: 0,
+    };
+
+    // The overlapping field is not written, its columns hold the account
+    let mut v = Vec::new();
+    let res = restated.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "ACCT42 17");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, PartialEq)]
+struct Rate {
+    #[fixcol(width = 4)]
+    code: String,
+    #[fixcol(width = 7, align = "right", scale = 2)]
+    amount: f64,
+    #[fixcol(skip = 1, width = 5, align = "full", scale = 2)]
+    rate: fixcol::Percent,
+}
+
+#[test]
+fn derive_read_scale() {
+    let rate = Rate::read_fixed_str("PRME  12050 07550").unwrap();
+    assert_eq!(
+        rate,
+        Rate {
+            code: String::from("PRME"),
+            amount: 120.5,
+            rate: fixcol::Percent(0.755),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_scale() {
+    let rate = Rate {
+        code: String::from("PRME"),
+        amount: 120.5,
+        rate: fixcol::Percent(0.755),
+    };
+
+    let mut v = Vec::new();
+    let res = rate.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(v.as_slice()).unwrap(),
+        "PRME  12050 07550"
+    );
+}
+
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(strict = true)]
+struct Optional {
+    #[fixcol(width = 4)]
+    id: String,
+    #[fixcol(skip = 1, width = 3, align = "right")]
+    qty: Option<u16>,
+    #[fixcol(skip = 1, width = 3)]
+    note: Option<String>,
+}
+
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+#[fixcol(strict = false)]
+struct OptionalLax {
+    #[fixcol(width = 4)]
+    id: String,
+    #[fixcol(skip = 1, width = 3, align = "right")]
+    qty: Option<u16>,
+    #[fixcol(skip = 1, width = 3)]
+    note: Option<String>,
+}
+
+#[test]
+fn derive_read_missing_options() {
+    let full = Optional::read_fixed_str("ABCD  12 xyz").unwrap();
+    assert_eq!(full.qty, Some(12));
+    assert_eq!(full.note, Some(String::from("xyz")));
+
+    // Trailing Option fields missing from a short line are None
+    let short = Optional::read_fixed_str("ABCD  12").unwrap();
+    assert_eq!(short.qty, Some(12));
+    assert_eq!(short.note, None);
+
+    let short = Optional::read_fixed_str("ABCD").unwrap();
+    assert_eq!(short.qty, None);
+    assert_eq!(short.note, None);
+
+    // A field that is only partly present is still an error
+    assert!(Optional::read_fixed_str("ABCD  1").is_err());
+    assert!(Optional::read_fixed_str("ABCD  12 xy").is_err());
+}
+
+#[test]
+fn derive_read_missing_options_lax() {
+    let short = OptionalLax::read_fixed_str("ABCD  12 xy").unwrap();
+    assert_eq!(short.note, Some(String::from("xy")));
+
+    let short = OptionalLax::read_fixed_str("ABCD  12 ").unwrap();
+    assert_eq!(short.note, None);
+
+    let short = OptionalLax::read_fixed_str("ABCD").unwrap();
+    assert_eq!(
+        short,
+        OptionalLax {
+            id: String::from("ABCD"),
+            qty: None,
+            note: None
+        }
+    );
+
+    // Only the last field may be partly present
+    assert!(OptionalLax::read_fixed_str("ABCD  1").is_err());
+}
+
+#[cfg(feature = "experimental-write")]
+#[derive(WriteFixed)]
+struct Blob {
+    #[fixcol(width = 2)]
+    kind: String,
+    #[fixcol(skip = 1, width = 4)]
+    data: Box<[u8]>,
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn derive_write_bytes() {
+    let blob = Blob {
+        kind: String::from("BN"),
+        data: Box::from(&[0x01, 0xFE][..]),
+    };
+
+    let mut v = Vec::new();
+    let res = blob.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(v, vec![b'B', b'N', b' ', 0x01, 0xFE, b' ', b' ']);
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, PartialEq)]
+#[fixcol(strict = true)]
+struct Invoice {
+    #[fixcol(width = 4)]
+    id: String,
+    #[fixcol(skip = 1, width = 8, prefix = "$", align = "right")]
+    total: f64,
+    #[fixcol(skip = 1, width = 5, prefix = "#", align = "left")]
+    count: u16,
+}
+
+#[test]
+fn read_prefixed_numbers() {
+    let invoice = Invoice::read_fixed_str("A001 $  12.75 #3   ").unwrap();
+    assert_eq!(
+        invoice,
+        Invoice {
+            id: String::from("A001"),
+            total: 12.75,
+            count: 3
+        }
+    );
+
+    assert!(Invoice::read_fixed_str("A001    12.75 #3   ").is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_prefixed_numbers() {
+    let invoice = Invoice {
+        id: String::from("A002"),
+        total: 1500.0,
+        count: 12,
+    };
+
+    let mut v = Vec::new();
+    let res = invoice.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "A002 $   1500 #12  ");
+}
+
+#[derive(Debug, ReadFixed, PartialEq, Eq)]
+#[fixcol(from_fields)]
+struct Release {
+    #[fixcol(width = 10)]
+    artist: String,
+    #[fixcol(skip = 1, width = 4, align = "right")]
+    year: u16,
+    #[fixcol(skip = 1, width = 2, align = "right")]
+    month: u8,
+    #[fixcol(skip = 1, width = 2, align = "right")]
+    day: u8,
+}
+
+#[test]
+fn read_from_fields() {
+    let release = Release::from_fields(&["George", "1989", "3", "12"]).unwrap();
+    assert_eq!(
+        release,
+        Release {
+            artist: String::from("George"),
+            year: 1989,
+            month: 3,
+            day: 12
+        }
+    );
+
+    // The same record read from a line
+    let line = Release::read_fixed_str("George     1989  3 12").unwrap();
+    assert_eq!(line, release);
+
+    let err = Release::from_fields(&["George", "1989", "3"]).unwrap_err();
+    assert!(err.to_string().contains("Expected 4 fields but found 3"));
+
+    let err = Release::from_fields(&["George", "1989", "x", "12"]).unwrap_err();
+    match err {
+        fixcol::error::Error::DataError(err) => assert_eq!(err.field_name(), Some("month")),
+        _ => panic!("Expected a data error"),
+    }
+}
+
+#[derive(Debug, ReadFixed, PartialEq, Eq)]
+#[fixcol(delimiter = '|')]
+struct Track {
+    #[fixcol(width = 10)]
+    title: String,
+    #[fixcol(width = 3, align = "right")]
+    seconds: u16,
+    #[fixcol(width = 1)]
+    explicit: Option<String>,
+}
+
+#[test]
+fn read_delimited() {
+    let mut buf = "Wildfire|245|\nSpeak|98|E\n".as_bytes();
+    let tracks: Vec<Track> = Track::read_fixed_all(&mut buf)
+        .map(|track| track.unwrap())
+        .collect();
+    assert_eq!(
+        tracks,
+        vec![
+            Track {
+                title: String::from("Wildfire"),
+                seconds: 245,
+                explicit: None,
+            },
+            Track {
+                title: String::from("Speak"),
+                seconds: 98,
+                explicit: Some(String::from("E")),
+            },
+        ]
+    );
+
+    // Values are not limited to the width of their field
+    let track = Track::read_fixed_str("A much longer title|1200|").unwrap();
+    assert_eq!(track.title, "A much longer title");
+    assert_eq!(track.seconds, 1200);
+
+    let err = Track::read_fixed_str("Wildfire|245").unwrap_err();
+    assert!(err.to_string().contains("Expected 3 fields but found 2"));
+
+    let err = Track::read_fixed_str("Wildfire|4:05|").unwrap_err();
+    match err {
+        fixcol::error::Error::DataError(err) => assert_eq!(err.field_name(), Some("seconds")),
+        _ => panic!("Expected a data error"),
+    }
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, PartialEq, Eq)]
+struct Account {
+    #[fixcol(width = 5)]
+    user: String,
+    #[fixcol(skip = 1, width = 4, flags = "X ")]
+    permissions: fixcol::Flags,
+    #[fixcol(skip = 1, width = 2)]
+    status: fixcol::Flags,
+}
+
+#[test]
+fn read_flags() {
+    let account = Account::read_fixed_str("alice X  X NY").unwrap();
+    assert_eq!(
+        account,
+        Account {
+            user: String::from("alice"),
+            permissions: fixcol::Flags(0b1001),
+            status: fixcol::Flags(0b10),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_flags() {
+    let account = Account {
+        user: String::from("bob"),
+        permissions: fixcol::Flags(0b0110),
+        status: fixcol::Flags(0b01),
+    };
+
+    let mut v = Vec::new();
+    let res = account.write_fixed(&mut v);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&v).unwrap(), "bob    XX  YN");
+}
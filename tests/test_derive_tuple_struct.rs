@@ -31,3 +31,14 @@ fn derive_write() {
     let s = std::str::from_utf8(buf.as_slice()).unwrap();
     assert_eq!(s, "  0 128  42");
 }
+
+#[cfg(feature = "experimental-write")]
+#[test]
+fn derive_write_header() {
+    let mut buf = Vec::new();
+    Color::write_fixed_header(&mut buf).unwrap();
+
+    // Tuple struct fields are unnamed, so the header is blank
+    let s = std::str::from_utf8(buf.as_slice()).unwrap();
+    assert_eq!(s, "           ");
+}
@@ -1,8 +1,8 @@
 extern crate fixcol;
 
-use fixcol::ReadFixed;
 #[cfg(feature = "experimental-write")]
 use fixcol::WriteFixed;
+use fixcol::{FixcolEnum, ReadFixed};
 
 #[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
 #[derive(Debug, Eq, PartialEq, ReadFixed)]
@@ -21,7 +21,7 @@ enum Color {
 struct Light {
     #[fixcol(width = 8)]
     name: String,
-    #[fixcol(width = 1)] // TODO: this is redundant -- find a way to inherit
+    #[fixcol(width = 1)]
     color: Color,
     #[fixcol(width = 3, align = "right")]
     pos_x: u8,
@@ -66,3 +66,157 @@ fn derive_write() {
 
     assert_eq!(actual, expected);
 }
+
+// The width of a keyed enum field is inferred from its key width
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Lamp {
+    #[fixcol(width = 4)]
+    name: String,
+    color: Color,
+    #[fixcol(width = 2, align = "right")]
+    watts: u8,
+}
+
+#[test]
+fn derive_read_inferred_width() {
+    let lamp = Lamp::read_fixed_str("DeskG40").unwrap();
+    assert_eq!(
+        lamp,
+        Lamp {
+            name: String::from("Desk"),
+            color: Color::Green,
+            watts: 40,
+        }
+    );
+}
+
+#[cfg(feature = "experimental-write")]
+#[test]
+fn derive_write_inferred_width() {
+    let lamp = Lamp {
+        name: String::from("Hall"),
+        color: Color::Red,
+        watts: 7,
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    let res = lamp.write_fixed(&mut buf);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "HallR 7");
+}
+
+#[derive(Debug, Eq, PartialEq, FixcolEnum)]
+enum EyeColor {
+    #[fixcol(value = "Bl")]
+    Blue,
+    #[fixcol(value = "Br")]
+    Brown,
+    #[fixcol(value = "G")]
+    Green,
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Person {
+    #[fixcol(width = 6)]
+    name: String,
+    #[fixcol(width = 2)]
+    eye_color: EyeColor,
+    #[fixcol(width = 3, align = "right")]
+    age: u8,
+}
+
+#[test]
+fn fixcol_enum_read() {
+    let person = Person::read_fixed_str("HaroldBr 42").unwrap();
+    assert_eq!(person.eye_color, EyeColor::Brown);
+
+    // Values shorter than the column are padded according to the alignment
+    let person = Person::read_fixed_str("ClaireG   7").unwrap();
+    assert_eq!(person.eye_color, EyeColor::Green);
+
+    let err = Person::read_fixed_str("GeorgeRd 30").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"Rd\": Unrecognized value. \
+        Expected one of \"Bl\", \"Br\", \"G\"\n\
+        Error occured in field 1 (`eye_color`)\n"
+    );
+}
+
+#[cfg(feature = "experimental-write")]
+#[test]
+fn fixcol_enum_write() {
+    let person = Person {
+        name: String::from("Claire"),
+        eye_color: EyeColor::Green,
+        age: 7,
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    let res = person.write_fixed(&mut buf);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "ClaireG   7");
+}
+
+#[derive(Debug, Eq, PartialEq, FixcolEnum)]
+#[fixcol(match_display)]
+enum Grade {
+    Senior,
+    Junior,
+    Intern,
+}
+
+impl std::fmt::Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Grade::Senior => write!(f, "SENIOR"),
+            Grade::Junior => write!(f, "JUNIOR"),
+            Grade::Intern => write!(f, "INT"),
+        }
+    }
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, ReadFixed, Eq, PartialEq)]
+struct Employee {
+    #[fixcol(width = 6)]
+    name: String,
+    #[fixcol(width = 8)]
+    grade: Grade,
+}
+
+#[test]
+fn match_display_read() {
+    let employee = Employee::read_fixed_str("HaroldSENIOR  ").unwrap();
+    assert_eq!(employee.grade, Grade::Senior);
+
+    let employee = Employee::read_fixed_str("ClaireINT     ").unwrap();
+    assert_eq!(employee.grade, Grade::Intern);
+
+    let err = Employee::read_fixed_str("GeorgeINTERN  ").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"INTERN\": Unrecognized value. \
+        Expected one of \"SENIOR\", \"JUNIOR\", \"INT\"\n\
+        Error occured in field 1 (`grade`)\n"
+    );
+}
+
+#[cfg(feature = "experimental-write")]
+#[test]
+fn match_display_write() {
+    let employee = Employee {
+        name: String::from("Claire"),
+        grade: Grade::Junior,
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    let res = employee.write_fixed(&mut buf);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "ClaireJUNIOR  ");
+}
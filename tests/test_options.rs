@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 use fixcol::ReadFixed;
 #[cfg(feature = "experimental-write")]
 use fixcol::WriteFixed;
@@ -88,10 +90,39 @@ fn err_on_non_option_empty() {
     assert!(actual.is_err());
     assert_eq!(
         actual.unwrap_err().to_string(),
-        "Error handling data from \"\": cannot parse float from empty string\n"
+        "Error handling data from \"\": cannot parse float from empty string\n\
+        Error occured in field 2 (`y`)\n"
     );
 }
 
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Thing5 {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 5, align = "right", blank_as = 0)]
+    x: f32,
+    #[fixcol(width = 5, align = "right", blank_as = "-1")]
+    y: i32,
+}
+
+#[test]
+fn blank_as_sentinel() {
+    let actual = Thing5::read_fixed_str("foo            ").unwrap();
+    let expected = Thing5 { name: String::from("foo"), x: 0.0, y: -1 };
+    assert_eq!(actual, expected);
+
+    let actual = Thing5::read_fixed_str("foo   3.14   42").unwrap();
+    let expected = Thing5 {
+        name: String::from("foo"),
+        x: 3.14,
+        y: 42,
+    };
+    assert_eq!(actual, expected);
+
+    let actual = Thing5::read_fixed_str("foo   3.14  abc");
+    assert!(actual.is_err());
+}
+
 #[test]
 fn option_vs_empty_string() {
     let actual = Thing3::read_fixed_str("foo   3.14   42").unwrap();
@@ -154,3 +185,699 @@ fn write_option_none() {
     let text = std::str::from_utf8(v.as_slice()).unwrap();
     assert_eq!(text, "foo          42");
 }
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Flags {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 1, bool_tokens = "YN")]
+    active: bool,
+    #[fixcol(width = 1, bool_tokens = "YN")]
+    verified: Option<bool>,
+}
+
+#[test]
+fn bool_tokens() {
+    let actual = Flags::read_fixed_str("foo  YN").unwrap();
+    let expected = Flags {
+        name: String::from("foo"),
+        active: true,
+        verified: Some(false),
+    };
+    assert_eq!(actual, expected);
+
+    let actual = Flags::read_fixed_str("foo  N ").unwrap();
+    let expected = Flags {
+        name: String::from("foo"),
+        active: false,
+        verified: None,
+    };
+    assert_eq!(actual, expected);
+
+    let actual = Flags::read_fixed_str("foo  yN");
+    assert!(actual.is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_bool_tokens() {
+    let flags = Flags {
+        name: String::from("foo"),
+        active: true,
+        verified: None,
+    };
+
+    let mut v = Vec::new();
+    let res = flags.write_fixed(&mut v);
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(v.as_slice()).unwrap(), "foo  Y ");
+}
+
+#[derive(Debug, ReadFixed)]
+struct Measurement {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 5, align = "right")]
+    value: Result<u32, fixcol::error::DataError>,
+    #[fixcol(width = 3, align = "right")]
+    count: u8,
+}
+
+#[test]
+fn result_field_captures_error() {
+    let actual = Measurement::read_fixed_str("foo     42  7").unwrap();
+    assert_eq!(actual.name, "foo");
+    assert_eq!(actual.value.unwrap(), 42);
+    assert_eq!(actual.count, 7);
+
+    let actual = Measurement::read_fixed_str("foo    4x2  7").unwrap();
+    let err = actual.value.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"4x2\": invalid digit found in string\n"
+    );
+    assert_eq!(actual.count, 7);
+
+    // Fields that are not a Result still fail the record
+    let actual = Measurement::read_fixed_str("foo     42  x");
+    assert!(actual.is_err());
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(utf8 = "lossy")]
+struct LossyName {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 3, align = "right")]
+    count: u8,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(utf8 = "lossy")]
+struct LossyTail {
+    #[fixcol(width = 2)]
+    id: String,
+    #[fixcol(width = 4, from_end = true)]
+    code: String,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct StrictName {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 3, align = "right")]
+    count: u8,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(key_width = 2, utf8 = "lossy")]
+enum LossyRecord {
+    #[fixcol(key = "NM")]
+    Name(#[fixcol(width = 5)] String),
+}
+
+#[test]
+fn utf8_lossy() {
+    let buf: &[u8] = b"ab\xffcd  7\nabcde  8\n";
+
+    let records: Vec<LossyName> = LossyName::read_fixed_all(buf)
+        .map(|res| res.unwrap())
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            LossyName {
+                name: String::from("ab\u{fffd}cd"),
+                count: 7
+            },
+            LossyName { name: String::from("abcde"), count: 8 },
+        ]
+    );
+
+    let record = LossyTail::read_fixed(&mut &b"01   x\xffyz"[..]).unwrap();
+    assert_eq!(record.code, "x\u{fffd}yz");
+
+    let mut buf: &[u8] = b"NMab\xffcd";
+    let record = LossyRecord::read_fixed(&mut buf).unwrap();
+    assert_eq!(record, LossyRecord::Name(String::from("ab\u{fffd}cd")));
+}
+
+#[test]
+fn utf8_strict() {
+    let buf: &[u8] = b"ab\xffcd  7\nabcde  8\n";
+
+    let mut records = StrictName::read_fixed_all(buf);
+
+    // The bad line is a data error and reading continues with the next line
+    let err = records.next().unwrap().unwrap_err();
+    assert!(err.to_string().ends_with("Error occured on line 1\n"));
+
+    let record = records.next().unwrap().unwrap();
+    assert_eq!(record, StrictName { name: String::from("abcde"), count: 8 });
+}
+
+#[cfg(feature = "encoding")]
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(encoding = "latin1")]
+struct Latin1Name {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 3, align = "right")]
+    count: u8,
+}
+
+#[cfg(feature = "encoding")]
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(key_width = 1, discriminant = 5, encoding = "windows-1252")]
+enum Cp1252Record {
+    #[fixcol(key = "P")]
+    Price(
+        #[fixcol(width = 5)] String,
+        #[fixcol(skip_literal = "P", width = 3)] String,
+    ),
+    #[fixcol(key = "é")]
+    Note(#[fixcol(width = 5)] String),
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding_latin1() {
+    let buf: &[u8] = b"Z\xfcrch  7\nabcde  8\n";
+
+    let records: Vec<Latin1Name> = Latin1Name::read_fixed_all(buf)
+        .map(|res| res.unwrap())
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            Latin1Name { name: String::from("Zürch"), count: 7 },
+            Latin1Name { name: String::from("abcde"), count: 8 },
+        ]
+    );
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+fn encoding_windows_1252() {
+    let record = Cp1252Record::read_fixed(&mut &b"\x93hi\x94 P\x80 5"[..]).unwrap();
+    assert_eq!(
+        record,
+        Cp1252Record::Price(String::from("“hi”"), String::from("€ 5"))
+    );
+
+    let record = Cp1252Record::read_fixed(&mut &b"caf\xe9 \xe9"[..]).unwrap();
+    assert_eq!(record, Cp1252Record::Note(String::from("café")));
+}
+
+const NAME_WIDTH: usize = 6;
+
+mod layout {
+    pub const CODE_WIDTH: usize = 3;
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, ReadFixed)]
+struct SharedLayout {
+    #[fixcol(width = NAME_WIDTH)]
+    name: String,
+    #[fixcol(skip = 1, width = layout::CODE_WIDTH, align = "right")]
+    code: u16,
+    #[fixcol(width = layout::CODE_WIDTH, from_end)]
+    tail: String,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(key_width = 2)]
+enum SharedRecord {
+    #[fixcol(key = "NM")]
+    Name(#[fixcol(width = NAME_WIDTH)] String),
+    #[fixcol(key = "CD")]
+    Code(#[fixcol(width = 2)] String),
+}
+
+#[test]
+fn const_width() {
+    let mut buf: &[u8] = b"Ada     42    xyz";
+    let record = SharedLayout::read_fixed(&mut buf).unwrap();
+    assert_eq!(
+        record,
+        SharedLayout {
+            name: String::from("Ada"),
+            code: 42,
+            tail: String::from("xyz"),
+        }
+    );
+
+    let mut buf: &[u8] = b"NMGrace ";
+    let record = SharedRecord::read_fixed(&mut buf).unwrap();
+    assert_eq!(record, SharedRecord::Name(String::from("Grace")));
+    assert_eq!(SharedRecord::total_width(), 2 + NAME_WIDTH);
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_const_width() {
+    let record = SharedLayout {
+        name: String::from("Ada"),
+        code: 42,
+        tail: String::from("xyz"),
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    record.write_fixed(&mut buf).unwrap();
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "Ada     42xyz");
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(strict = true)]
+struct Exon {
+    #[fixcol(width = 4)]
+    chrom: String,
+    #[fixcol(width = 5, count = 2, align = "right")]
+    span: std::ops::Range<u32>,
+    #[fixcol(skip = 1, width = 1)]
+    strand: String,
+}
+
+#[test]
+fn range_field() {
+    let mut buf: &[u8] = b"chr1  120  450 +";
+    let exon = Exon::read_fixed(&mut buf).unwrap();
+    assert_eq!(
+        exon,
+        Exon {
+            chrom: String::from("chr1"),
+            span: 120..450,
+            strand: String::from("+"),
+        }
+    );
+
+    let mut buf: &[u8] = b"chr1  450  120 +";
+    assert!(Exon::read_fixed(&mut buf).is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_range_field() {
+    let exon = Exon {
+        chrom: String::from("chr2"),
+        span: 7..1024,
+        strand: String::from("-"),
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    exon.write_fixed(&mut buf).unwrap();
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "chr2    7 1024 -");
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Fix {
+    #[fixcol(width = 4)]
+    name: String,
+    #[fixcol(width = 7, align = "right")]
+    lat: f32,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct FixTuple(#[fixcol(width = 4)] String, #[fixcol(width = 4)] u8);
+
+#[test]
+fn error_field_position() {
+    let err = Fix::read_fixed_str("KBOS 42.3x6").unwrap_err();
+    let data_err = match &err {
+        fixcol::error::Error::DataError(e) => e,
+        _ => panic!("Expected a data error"),
+    };
+    assert_eq!(data_err.field_index(), Some(1));
+    assert_eq!(data_err.field_name(), Some("lat"));
+    assert!(err
+        .to_string()
+        .ends_with("Error occured in field 1 (`lat`)\n"));
+
+    let err = FixTuple::read_fixed_str("abcdxyz ").unwrap_err();
+    assert!(err.to_string().ends_with("Error occured in field 1\n"));
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(key_width = 2)]
+enum Reading {
+    #[fixcol(key = "T")]
+    Temp {
+        #[fixcol(width = 3)]
+        station: String,
+        #[fixcol(skip = 1, width = 5, align = "right")]
+        degrees: f32,
+    },
+}
+
+#[test]
+fn error_field_span() {
+    let err = Fix::read_fixed_str("KBOS 42.3x6").unwrap_err();
+    match err {
+        fixcol::error::Error::DataError(e) => assert_eq!(e.span(), Some((4, 7))),
+        other => panic!("Expected a data error, got {:?}", other),
+    }
+
+    // Spans are columns of the whole line, including the variant's key
+    let err = Reading::read_fixed_str("T KBO  1x.5").unwrap_err();
+    match err {
+        fixcol::error::Error::DataError(e) => assert_eq!(e.span(), Some((6, 5))),
+        other => panic!("Expected a data error, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "experimental-write")]
+#[derive(Debug, PartialEq, ReadFixed, WriteFixed)]
+struct Label {
+    #[fixcol(width = 5, on_overflow = "truncate")]
+    name: String,
+    #[fixcol(width = 3, align = "right", strict = false, on_overflow = "error")]
+    qty: u16,
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_on_overflow() {
+    let label = Label { name: String::from("Widgets"), qty: 12 };
+
+    let mut buf: Vec<u8> = Vec::new();
+    label.write_fixed(&mut buf).unwrap();
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "Widge 12");
+
+    let label = Label { name: String::from("Nuts"), qty: 1200 };
+    let mut buf: Vec<u8> = Vec::new();
+    assert!(label.write_fixed(&mut buf).is_err());
+}
+
+#[cfg(feature = "experimental-write")]
+#[derive(WriteFixed)]
+struct Ledger {
+    #[fixcol(width = 4, align = "right", strict = false)]
+    number: String,
+    #[fixcol(width = 4, align = "right", on_overflow = "keep_end")]
+    account: String,
+    #[fixcol(width = 3, align = "right", on_overflow = "keep_end")]
+    sequence: u32,
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_overflow_keep_end() {
+    let ledger = Ledger {
+        number: String::from("123456"),
+        account: String::from("987654"),
+        sequence: 12345,
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    ledger.write_fixed(&mut buf).unwrap();
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), "12347654345");
+}
+
+/// Records the name of the field it was read from
+#[derive(Debug, PartialEq)]
+struct FieldName(Option<&'static str>);
+
+impl fixcol::FixedDeserializer for FieldName {
+    fn parse_fixed(
+        _s: &str,
+        desc: &fixcol::FieldDescription,
+    ) -> Result<Self, fixcol::error::DataError> {
+        Ok(FieldName(desc.name))
+    }
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct NamedColumns {
+    #[fixcol(width = 2)]
+    r#first: FieldName,
+    #[fixcol(width = 2)]
+    second: FieldName,
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct UnnamedColumns(#[fixcol(width = 2)] FieldName);
+
+#[test]
+fn field_description_name() {
+    let named = NamedColumns::read_fixed_str("abcd").unwrap();
+    assert_eq!(named.first, FieldName(Some("first")));
+    assert_eq!(named.second, FieldName(Some("second")));
+
+    let unnamed = UnnamedColumns::read_fixed_str("ab").unwrap();
+    assert_eq!(unnamed.0, FieldName(None));
+}
+
+/// Records the description of the field it was read from
+#[derive(Debug, PartialEq)]
+struct FieldDoc(Option<&'static str>);
+
+impl fixcol::FixedDeserializer for FieldDoc {
+    fn parse_fixed(
+        _s: &str,
+        desc: &fixcol::FieldDescription,
+    ) -> Result<Self, fixcol::error::DataError> {
+        Ok(FieldDoc(desc.description))
+    }
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct DocumentedColumns {
+    /// The x coordinate
+    #[fixcol(width = 2)]
+    x: FieldDoc,
+    /// The y coordinate, measured
+    /// from the top of the page
+    ///
+    /// In points.
+    #[fixcol(width = 2)]
+    y: FieldDoc,
+    #[fixcol(width = 2)]
+    z: FieldDoc,
+}
+
+#[test]
+fn field_description_doc_comment() {
+    let columns = DocumentedColumns::read_fixed_str("abcdef").unwrap();
+    assert_eq!(columns.x, FieldDoc(Some("The x coordinate")));
+    assert_eq!(
+        columns.y,
+        FieldDoc(Some(
+            "The y coordinate, measured from the top of the page In points."
+        ))
+    );
+    assert_eq!(columns.z, FieldDoc(None));
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+#[fixcol(lines = 3)]
+struct Mailing {
+    #[fixcol(width = 10)]
+    name: String,
+    #[fixcol(width = 12)]
+    street: String,
+    #[fixcol(width = 8)]
+    city: String,
+}
+
+#[test]
+fn read_multi_line_records() {
+    let text = "Harold    \n12 Elm St   \nSpringfi\n\
+        Claire    \n9 Oak Ave   \nShelby  \n";
+
+    let mailings: Vec<Mailing> = Mailing::read_fixed_all(text.as_bytes())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(
+        mailings,
+        vec![
+            Mailing {
+                name: String::from("Harold"),
+                street: String::from("12 Elm St"),
+                city: String::from("Springfi"),
+            },
+            Mailing {
+                name: String::from("Claire"),
+                street: String::from("9 Oak Ave"),
+                city: String::from("Shelby"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn multi_line_record_line_numbers() {
+    let text = "# mailing list\n\
+        Harold    \n12 Elm St   \nSpringfi\n\
+        Claire    \n9 Oak Ave   \n";
+
+    let results: Vec<(usize, Result<Mailing, fixcol::error::Error>)> =
+        fixcol::FixedReader::<Mailing>::new()
+            .comment_prefix("#")
+            .read(text.as_bytes())
+            .with_line_numbers()
+            .collect();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, 2);
+    assert!(results[0].1.is_ok());
+
+    let (line, res) = &results[1];
+    assert_eq!(*line, 5);
+    let err = res.as_ref().unwrap_err();
+    assert!(err.to_string().contains("Record ends after 2 of 3 lines"));
+    assert!(err.to_string().ends_with("Error occured on line 5\n"));
+}
+
+#[cfg(feature = "experimental-write")]
+#[derive(Debug, PartialEq, ReadFixed, WriteFixed)]
+struct Invoice {
+    #[fixcol(width = 8, min_digits = 4, align = "right")]
+    number: u32,
+    #[fixcol(width = 6, min_digits = 3)]
+    line: i16,
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_min_digits() {
+    let invoice = Invoice { number: 7, line: -12 };
+
+    let mut buf: Vec<u8> = Vec::new();
+    invoice.write_fixed(&mut buf).unwrap();
+    let text = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(text, "    0007-012  ");
+
+    assert_eq!(Invoice::read_fixed_str(text).unwrap(), invoice);
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Tagged {
+    #[fixcol(width = 6)]
+    name: String,
+    #[fixcol(width = 10, sep = ',')]
+    tags: Vec<String>,
+    #[fixcol(width = 8, sep = "|", align = "right")]
+    scores: Vec<u8>,
+}
+
+#[test]
+fn read_separated_list() {
+    let tagged = Tagged::read_fixed_str("widgetA,B,C         7|42").unwrap();
+    assert_eq!(
+        tagged,
+        Tagged {
+            name: String::from("widget"),
+            tags: vec![String::from("A"), String::from("B"), String::from("C")],
+            scores: vec![7, 42],
+        }
+    );
+
+    let tagged = Tagged::read_fixed_str("nuts  X,                ").unwrap();
+    assert_eq!(tagged.tags, vec![String::from("X")]);
+    assert!(tagged.scores.is_empty());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_separated_list() {
+    let tagged = Tagged {
+        name: String::from("bolts"),
+        tags: vec![String::from("M4"), String::from("steel")],
+        scores: vec![1, 2, 3],
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    tagged.write_fixed(&mut buf).unwrap();
+    let text = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(text, "bolts M4,steel     1|2|3");
+
+    assert_eq!(Tagged::read_fixed_str(text).unwrap(), tagged);
+}
+
+#[cfg_attr(feature = "experimental-write", derive(WriteFixed))]
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Route {
+    #[fixcol(width = 15)]
+    gateway: std::net::Ipv4Addr,
+    #[fixcol(width = 12, ipv4 = "octets")]
+    host: std::net::Ipv4Addr,
+}
+
+#[test]
+fn read_ipv4_octets() {
+    let route = Route::read_fixed_str("10.0.0.1       192168001020").unwrap();
+    assert_eq!(
+        route,
+        Route {
+            gateway: std::net::Ipv4Addr::new(10, 0, 0, 1),
+            host: std::net::Ipv4Addr::new(192, 168, 1, 20),
+        }
+    );
+
+    assert!(Route::read_fixed_str("10.0.0.1       192.168.1.20").is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn write_ipv4_octets() {
+    let route = Route {
+        gateway: std::net::Ipv4Addr::new(172, 16, 0, 1),
+        host: std::net::Ipv4Addr::new(8, 8, 4, 4),
+    };
+
+    let mut buf: Vec<u8> = Vec::new();
+    route.write_fixed(&mut buf).unwrap();
+    let text = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(text, "172.16.0.1     008008004004");
+
+    assert_eq!(Route::read_fixed_str(text).unwrap(), route);
+}
+
+fn clamp_percent(value: u8) -> u8 {
+    value.min(100)
+}
+
+fn nonzero(value: u16) -> Result<u16, fixcol::error::DataError> {
+    match value {
+        0 => Err(fixcol::error::DataError::custom(
+            "0",
+            "Expected a nonzero count",
+        )),
+        n => Ok(n),
+    }
+}
+
+#[derive(Debug, PartialEq, ReadFixed)]
+struct Normalized {
+    #[fixcol(width = 4, transform = "str::to_uppercase")]
+    code: String,
+    #[fixcol(width = 3, align = "right", transform = clamp_percent)]
+    percent: u8,
+    #[fixcol(width = 3, align = "right", transform = nonzero)]
+    count: u16,
+}
+
+#[test]
+fn read_transform() {
+    let normalized = Normalized::read_fixed_str("ab1 250  7").unwrap();
+    assert_eq!(
+        normalized,
+        Normalized {
+            code: String::from("AB1"),
+            percent: 100,
+            count: 7,
+        }
+    );
+
+    let err = Normalized::read_fixed_str("ab1  50  0").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"0\": Expected a nonzero count\n\
+        Error occured in field 2 (`count`)\n"
+    );
+}
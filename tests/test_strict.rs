@@ -59,17 +59,86 @@ fn whitespace_malformed_strict() {
     assert_eq!(
         err.to_string(),
         "Error handling data from \"4201\": Found non-whitespace \
-        character between data fields (strict)\n",
+        character between data fields (strict)\n\
+        Error occured in field 1 (`y`)\n",
     );
 
     let err = PointS::read_fixed_str("1  42  ").unwrap_err();
     assert_eq!(
         err.to_string(),
         "Error handling data from \"42  \": Found non-whitespace \
-        character between data fields (strict)\n",
+        character between data fields (strict)\n\
+        Error occured in field 1 (`y`)\n",
     );
 }
 
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true)]
+struct Span3 {
+    #[fixcol(width = 2)]
+    a: String,
+    #[fixcol(skip = 2, width = 3)]
+    b: String,
+    #[fixcol(skip = 1, width = 2)]
+    c: String,
+}
+
+#[test]
+fn whitespace_overrun_strict() {
+    let span = Span3::read_fixed_str("ab  cde fg").unwrap();
+    assert_eq!(span.b, "cde");
+
+    // `b` runs one column past its width into the gap before `c`
+    let err = Span3::read_fixed_str("ab  cdefgh").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"fgh\": Found non-whitespace \
+        character between data fields (strict)\n\
+        Error occured in field 2 (`c`)\n",
+    );
+
+    // The gap is checked across its full width, not just its first column
+    let err = Span3::read_fixed_str("ab xcde fg").unwrap_err();
+    assert!(err.to_string().contains("Error occured in field 1 (`b`)"));
+}
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true)]
+struct TrailerS {
+    #[fixcol(width = 3)]
+    kind: String,
+    #[fixcol(width = 4, from_end = true, align = "right")]
+    count: u16,
+    #[fixcol(width = 3, skip = 1, from_end = true)]
+    code: String,
+}
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+struct TrailerL {
+    #[fixcol(width = 3)]
+    kind: String,
+    #[fixcol(width = 3, skip = 1, from_end = true, strict = false)]
+    code: String,
+}
+
+#[test]
+fn whitespace_from_end_gap() {
+    let trailer = TrailerS::read_fixed_str("HDR  12XYZ ").unwrap();
+    assert_eq!(trailer.code, "XYZ");
+
+    // The skipped column following a field read from the end is checked too
+    let err = TrailerS::read_fixed_str("HDR  12XYZW").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"XYZW\": Found non-whitespace \
+        character between data fields (strict)\n\
+        Error occured in field 2 (`code`)\n",
+    );
+
+    let trailer = TrailerL::read_fixed_str("HDRXYZW").unwrap();
+    assert_eq!(trailer.code, "XYZ");
+}
+
 // strict mode should require last field of line to be full length when reading
 ///////////////////////////////////////////////////////////////////////////////
 
@@ -97,3 +166,319 @@ fn full_line_strict() {
     let point = PointS::read_fixed_str("7   21 ").unwrap();
     assert_eq!(point, PointS::new(7, 21));
 }
+
+// a lax last field the line ends before is empty, so numbers read as zero
+///////////////////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Debug, ReadFixed)]
+#[fixcol(strict = false)]
+struct NameL2 {
+    #[fixcol(width = 3)]
+    code: String,
+    #[fixcol(skip = 1, width = 5)]
+    name: String,
+}
+
+#[derive(PartialEq, Debug, ReadFixed)]
+#[fixcol(strict = false)]
+struct AmountL {
+    #[fixcol(width = 3)]
+    code: String,
+    #[fixcol(skip = 1, width = 5, align = "right", blank_as = "-1")]
+    amount: f32,
+}
+
+#[test]
+fn empty_last_field_lax() {
+    // The line ends exactly at the prior field
+    assert_eq!(PointL::read_fixed_str("7  ").unwrap(), PointL::new(7, 0));
+
+    // The line ends within the skipped columns
+    assert_eq!(PointL::read_fixed_str("7   ").unwrap(), PointL::new(7, 0));
+
+    let name = NameL2::read_fixed_str("abc").unwrap();
+    assert_eq!(name.name, "");
+
+    // A field's own blank_as is used instead of zero
+    let amount = AmountL::read_fixed_str("abc").unwrap();
+    assert_eq!(amount.amount, -1.0);
+
+    // A present but blank value is not empty and still fails to parse
+    assert!(PointL::read_fixed_str("7      ").is_err());
+}
+
+#[test]
+fn empty_last_field_strict() {
+    assert!(PointS::read_fixed_str("7  ").is_err());
+}
+
+// strict mode should reject lines longer than max_line_length
+///////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true, max_line_length = 8)]
+struct PointMaxS {
+    #[fixcol(width = 3)]
+    x: u8,
+    #[fixcol(skip = 1, width = 3)]
+    y: u8,
+}
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = false, max_line_length = 8)]
+struct PointMaxL {
+    #[fixcol(width = 3)]
+    x: u8,
+    #[fixcol(skip = 1, width = 3)]
+    y: u8,
+}
+
+#[test]
+fn max_line_length_within_strict() {
+    let point = PointMaxS::read_fixed_str("7   21  ").unwrap();
+    assert_eq!(point, PointMaxS { x: 7, y: 21 });
+}
+
+#[test]
+fn max_line_length_exceeded_strict() {
+    let err = PointMaxS::read_fixed_str("7   21  xyz").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \" xyz\": Line length 11 exceeds max_line_length 8 by 3 (strict)\n"
+    );
+}
+
+#[test]
+fn max_line_length_exceeded_lax() {
+    let point = PointMaxL::read_fixed_str("7   21  xyz").unwrap();
+    assert_eq!(point, PointMaxL { x: 7, y: 21 });
+}
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true, max_line_length = 6)]
+struct RowMaxS {
+    #[fixcol(width = 2)]
+    id: u8,
+    #[fixcol(width = 2, repeat = "rest", align = "right")]
+    values: Vec<u8>,
+}
+
+#[test]
+fn max_line_length_repeat_rest_strict() {
+    let row = RowMaxS::read_fixed_str("12 3 4").unwrap();
+    assert_eq!(row, RowMaxS { id: 12, values: vec![3, 4] });
+
+    let err = RowMaxS::read_fixed_str("12 3 4 5 6 7").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"\": Line length 12 exceeds max_line_length 6 by 6 (strict)\n"
+    );
+}
+
+// strict mode should reject non-ASCII fields when ascii_only is set
+///////////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true, ascii_only = true)]
+struct NameS {
+    #[fixcol(width = 4)]
+    code: String,
+    #[fixcol(width = 6)]
+    name: String,
+}
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = false, ascii_only = true)]
+struct NameL {
+    #[fixcol(width = 4)]
+    code: String,
+    #[fixcol(width = 6)]
+    name: String,
+}
+
+#[test]
+fn ascii_only_accepts_ascii() {
+    let name = NameS::read_fixed_str("ab  cafe  ").unwrap();
+    assert_eq!(
+        name,
+        NameS {
+            code: "ab".to_string(),
+            name: "cafe".to_string()
+        }
+    );
+}
+
+#[test]
+fn ascii_only_rejects_non_ascii_strict() {
+    let err = NameS::read_fixed_str("ab  café ").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"café \": Non-ASCII character in field name (strict)\n"
+    );
+}
+
+#[test]
+fn ascii_only_ignored_lax() {
+    let name = NameL::read_fixed_str("ab  café ").unwrap();
+    assert_eq!(
+        name,
+        NameL {
+            code: "ab".to_string(),
+            name: "café".to_string()
+        }
+    );
+}
+
+// a bare strict flag should behave the same as strict = true
+/////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict)]
+struct PointFlag {
+    #[fixcol(width = 3)]
+    x: u8,
+    #[fixcol(skip = 1, width = 3)]
+    y: u8,
+}
+
+#[test]
+fn bare_strict_flag() {
+    let point = PointFlag::read_fixed_str("123 201").unwrap();
+    assert_eq!(point, PointFlag { x: 123, y: 201 });
+
+    let point = PointFlag::read_fixed_str("123x201");
+    assert!(point.is_err());
+}
+
+// non-finite floats are rejected in strict mode unless allowed
+/////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Debug, ReadFixed)]
+#[fixcol(strict = true)]
+struct Reading {
+    #[fixcol(width = 4)]
+    value: f64,
+    #[fixcol(skip = 1, width = 4, allow_nonfinite)]
+    limit: f64,
+}
+
+#[test]
+fn strict_nonfinite_floats() {
+    let reading = Reading::read_fixed_str("1.5  inf ").unwrap();
+    assert_eq!(reading, Reading { value: 1.5, limit: f64::INFINITY });
+
+    let err = Reading::read_fixed_str("inf  2.5 ").unwrap_err();
+    assert!(err.to_string().contains("Value is not a finite number"));
+}
+
+// reject_blank makes blank required fields an error in strict mode
+/////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true, reject_blank)]
+struct Shipment {
+    #[fixcol(width = 4)]
+    code: String,
+    #[fixcol(width = 3, align = "right")]
+    count: u16,
+    #[fixcol(width = 3, align = "right", blank_as = "1")]
+    boxes: u16,
+    #[fixcol(width = 4, strict = false)]
+    note: String,
+    #[fixcol(width = 2)]
+    carrier: Option<String>,
+}
+
+#[test]
+fn reject_blank_fields() {
+    let shipment = Shipment::read_fixed_str("AB12 20         ").unwrap();
+    assert_eq!(
+        shipment,
+        Shipment {
+            code: String::from("AB12"),
+            count: 20,
+            boxes: 1,
+            note: String::new(),
+            carrier: None,
+        }
+    );
+
+    let err = Shipment::read_fixed_str("     20  2    UP").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"    \": required field is blank\n\
+        Error occured in field 0 (`code`)\n"
+    );
+
+    let err = Shipment::read_fixed_str("AB12     2    UP").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error handling data from \"   \": required field is blank\n\
+        Error occured in field 1 (`count`)\n"
+    );
+}
+
+// signed numbers in full aligned columns are zero padded after the sign
+/////////////////////////////////////////////////////////////
+
+#[cfg_attr(feature = "experimental-write", derive(fixcol::WriteFixed))]
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+#[fixcol(strict = true)]
+struct Adjustment {
+    #[fixcol(width = 5, align = "full")]
+    delta: i32,
+}
+
+#[test]
+fn strict_signed_full() {
+    let adjustment = Adjustment::read_fixed_str("-0042").unwrap();
+    assert_eq!(adjustment, Adjustment { delta: -42 });
+
+    let adjustment = Adjustment::read_fixed_str("+0007").unwrap();
+    assert_eq!(adjustment, Adjustment { delta: 7 });
+
+    assert!(Adjustment::read_fixed_str("  -42").is_err());
+    assert!(Adjustment::read_fixed_str("-  42").is_err());
+}
+
+#[test]
+#[cfg(feature = "experimental-write")]
+fn strict_signed_full_round_trip() {
+    use fixcol::WriteFixed;
+
+    let adjustment = Adjustment { delta: -42 };
+    let mut buf: Vec<u8> = Vec::new();
+    adjustment.write_fixed(&mut buf).unwrap();
+    let text = std::str::from_utf8(&buf).unwrap();
+    assert_eq!(text, "-0042");
+
+    assert_eq!(Adjustment::read_fixed_str(text).unwrap(), adjustment);
+}
+
+// the `lax-by-default` feature flips the default for derives and descriptions
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(PartialEq, Eq, Debug, ReadFixed)]
+struct PointD {
+    #[fixcol(width = 3)]
+    x: u8,
+    #[fixcol(skip = 1, width = 3)]
+    y: u8,
+}
+
+#[test]
+#[cfg(feature = "lax-by-default")]
+fn lax_by_default() {
+    let point = PointD::read_fixed_str("1234201").unwrap();
+    assert_eq!(point, PointD { x: 123, y: 201 });
+
+    assert!(!fixcol::FieldDescription::default().strict);
+}
+
+#[test]
+#[cfg(not(feature = "lax-by-default"))]
+fn strict_by_default() {
+    assert!(PointD::read_fixed_str("1234201").is_err());
+
+    assert!(fixcol::FieldDescription::default().strict);
+}
@@ -25,11 +25,11 @@ impl FakeBuffer {
     }
 
     pub fn as_slice(&self) -> &[u8] {
-        &self.data.as_slice()
+        self.data.as_slice()
     }
 
     pub fn as_string(&self) -> Option<String> {
-        std::str::from_utf8(&self.as_slice())
+        std::str::from_utf8(self.as_slice())
             .ok()
             .map(|x| x.to_string())
     }
@@ -52,11 +52,36 @@ impl Write for FakeBuffer {
     }
 }
 
+/// A writable buffer that accepts at most one byte per call to `write`
+///
+/// Short writes are allowed by `Write::write` so all of the output must still
+/// be written.
+#[derive(Default)]
+struct TrickleBuffer {
+    data: Vec<u8>,
+}
+
+impl Write for TrickleBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match buf.first() {
+            Some(byte) => {
+                self.data.push(*byte);
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 //
 // Tests of struct writes
 //
 
-const EXPECTED_STRUCT_TEXT: &'static str = r#"91 115
+const EXPECTED_STRUCT_TEXT: &str = r#"91 115
 221159
 92 0  
 151171
@@ -111,6 +136,20 @@ fn struct_adequate_size_control() {
     assert_eq!(text, EXPECTED_STRUCT_TEXT);
 }
 
+#[test]
+fn struct_short_writes() {
+    let points = Point::sample();
+
+    let mut buf = TrickleBuffer::default();
+    let res = points.write_fixed_all(&mut buf);
+
+    assert!(res.is_ok());
+    assert_eq!(
+        std::str::from_utf8(&buf.data).unwrap(),
+        EXPECTED_STRUCT_TEXT
+    );
+}
+
 #[test]
 fn struct_out_of_space_test() {
     let points = Point::sample();
@@ -160,7 +199,7 @@ impl Datum {
     }
 }
 
-const EXPECTED_ENUM_TEXT: &'static str = r#"P5354272   
+const EXPECTED_ENUM_TEXT: &str = r#"P5354272   
 U
 U
 S      1234
@@ -192,6 +231,17 @@ fn enum_adequate_size_control() {
     assert_eq!(text, EXPECTED_ENUM_TEXT);
 }
 
+#[test]
+fn enum_short_writes() {
+    let data = Datum::sample();
+
+    let mut buf = TrickleBuffer::default();
+    let res = data.write_fixed_all(&mut buf);
+
+    assert!(res.is_ok());
+    assert_eq!(std::str::from_utf8(&buf.data).unwrap(), EXPECTED_ENUM_TEXT);
+}
+
 #[test]
 fn out_of_space_in_struct_variant() {
     let data = Datum::sample();
@@ -0,0 +1,13 @@
+use fixcol_derive::{ReadFixed};
+
+const ID_WIDTH: usize = 5;
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = ID_WIDTH)]
+    id: u64,
+    #[fixcol(at = 8, width = 5)]
+    name: String,
+}
+
+pub fn main() {}
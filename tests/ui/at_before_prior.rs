@@ -0,0 +1,11 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = 5)]
+    id: u64,
+    #[fixcol(at = 3, width = 5)]
+    name: String,
+}
+
+pub fn main() {}
@@ -0,0 +1,10 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+#[fixcol(encoding = "ebcdic")]
+struct Item {
+    #[fixcol(width = 5)]
+    name: String,
+}
+
+pub fn main() {}
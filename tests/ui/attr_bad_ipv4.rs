@@ -0,0 +1,9 @@
+use fixcol_derive::ReadFixed;
+
+#[derive(ReadFixed)]
+struct Thing {
+    #[fixcol(width = 12, ipv4 = "packed")]
+    field: std::net::Ipv4Addr,
+}
+
+pub fn main() {}
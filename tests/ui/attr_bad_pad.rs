@@ -0,0 +1,9 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = 5, pad = "ab")]
+    name: String,
+}
+
+pub fn main() {}
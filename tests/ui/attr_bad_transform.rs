@@ -0,0 +1,9 @@
+use fixcol_derive::ReadFixed;
+
+#[derive(ReadFixed)]
+struct Thing {
+    #[fixcol(width = 5, transform = 3)]
+    field: String,
+}
+
+pub fn main() {}
@@ -0,0 +1,10 @@
+use fixcol_derive::ReadFixed;
+
+#[derive(ReadFixed)]
+#[fixcol(delimiter = '|', lines = 2)]
+struct Thing {
+    #[fixcol(width = 5)]
+    field: String,
+}
+
+pub fn main() {}
@@ -0,0 +1,10 @@
+use fixcol_derive::ReadFixed;
+
+#[derive(ReadFixed)]
+#[fixcol(key_width = 3)]
+enum Thing {
+    #[fixcol(key = "one", embed = true)]
+    Thing1(u16, u16),
+}
+
+pub fn main() {}
@@ -0,0 +1,12 @@
+use fixcol::ReadFixed;
+
+#[derive(ReadFixed)]
+#[fixcol(key_width = 4)]
+enum Passthrough {
+    #[fixcol(key = "NODE")]
+    Node,
+    #[fixcol(catch_all)]
+    Other { line: String },
+}
+
+fn main() {}
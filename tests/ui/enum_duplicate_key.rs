@@ -0,0 +1,25 @@
+use fixcol::ReadFixed;
+
+#[derive(ReadFixed)]
+#[fixcol(key_width = 2)]
+enum Shape {
+    #[fixcol(key = "SQ")]
+    Square(#[fixcol(width = 3)] u16),
+    #[fixcol(key = "CI")]
+    Circle(#[fixcol(width = 3)] u16),
+    #[fixcol(key = "SQ")]
+    Rhombus(#[fixcol(width = 3)] u16),
+}
+
+#[derive(ReadFixed)]
+#[fixcol(key_width = 3, key_align = "right")]
+enum Code {
+    #[fixcol(key = "A")]
+    Alpha,
+    #[fixcol(key = "A", key_width = 2)]
+    Apex,
+    #[fixcol(key = "A")]
+    Again,
+}
+
+pub fn main() {}
@@ -0,0 +1,11 @@
+use fixcol::FixcolEnum;
+
+#[derive(FixcolEnum)]
+enum EyeColor {
+    #[fixcol(value = "Bl")]
+    Blue,
+    #[fixcol(value = "Ot")]
+    Other(String),
+}
+
+fn main() {}
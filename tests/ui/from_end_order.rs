@@ -0,0 +1,11 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = 5, from_end = true)]
+    id: u64,
+    #[fixcol(width = 5)]
+    name: String,
+}
+
+pub fn main() {}
@@ -0,0 +1,17 @@
+use fixcol::FixcolEnum;
+
+#[derive(FixcolEnum)]
+#[fixcol(match_display)]
+enum Status {
+    #[fixcol(value = "A")]
+    Active,
+    Retired,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "status")
+    }
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 use fixcol::ReadFixed;
 
 #[derive(ReadFixed)]
-struct Foo(u64);
+struct Foo(u64, #[fixcol(width = 3)] u8);
 
 #[derive(ReadFixed)]
 struct Baz(#[fixcol(width = 20)] u64);
@@ -1,10 +1,10 @@
 use fixcol::{ReadFixed, WriteFixed};
 
 #[derive(ReadFixed)]
-struct Foo(u64);
+struct Foo(u64, #[fixcol(width = 3)] u8);
 
 #[derive(WriteFixed)]
-struct Bar(u64);
+struct Bar(u64, #[fixcol(width = 3)] u8);
 
 #[derive(ReadFixed, WriteFixed)]
 struct Baz(#[fixcol(width = 20)] u64);
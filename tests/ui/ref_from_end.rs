@@ -0,0 +1,11 @@
+use fixcol_derive::{ReadFixedRef};
+
+#[derive(ReadFixedRef)]
+struct Item<'a> {
+    #[fixcol(width = 5)]
+    name: &'a str,
+    #[fixcol(width = 3, from_end = true)]
+    code: &'a str,
+}
+
+pub fn main() {}
@@ -0,0 +1,11 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = 2, repeat = "rest")]
+    ids: Vec<u8>,
+    #[fixcol(width = 5)]
+    name: String,
+}
+
+pub fn main() {}
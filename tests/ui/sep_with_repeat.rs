@@ -0,0 +1,11 @@
+use fixcol_derive::{ReadFixed};
+
+#[derive(ReadFixed)]
+struct Item {
+    #[fixcol(width = 5)]
+    name: String,
+    #[fixcol(width = 2, sep = ',', repeat = "rest")]
+    ids: Vec<u8>,
+}
+
+pub fn main() {}